@@ -1,15 +1,124 @@
 use dioxus::prelude::*;
+use log::warn;
 
-use crate::grid::{Axis, Grid};
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::multiline_text::MultilineText;
+use crate::grid::{
+    axis_title_centers, break_glyph, reference_lines, reference_values, rotated_labels_height,
+    Axis, Grid, AXIS_TITLE_ROOM, LABEL_OFFSET,
+};
+use crate::hooks::use_reduced_motion;
 use crate::types::*;
+use crate::utils::color::{fill_pattern, grey, DivergingScale};
+use crate::utils::format::{label_number_format, NumberFormat, RoundingMode};
+use crate::utils::path::PathBuilder;
+use crate::utils::stroke_width_to_view;
+use crate::utils::text::{estimate_text_width, format_scaled, DEFAULT_FONT_SIZE};
+use crate::utils::transform::percent_of_category;
+
+pub use crate::grid::{AxisScale, DomainMode, NonPositive, ReferenceLine, TickOverflow, TickSteps};
+pub use crate::types::ContextMenu;
+pub use crate::utils::format::ValueKind;
+pub use crate::utils::transform::SummaryBand;
+
+/// How far the axis break marks go past both sides of the axis and of the bars.
+const BREAK_MARK_OVERHANG: f32 = 4.0;
+
+/// The space between the columns of stacked bars with `column_weights`.
+const WEIGHTED_COLUMN_GAP: f32 = 2.0;
+
+/// The width of the caps at the ends of the error whiskers.
+const ERROR_CAP_WIDTH: f32 = 8.0;
+
+/// The payload of the stacked bars segment events, with the segment indices, its value and
+/// its rect in viewbox coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SegmentEvent {
+    /// The index of the series the segment belongs to.
+    pub series: usize,
+    /// The index of the category the segment belongs to.
+    pub category: usize,
+    /// The value of the segment.
+    pub value: f32,
+    /// The left side of the segment rect.
+    pub x: f32,
+    /// The top side of the segment rect.
+    pub y: f32,
+    /// The width of the segment rect.
+    pub width: f32,
+    /// The height of the segment rect.
+    pub height: f32,
+}
+
+impl SegmentEvent {
+    /// The middle of the segment rect.
+    fn middle(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
+/// The description of a bar sent to the `label_format` function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BarLabel {
+    /// The index of the series of the bar.
+    pub series: usize,
+    /// The index of the category of the bar.
+    pub category: usize,
+    /// The bar value in the series.
+    pub value: f32,
+}
+
+/// The direction the bars grow in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    /// Vertical bars growing up from the labels axis at the bottom.
+    Vertical,
+    /// Horizontal bars growing right from the labels axis on the left.
+    Horizontal,
+}
+
+/// `true` for [`Orientation::Horizontal`], like the former `horizontal_bars` flag.
+impl From<bool> for Orientation {
+    fn from(horizontal: bool) -> Self {
+        if horizontal {
+            Orientation::Horizontal
+        } else {
+            Orientation::Vertical
+        }
+    }
+}
+
+/// Where the value labels of the bars are placed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ValuePosition {
+    /// Past the end of the bars, above vertical bars and right of horizontal ones.
+    #[default]
+    Outside,
+    /// Inside the bars at their end. The labels of bars too short to hold them go outside.
+    Inside,
+}
 
 /// The `BarChart` properties struct for the configuration of the bar chart.
 #[allow(clippy::struct_excessive_bools)]
-#[derive(PartialEq, Props)]
+#[derive(Props)]
 pub struct BarChartProps<'a> {
     series: Series,
     #[props(optional)]
     labels: Option<Labels>,
+    #[props(optional)]
+    comparison_series: Option<Series>,
+    #[props(optional)]
+    bar_errors: Option<PointErrors>,
+    #[props(optional)]
+    offsets: Option<Vec<f32>>,
+    #[props(default = false)]
+    show_offset_markers: bool,
+    #[props(optional)]
+    summary_band: Option<SummaryBand>,
+    #[props(optional)]
+    reference_lines: Option<Vec<ReferenceLine>>,
+    #[props(optional)]
+    reference_bands: Option<Vec<(f32, f32)>>,
 
     #[props(default = "100%")]
     width: &'a str,
@@ -19,6 +128,10 @@ pub struct BarChartProps<'a> {
     viewbox_width: i32,
     #[props(default = 400)]
     viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
 
     #[props(default)]
     padding_top: i32,
@@ -35,48 +148,178 @@ pub struct BarChartProps<'a> {
     highest: Option<f32>,
     #[props(default = 8)]
     max_ticks: i32,
+    #[props(default)]
+    tick_steps: TickSteps,
+    #[props(default = DomainMode::Nice)]
+    domain: DomainMode,
+    #[props(default)]
+    scale: AxisScale,
+    #[props(default)]
+    non_positive: NonPositive,
+    #[props(optional)]
+    axis_breaks: Option<Vec<(f32, f32)>>,
 
     #[props(default = true)]
     show_grid: bool,
     #[props(default = true)]
+    show_grid_x: bool,
+    #[props(default = true)]
+    show_grid_y: bool,
+    #[props(default = false)]
+    show_minor_grid: bool,
+    #[props(default = true)]
     show_dotted_grid: bool,
     #[props(default = false)]
     show_grid_ticks: bool,
+    #[props(optional)]
+    emphasized_ticks: Option<Vec<f32>>,
+    #[props(default = false)]
+    label_emphasized: bool,
+    #[props(default = false)]
+    show_plot_background: bool,
+    #[props(default = false)]
+    show_plot_border: bool,
     #[props(default = true)]
     show_labels: bool,
     #[props(default = true)]
     show_series_labels: bool,
+    #[props(default)]
+    value_position: ValuePosition,
+    #[props(default = false)]
+    show_comparison_delta: bool,
 
     #[props(default = 60)]
     label_size: i32,
+    #[props(default = TickOverflow::AutoSkip)]
+    tick_overflow: TickOverflow,
+    #[props(default)]
+    x_label_rotation: f32,
+    #[props(optional)]
+    x_label_step: Option<usize>,
     #[props(optional)]
     label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    label_format: Option<fn(BarLabel) -> String>,
+    #[props(optional)]
+    number_format: Option<NumberFormat>,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+    #[props(default)]
+    value_kind: ValueKind,
+    #[props(optional)]
+    axis_unit: Option<&'a str>,
+    #[props(optional)]
+    x_title: Option<&'a str>,
+    #[props(optional)]
+    y_title: Option<&'a str>,
+    #[props(optional)]
+    axis_divisor: Option<f32>,
+    #[props(default = false)]
+    scale_value_labels: bool,
 
     #[props(default = "5%")]
     bar_width: &'a str,
     #[props(default = 30.0)]
     bar_distance: f32,
-    #[props(default = false)]
-    horizontal_bars: bool,
+    #[props(optional)]
+    bar_gap_ratio: Option<f32>,
+    #[props(default = 0.0)]
+    bar_radius: f32,
+    #[props(default = Orientation::Vertical, into)]
+    horizontal_bars: Orientation,
     #[props(default = false)]
     stacked_bars: bool,
+    #[props(default = false)]
+    stacked_percent: bool,
+    #[props(optional)]
+    series_order: Option<Vec<usize>>,
+    #[props(optional)]
+    column_weights: Option<Vec<f32>>,
+    #[props(default = 4.0)]
+    min_column_width: f32,
+    #[props(default = false)]
+    show_flows: bool,
+    #[props(default = false)]
+    stable_keys: bool,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+    #[props(default = false)]
+    monochrome: bool,
+    #[props(optional)]
+    diverging: Option<DivergingScale>,
+    #[props(optional)]
+    flash_segment: Option<(usize, usize)>,
+    #[props(default = true)]
+    respect_reduced_motion: bool,
+    #[props(optional)]
+    on_segment_activate: Option<EventHandler<'a, SegmentEvent>>,
+    #[props(optional)]
+    on_context_menu: Option<EventHandler<'a, ContextMenu<SegmentEvent>>>,
+    #[props(optional)]
+    onclick_bar: Option<EventHandler<'a, (usize, usize)>>,
+    #[props(optional)]
+    onmouseover_bar: Option<EventHandler<'a, (usize, usize)>>,
+    #[props(optional)]
+    onmouseout_bar: Option<EventHandler<'a, (usize, usize)>>,
 
     #[props(default = "dx-chart-bar")]
     class_chart_bar: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
     #[props(default = "dx-bar")]
     class_bar: &'a str,
     #[props(default = "dx-bar-group")]
     class_bar_group: &'a str,
     #[props(default = "dx-bar-label")]
     class_bar_label: &'a str,
+    #[props(default = "dx-bar-flash")]
+    class_bar_flash: &'a str,
+    #[props(default = "dx-bar--hover")]
+    class_bar_hover: &'a str,
+    #[props(default = "dx-series")]
+    class_series: &'a str,
+    #[props(default = "dx-flow")]
+    class_flow: &'a str,
+    #[props(default = "dx-bar-comparison")]
+    class_bar_comparison: &'a str,
+    #[props(default = "dx-bar-delta")]
+    class_bar_delta: &'a str,
+    #[props(default = "dx-error-bar")]
+    class_error_bar: &'a str,
+    #[props(default = "dx-offset-marker")]
+    class_offset_marker: &'a str,
+    #[props(default = "dx-summary-band")]
+    class_summary_band: &'a str,
+    #[props(default = "dx-reference-line")]
+    class_reference_line: &'a str,
+    #[props(default = "dx-reference-band")]
+    class_reference_band: &'a str,
+    #[props(default = "dx-plot-background")]
+    class_plot_background: &'a str,
+    #[props(default = "dx-plot-border")]
+    class_plot_border: &'a str,
+    #[props(default = "dx-baseline")]
+    class_baseline: &'a str,
+    #[props(default = "dx-axis-break")]
+    class_axis_break: &'a str,
     #[props(default = "dx-grid")]
     class_grid: &'a str,
     #[props(default = "dx-grid-line")]
     class_grid_line: &'a str,
+    #[props(default = "dx-grid-minor-line")]
+    class_grid_minor_line: &'a str,
     #[props(default = "dx-grid-label")]
     class_grid_label: &'a str,
     #[props(default = "dx-grid-labels")]
     class_grid_labels: &'a str,
+    #[props(default = "dx-grid-emphasis")]
+    class_grid_emphasis: &'a str,
+    #[props(default = "dx-tick-emphasis")]
+    class_tick_emphasis: &'a str,
+    #[props(default = "dx-axis-title")]
+    class_axis_title: &'a str,
 }
 
 /// This is the `BarChart` function used to render the bar chart `Element`.
@@ -111,77 +354,430 @@ pub struct BarChartProps<'a> {
 /// # Props
 ///
 /// - `series`: [Vec]<[Vec]<[f32]>> (**required**): The series vector of vectors with the all series values.
+///   The bars grow from zero, so negative values hang below it, and stacked negative values
+///   stack down from it.
 /// - `labels`: [Vec]<[String]> (optional): Optional labels to show on the labels axis.
+/// - `comparison_series`: [Vec]<[Vec]<[f32]>> (optional): Values to compare each series against,
+///   i.e., the previous period, drawn as outlined bars behind the bars of the series with the same
+///   index. The value axis range includes them. Ignored for stacked bars.
+/// - `bar_errors`: [Vec]<[Vec]<[Option]<([f32], [f32])>>> (optional): One vector for each series
+///   with the `(low, high)` error of every bar, drawn as a whisker with caps from `value - low`
+///   to `value + high` across the end of the bar, cut at the plot area. Bars with `None` have no
+///   whisker. Ignored for stacked bars.
+/// - `offsets`: [Vec]<[f32]> (optional): A starting value for every category, i.e., yesterday's
+///   cumulative value, so its bars float from the offset up by their values. Stacked bars start
+///   stacking from it. The value labels still show the bar values, and the value axis range
+///   includes the offsets. Must have one offset per category.
+/// - `show_offset_markers`: [bool] (default: `false`): Draw a faint line across every category at
+///   its offset.
+/// - `summary_band`: [`SummaryBand`] (optional): A translucent band drawn behind the bars, clipped
+///   to the plot area.
+/// - `reference_lines`: [Vec]<[`ReferenceLine`]> (optional): Lines across the plot at values of
+///   the value axis, drawn over the bars with their label at the end.
+/// - `reference_bands`: [Vec]<([f32], [f32])> (optional): Ranges of values shaded across the plot
+///   behind the bars, clipped to the plot area.
 /// ---
 /// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
-/// other CSS style, i.e., "200px"
+///   other CSS style, i.e., "200px"
 /// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
 /// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
-/// `viewbox_height` it is useful for adjusting the aspect ratio for longer charts.
+///   `viewbox_height` it is useful for adjusting the aspect ratio for longer charts.
 /// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
 /// ---
 /// - `padding_top`: [i32] (default: `0`): Padding for the top side of the view box.
 /// - `padding_bottom`: [i32] (default: `0`): Padding for the bottom side of the view box.
-/// - `padding_left`: [i32] (default: `0`): Padding for the left side of the view box.
+/// - `padding_left`: [i32] (default: `0`): Padding for the left side of the view box. With
+///   horizontal bars it grows if needed to fit the category labels, up to `label_size`.
 /// - `padding_right`: [i32] (default: `0`): Padding for the right side of the view box.
 /// ---
-/// - `lowest`: [f32] (optional): The lowest number on the chart for the value axis.
-/// - `highest`: [f32] (optional): The highest number on the chart for the value axis.
+/// - `lowest`: [f32] (optional): The lowest number on the chart for the value axis. The axis
+///   spans from the lowest value or zero, whichever is lower, when not set.
+/// - `highest`: [f32] (optional): The highest number on the chart for the value axis. Longer
+///   bars are cut at the end of the plot.
 /// - `max_ticks`: [i32] (default: `8`): The maximum number of ticks on the generated value axis.
+/// - `tick_steps`: [`TickSteps`] (default: [`TickSteps::Fine`]): The round numbers the ticks of
+///   the generated value axis step by. [`TickSteps::Round`] keeps to 1, 2 or 5 times a power of
+///   ten.
+/// - `domain`: [`DomainMode`] (default: [`DomainMode::Nice`]): How the value axis range is
+///   picked from the data. A `Manual` range that is empty or inverted is an error.
+/// - `scale`: [`AxisScale`] (default: [`AxisScale::Linear`]): How the values are mapped along the
+///   value axis. A [`AxisScale::Log10`] axis has its ticks at powers of ten and ignores the
+///   `axis_breaks` and the `value_kind`.
+/// - `non_positive`: [`NonPositive`] (default: [`NonPositive::Clamp`]): What to do with the zero
+///   and negative values on a log value axis.
+/// - `axis_breaks`: [Vec]<([f32], [f32])> (optional): Ranges of values skipped by the value axis,
+///   i.e., to keep an outlier from dwarfing the other bars. Every range is compressed to a small
+///   gap without ticks, marked with two slashes on the axis and across the bars going through
+///   it. The labels and events still show the real values.
 /// ---
 /// - `show_grid`: [bool] (default: `true`): Show/hide the chart grid.
+/// - `show_grid_x`: [bool] (default: `true`): Show/hide the vertical grid lines at the ticks of
+///   the horizontal axis.
+/// - `show_grid_y`: [bool] (default: `true`): Show/hide the horizontal grid lines at the ticks of
+///   the vertical axis.
+/// - `show_minor_grid`: [bool] (default: `false`): Show grid lines at 2 to 9 times every power of
+///   ten of a log value axis.
 /// - `show_dotted_grid`: [bool] (default: `true`): Show the chart grid with dotted style or not.
 /// - `show_grid_ticks`: [bool] (default: `false`): Show the chart grid ticks instead of drawing the
-/// whole grid lines for a cleaner look.
+///   whole grid lines for a cleaner look.
+/// - `emphasized_ticks`: [Vec]<[f32]> (optional): Values of the value axis whose grid line is
+///   drawn stronger and whose tick label is bold. Defaults to zero when the axis crosses it.
+///   Values between the ticks get their own line, without a label.
+/// - `label_emphasized`: [bool] (default: `false`): Also label the emphasized values between
+///   the ticks.
+/// - `show_plot_background`: [bool] (default: `false`): Draw a rect matching the plot area behind
+///   everything else, to style it apart from the page and to capture pointer events over the
+///   whole plot.
+/// - `show_plot_border`: [bool] (default: `false`): Draw a border around the plot area, above the
+///   grid and below the data.
 /// - `show_labels`: [bool] (default: `true`): Show/hide the labels.
 /// - `show_series_labels`: [bool] (default: `true`): Show/hide the values labels at the top of
-/// bars.
+///   bars.
+/// - `value_position`: [`ValuePosition`] (default: [`ValuePosition::Outside`]): Where the value
+///   labels are placed relative to the end of the bars. The labels of negative bars are placed
+///   on the other side.
+/// - `show_comparison_delta`: [bool] (default: `false`): Show the signed change from the
+///   `comparison_series` value beyond each pair of bars.
 /// ---
 /// - `label_size`: [i32] (default: `60`): The maximum width or height of the label rect depending
-/// on whether the chart shows horizontal or vertical bars.
+///   on whether the chart shows horizontal or vertical bars.
+/// - `tick_overflow`: [`TickOverflow`] (default: [`TickOverflow::AutoSkip`]): What to do with the
+///   category labels when they don't fit side by side.
+/// - `x_label_rotation`: [f32] (default: `0.0`): The rotation in degrees of the category labels
+///   around their anchor, i.e., `-45.0`. The bottom padding grows if needed to fit them, up to
+///   `label_size`, and [`TickOverflow::AutoSkip`] accounts for the rotation. Ignored for
+///   horizontal bars.
+/// - `x_label_step`: [usize] (optional): Show only every Nth category label, whatever the
+///   `tick_overflow`. The first and the last labels are always shown.
 /// - `label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the
-/// generated labels for values.
+///   generated labels for values.
+/// - `label_format`: fn([`BarLabel`]) -> [String] (optional): Function for formatting the value
+///   label of every bar from its value and its series and category indices. It replaces
+///   `label_interpolation` for the bar labels.
+/// - `number_format`: [`NumberFormat`] (optional): The formatting of the generated labels when
+///   there is no `label_interpolation` function.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the generated labels, which
+///   keep their full precision when neither it nor `number_format` is set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the generated labels
+///   are rounded to the `label_decimals`, or to the decimals of the `number_format`.
+/// - `value_kind`: [`ValueKind`] (default: [`ValueKind::Number`]): The kind of the series values.
+///   With [`ValueKind::DurationSeconds`] the value axis ticks land on round durations, and the
+///   generated labels are formatted like `1h 23m` when there is no `label_interpolation`.
+/// - `axis_unit`: &[str] (optional): A unit appended to the value axis labels, i.e., `"MiB"`.
+/// - `x_title`: &[str] (optional): The title of the horizontal axis, centered below it. The plot
+///   is shrunk to make room for it.
+/// - `y_title`: &[str] (optional): The title of the vertical axis, rotated and centered left of
+///   it. The plot is shrunk to make room for it.
+/// - `axis_divisor`: [f32] (optional): The value axis labels show the values divided by it, i.e.,
+///   `1048576.0` for bytes in MiB. The ticks land on round divided values while the data and
+///   the bar value labels keep the original values.
+/// - `scale_value_labels`: [bool] (default: `false`): Divide the bar value labels too, and
+///   append the unit to them.
 /// ---
-/// - `bar_width`: &[str] (default: `"5%"`): The width of each bar.
+/// - `bar_width`: &[str] (default: `"5%"`): The width of each bar, as an SVG length in viewbox
+///   units or a percentage. The bars of a category, with their distance, are narrowed to fit in
+///   its slot instead of overlapping the neighbours.
 /// - `bar_distance`: [f32] (default: `30.0`): The distance between the bars for charts that have
-/// multiple ones side by side.
-/// - `horizontal_bars`: [bool] (default: `false`): Show horizontal bars.
-/// - `stacked_bars`: [bool] (default: `false`): Build a Stacked Bars chart.
+///   multiple ones side by side.
+/// - `bar_gap_ratio`: [f32] (optional): The gap between categories as a fraction of their slot,
+///   from `0.0` for touching bars like a histogram to `1.0`. The rest of the slot is shared by
+///   the bars of the category side by side, or taken by the stacked bar, instead of using
+///   `bar_width` and `bar_distance`.
+/// - `bar_radius`: [f32] (default: `0.0`): The radius of the two corners at the value end of the
+///   bars, or of the outermost segments of the stacked bars. It is capped at half the bar width
+///   and at the bar length.
+/// - `horizontal_bars`: [`Orientation`] (default: [`Orientation::Vertical`]): The direction of the
+///   bars. Also accepts a [bool], `true` for horizontal bars.
+/// - `stacked_bars`: [bool] (default: `false`): Build a Stacked Bars chart. Zero values add no
+///   segment.
+/// - `stacked_percent`: [bool] (default: `false`): Build a Stacked Bars chart where every
+///   category shows the share of each series in the category total, filling the value axis from
+///   0 to 100. The segment events still report the series values.
+/// - `series_order`: [Vec]<[usize]> (optional): The order in which the series are stacked, from
+///   the axis outwards, as a permutation of the series indices. Colors and classes stay bound to
+///   the original series indices.
+/// - `column_weights`: [Vec]<[f32]> (optional): One weight for each category of a stacked bars
+///   chart, i.e., the market size. The columns share the plot width in proportion to their
+///   weights instead of using `bar_width`, and the category labels follow them. Ignored for
+///   regular bars.
+/// - `min_column_width`: [f32] (default: `4.0`): The narrowest column allowed with
+///   `column_weights`. Zero and negative weights are clamped to it with a warning.
+/// - `show_flows`: [bool] (default: `false`): Connect the segments of every series across
+///   neighbouring stacked columns with faint ribbons, to show how the composition shifts. Zero
+///   values collapse the ribbon ends to a point instead of breaking the flow.
+/// - `stable_keys`: [bool] (default: `false`): Key the bars and category labels by their label
+///   instead of their position, so that categories which persist between updates keep their
+///   elements when they change rank, i.e., for live "top N" charts. Falls back to positional
+///   keys with a warning when the labels are missing or not unique.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the series, cycled when there are more
+///   series than colors. The generated shades of red are used when not set.
+/// - `monochrome`: [bool] (default: `false`): Fill every series with its own pattern in shades
+///   of grey instead of colors, for printing in black and white.
+/// - `diverging`: [`DivergingScale`] (optional): Color every bar by its value on the scale, from
+///   the lowest to the highest value of all the series, instead of by its series. Ignored for
+///   stacked bars and in monochrome mode.
+/// - `flash_segment`: ([usize], [usize]) (optional): The series and category indices of a
+///   stacked bars segment to pulse, i.e., to point out where the user came back from.
+/// - `respect_reduced_motion`: [bool] (default: `true`): Skip the animations when a parent
+///   component provides a reduced [`MotionPreference`](crate::hooks::MotionPreference), keeping only the `class_bar_flash` class
+///   of the flashed segment.
+/// - `on_segment_activate`: [EventHandler]<[`SegmentEvent`]> (optional): Called when a stacked
+///   bars segment is clicked, with the segment rect as laid out on the chart.
+/// - `on_context_menu`: [EventHandler]<[`ContextMenu`]<[`SegmentEvent`]>> (optional): Called when
+///   a bar or stacked bars segment is right-clicked, with the segment, the pointer position and
+///   the segment position in the viewbox. The browser menu is only prevented when it is set.
+/// - `onclick_bar`: [EventHandler]<([usize], [usize])> (optional): Called with the series and
+///   category indices of the clicked bar or stacked segment, in the order of `series` whatever
+///   the `series_order`. The bars get a pointer cursor when it is set.
+/// - `onmouseover_bar`: [EventHandler]<([usize], [usize])> (optional): Like `onclick_bar` when
+///   the pointer enters a bar.
+/// - `onmouseout_bar`: [EventHandler]<([usize], [usize])> (optional): Like `onclick_bar` when
+///   the pointer leaves a bar.
 /// ---
 /// - `class_chart_bar`: &[str] (default: `"dx-chart-line"`): The HTML element `class` of the
-/// chart.
+///   chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
 /// - `class_bar`: &[str] (default: `"dx-bar"`): The HTML element `class` of the whole line.
 /// - `class_bar_group`: &[str] (default: `"dx-bar-group"`): The HTML element `class` of the line path.
 /// - `class_bar_label`: &[str] (default: `"dx-bar-label"`): The HTML element `class` of the line
-/// labels.
+///   labels.
+/// - `class_bar_flash`: &[str] (default: `"dx-bar-flash"`): The HTML element `class` of the
+///   segment selected by `flash_segment`.
+/// - `class_bar_hover`: &[str] (default: `"dx-bar--hover"`): The HTML element `class` added to
+///   the bar or stacked segment under the pointer.
+/// - `class_series`: &[str] (default: `"dx-series"`): The HTML element `class` prefix of every bar
+///   and stacked segment, followed by the series index, i.e., `dx-series-0`.
+/// - `class_flow`: &[str] (default: `"dx-flow"`): The HTML element `class` of the flow
+///   ribbons between stacked columns.
+/// - `class_bar_comparison`: &[str] (default: `"dx-bar-comparison"`): The HTML element `class` of
+///   the outlined comparison bars.
+/// - `class_bar_delta`: &[str] (default: `"dx-bar-delta"`): The HTML element `class` of the
+///   comparison delta labels.
+/// - `class_error_bar`: &[str] (default: `"dx-error-bar"`): The HTML element `class` of the
+///   error whiskers of every series.
+/// - `class_offset_marker`: &[str] (default: `"dx-offset-marker"`): The HTML element `class` of
+///   the lines marking the `offsets`.
+/// - `class_summary_band`: &[str] (default: `"dx-summary-band"`): The HTML element `class` of the
+///   summary band.
+/// - `class_reference_line`: &[str] (default: `"dx-reference-line"`): The HTML element `class` of
+///   every reference line with its label.
+/// - `class_reference_band`: &[str] (default: `"dx-reference-band"`): The HTML element `class` of
+///   every reference band.
+/// - `class_plot_background`: &[str] (default: `"dx-plot-background"`): The HTML element `class`
+///   of the plot area background.
+/// - `class_plot_border`: &[str] (default: `"dx-plot-border"`): The HTML element `class` of the
+///   plot area border.
+/// - `class_baseline`: &[str] (default: `"dx-baseline"`): The HTML element `class` of the line at
+///   zero on the value axis, where the bars start.
+/// - `class_axis_break`: &[str] (default: `"dx-axis-break"`): The HTML element `class` of the
+///   axis break marks.
 /// - `class_grid`: &[str] (default: `"dx-grid"`): The HTML element `class` of the grid.
 /// - `class_grid_line`: &[str] (default: `"dx-grid-line"`): The HTML element `class` of every grid
-/// line.
+///   line.
+/// - `class_grid_minor_line`: &[str] (default: `"dx-grid-minor-line"`): The HTML element `class`
+///   of every minor grid line of a log value axis.
 /// - `class_grid_label`: &[str] (default: `"dx-grid-label"`): The HTML element `class` of the grid
-/// labels.
+///   labels.
 /// - `class_grid_labels`: &[str] (default: `"dx-grid-labels"`): The HTML element `class` of the
-/// group of grid labels.
+///   group of grid labels.
+/// - `class_grid_emphasis`: &[str] (default: `"dx-grid-emphasis"`): The HTML element `class` of
+///   every emphasized grid line.
+/// - `class_tick_emphasis`: &[str] (default: `"dx-tick-emphasis"`): The HTML element `class` of
+///   every emphasized tick label, next to the grid label class.
+/// - `class_axis_title`: &[str] (default: `"dx-axis-title"`): The HTML element `class` of the
+///   axis titles.
 #[allow(non_snake_case)]
-pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
+pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element<'a> {
+    let hovered = use_state(&cx, || None::<(usize, usize)>);
+
+    if cx.props.series.iter().all(Vec::is_empty) {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart_bar,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
     for series in &cx.props.series {
         if series.is_empty() {
             return cx.render(rsx!("Bar chart error: empty series"));
         }
     }
 
-    let grid = {
-        let view = Rect::new(
-            cx.props.padding_left as f32,
-            cx.props.padding_top as f32,
-            (cx.props.viewbox_width - cx.props.padding_right) as f32,
-            (cx.props.viewbox_height - cx.props.padding_bottom) as f32,
-        );
+    if cx.props.domain.bounds(0.0, 1.0).is_none() {
+        return cx.render(rsx!("Bar chart error: empty or inverted manual domain"));
+    }
 
-        let lowest = if let Some(low) = cx.props.lowest {
-            low
-        } else {
-            0.0
-        };
+    if cx.props.scale == AxisScale::Log10
+        && cx.props.non_positive == NonPositive::Error
+        && cx.props.series.iter().flatten().any(|v| *v <= 0.0)
+    {
+        return cx.render(rsx!(
+            "Bar chart error: zero or negative values on a log axis"
+        ));
+    }
+
+    let number_format = label_number_format(
+        cx.props.number_format.as_ref(),
+        cx.props.label_decimals,
+        cx.props.rounding,
+    );
+
+    let horizontal = cx.props.horizontal_bars == Orientation::Horizontal;
+    let stacked = cx.props.stacked_bars || cx.props.stacked_percent;
+    let series = &if cx.props.stacked_percent {
+        percent_of_category(&cx.props.series)
+    } else {
+        cx.props.series.clone()
+    };
+    let label_interpolation = cx
+        .props
+        .label_interpolation
+        .or_else(|| cx.props.value_kind.label_interpolation());
+
+    let series_order = if let Some(order) = &cx.props.series_order {
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+
+        if !sorted.iter().copied().eq(0..series.len()) {
+            return cx.render(rsx!(
+                "Bar chart error: series_order is not a permutation of the series indices"
+            ));
+        }
+
+        order.clone()
+    } else {
+        (0..series.len()).collect()
+    };
+
+    let categories = series.iter().map(Vec::len).max().unwrap_or(0);
+    let column_weights = cx.props.column_weights.as_ref().filter(|_| stacked);
+
+    if let Some(weights) = column_weights {
+        if weights.len() != categories {
+            return cx.render(rsx!(
+                "Bar chart error: column_weights and categories lengths don't match"
+            ));
+        }
+    }
+
+    let offsets = cx.props.offsets.as_ref();
+    if offsets.is_some_and(|offsets| offsets.len() != categories) {
+        return cx.render(rsx!(
+            "Bar chart error: offsets and categories lengths don't match"
+        ));
+    }
+    let category_offset = |j: usize| offsets.map_or(0.0, |offsets| offsets[j]);
+
+    let stable_keys = match &cx.props.labels {
+        Some(labels) if cx.props.stable_keys => {
+            let mut sorted = labels.iter().collect::<Vec<&String>>();
+            sorted.sort_unstable();
+            sorted.dedup();
+
+            let unique = sorted.len() == labels.len() && labels.len() >= categories;
+            if !unique {
+                warn!("stable_keys ignored: the category labels are not unique");
+            }
+            unique
+        }
+        None if cx.props.stable_keys => {
+            warn!("stable_keys ignored: the chart has no category labels");
+            false
+        }
+        _ => false,
+    };
+    let category_keys = &match &cx.props.labels {
+        Some(labels) if stable_keys => labels.clone(),
+        _ => (0..categories)
+            .map(|j| j.to_string())
+            .collect::<Vec<String>>(),
+    };
+
+    let comparison_series = cx.props.comparison_series.as_ref().filter(|_| !stacked);
+    let shifted = |a: &Vec<f32>| {
+        a.iter()
+            .enumerate()
+            .map(|(j, v)| v + category_offset(j))
+            .collect::<Vec<f32>>()
+    };
+    let axis_series = match (comparison_series, offsets) {
+        (None, None) => None,
+        // The stacked sums start from the offsets
+        (_, Some(offsets)) if stacked => {
+            Some(series.iter().chain([offsets]).cloned().collect::<Series>())
+        }
+        (comparison, _) => Some(
+            series
+                .iter()
+                .chain(comparison.into_iter().flatten().filter(|a| !a.is_empty()))
+                .map(shifted)
+                .chain(offsets.cloned())
+                .collect::<Series>(),
+        ),
+    };
+    let offsets_lowest = offsets.map(|offsets| {
+        series
+            .iter()
+            .flat_map(shifted)
+            .chain(offsets.iter().copied())
+            .fold(0.0, f32::min)
+    });
+
+    // Horizontal bars make room for the category labels on the left, up to `label_size`
+    let padding_left = match &cx.props.labels {
+        Some(labels) if horizontal && cx.props.show_labels => {
+            let longest = labels
+                .iter()
+                .flat_map(|label| label.lines())
+                .map(|line| estimate_text_width(line, DEFAULT_FONT_SIZE))
+                .fold(0.0, f32::max);
+
+            (cx.props.padding_left as f32)
+                .max(longest.min(cx.props.label_size as f32) + LABEL_OFFSET)
+        }
+        _ => cx.props.padding_left as f32,
+    };
+    // Rotated category labels make room below the plot, up to `label_size`
+    let label_rotation = if horizontal {
+        0.0
+    } else {
+        cx.props.x_label_rotation
+    };
+    let padding_bottom = match &cx.props.labels {
+        Some(labels) if label_rotation != 0.0 && cx.props.show_labels => {
+            let height = rotated_labels_height(labels, label_rotation);
+
+            (cx.props.padding_bottom as f32)
+                .max(height.min(cx.props.label_size as f32) + LABEL_OFFSET)
+        }
+        _ => cx.props.padding_bottom as f32,
+    };
+    let title_room = |title: Option<&str>| title.map_or(0.0, |_| AXIS_TITLE_ROOM);
+
+    let view = Rect::new(
+        padding_left + title_room(cx.props.y_title),
+        cx.props.padding_top as f32,
+        (cx.props.viewbox_width - cx.props.padding_right) as f32,
+        cx.props.viewbox_height as f32 - padding_bottom - title_room(cx.props.x_title),
+    );
+    let (x_title, y_title) = axis_title_centers(&view, cx.props.viewbox_height as f32);
+
+    let grid = {
+        let lowest = cx.props.lowest.or(offsets_lowest);
 
         let max_ticks = cx.props.max_ticks.max(3);
 
@@ -194,42 +790,256 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
         let axis_y = Axis::builder()
             .with_view(view)
             .with_max_ticks(max_ticks)
+            .with_tick_steps(cx.props.tick_steps)
             .with_grid_ticks(cx.props.show_grid_ticks)
-            .with_series(&cx.props.series)
-            .with_stacked_series(cx.props.stacked_bars)
-            .with_label_interpolation(cx.props.label_interpolation)
-            .with_highest(cx.props.highest)
-            .with_lowest(Some(lowest));
+            .with_series(axis_series.as_ref().unwrap_or(series))
+            .with_stacked_series(stacked)
+            .with_label_interpolation(label_interpolation)
+            .with_value_kind(cx.props.value_kind)
+            .with_number_format(number_format.as_ref())
+            .with_divisor(cx.props.axis_divisor)
+            .with_unit(cx.props.axis_unit)
+            .with_highest(
+                cx.props
+                    .highest
+                    .or(cx.props.stacked_percent.then_some(100.0)),
+            )
+            .with_domain(cx.props.domain)
+            .with_scale(cx.props.scale)
+            .with_breaks(cx.props.axis_breaks.as_deref())
+            .with_zero(true)
+            .with_included(reference_values(cx.props.reference_lines.as_deref()))
+            .with_lowest(lowest);
 
-        if cx.props.horizontal_bars {
+        if horizontal {
             Grid::new(axis_y, axis_x)
         } else {
             Grid::new(axis_x, axis_y)
         }
     };
 
-    let (axis_value, axis_label) = if cx.props.horizontal_bars {
+    let (axis_value, axis_label) = if horizontal {
         (&grid.x, &grid.y)
     } else {
         (&grid.y, &grid.x)
     };
 
-    let lines = grid.lines();
+    let lines = grid.lines(cx.props.show_grid_x, cx.props.show_grid_y);
+    let minor_lines = if cx.props.show_minor_grid {
+        axis_value.minor_lines()
+    } else {
+        Vec::new()
+    };
+
+    // The room of every category along the label axis
+    let category_slot = match axis_label.tick_centers().as_slice() {
+        [first, second, ..] if horizontal => (second.y - first.y).abs(),
+        [first, second, ..] => (second.x - first.x).abs(),
+        _ if horizontal => view.height(),
+        _ => view.width(),
+    };
+    let group_size = if stacked {
+        1.0
+    } else {
+        series.len().max(1) as f32
+    };
+    let (bar_thickness, bar_distance) = if let Some(ratio) = cx.props.bar_gap_ratio {
+        let thickness = category_slot * (1.0 - ratio.clamp(0.0, 1.0)) / group_size;
+        (thickness, thickness)
+    } else {
+        let thickness = stroke_width_to_view(
+            cx.props.bar_width,
+            cx.props.viewbox_width as f32,
+            cx.props.viewbox_height as f32,
+        );
+        let group_width = (group_size - 1.0) * cx.props.bar_distance + thickness;
+        let shrink = if group_width > category_slot && group_width > 0.0 {
+            category_slot / group_width
+        } else {
+            1.0
+        };
+        (thickness * shrink, cx.props.bar_distance * shrink)
+    };
+    let column_spans = column_weights.map(|weights| {
+        if horizontal {
+            column_spans(
+                weights,
+                view.min.y,
+                view.height(),
+                cx.props.min_column_width,
+            )
+        } else {
+            column_spans(weights, view.min.x, view.width(), cx.props.min_column_width)
+        }
+    });
+    let column_spans = &column_spans;
+    let column_thickness = |j: usize| {
+        column_spans
+            .as_ref()
+            .map_or(bar_thickness, |spans| spans[j].1)
+    };
+    let segment_bounds = |rect: &Rect, thickness: f32| {
+        if horizontal {
+            (
+                rect.min.x.min(rect.max.x),
+                rect.min.y - thickness / 2.0,
+                (rect.max.x - rect.min.x).abs(),
+                thickness,
+            )
+        } else {
+            (
+                rect.min.x - thickness / 2.0,
+                rect.min.y.min(rect.max.y),
+                thickness,
+                (rect.max.y - rect.min.y).abs(),
+            )
+        }
+    };
+    // The rect clipping a bar to round the corners at its value end, reaching past its start so
+    // that the corners there stay square
+    let rounded_clip = |rect: &Rect, thickness: f32| {
+        let (x, y, width, height) = segment_bounds(rect, thickness);
+        let length = if horizontal { width } else { height };
+        let radius = cx.props.bar_radius.min(thickness / 2.0).min(length);
 
+        (radius > 0.0).then(|| {
+            if horizontal {
+                let x = if rect.max.x < rect.min.x {
+                    x
+                } else {
+                    x - radius
+                };
+                (x, y, width + radius, height, radius)
+            } else {
+                let y = if rect.max.y < rect.min.y {
+                    y
+                } else {
+                    y - radius
+                };
+                (x, y, width, height + radius, radius)
+            }
+        })
+    };
+    let rounded_clip = &rounded_clip;
+    let pattern_prefix = &format!("dx-pattern-{}", cx.scope_id().0);
+    let clip_prefix = &format!("dx-bar-clip-{}", cx.scope_id().0);
+    let reduced_motion = cx.props.respect_reduced_motion && use_reduced_motion(&cx);
+    let bar_style = if cx.props.onclick_bar.is_some() {
+        "cursor: pointer"
+    } else {
+        ""
+    };
+    let segment_style = if cx.props.on_segment_activate.is_some() {
+        "cursor: pointer"
+    } else {
+        bar_style
+    };
     let mut color_var = 255.0;
+    // The browser menu is left alone without a handler
+    let prevent_context_menu = if cx.props.on_context_menu.is_some() {
+        "oncontextmenu"
+    } else {
+        ""
+    };
+    let series_strokes = &(0..series.len())
+        .map(|i| {
+            color_var -= 75.0 * (1.0 / (i + 1) as f32);
+
+            match &cx.props.colors {
+                _ if cx.props.monochrome => format!("url(#{pattern_prefix}-{i})"),
+                Some(colors) if !colors.is_empty() => colors[i % colors.len()].clone(),
+                _ => format!("rgb({color_var}, 40, 40)"),
+            }
+        })
+        .collect::<Vec<String>>();
+    let summary_band = cx.props.summary_band.as_ref().map(|band| {
+        let low = axis_value.value_to_view(band.center - band.half_width);
+        let high = axis_value.value_to_view(band.center + band.half_width);
+        let center = axis_value.value_to_view(band.center);
+
+        let (rect, center_line) = if horizontal {
+            let (min, max) = (low.min(high).max(view.min.x), low.max(high).min(view.max.x));
+            (
+                Rect::new(min, view.min.y, max.max(min), view.max.y),
+                (view.min.x..=view.max.x)
+                    .contains(&center)
+                    .then(|| Rect::new(center, view.min.y, center, view.max.y)),
+            )
+        } else {
+            let (min, max) = (low.min(high).max(view.min.y), low.max(high).min(view.max.y));
+            (
+                Rect::new(view.min.x, min, view.max.x, max.max(min)),
+                (view.min.y..=view.max.y)
+                    .contains(&center)
+                    .then(|| Rect::new(view.min.x, center, view.max.x, center)),
+            )
+        };
+
+        (
+            rect,
+            center_line.filter(|_| band.show_center_line),
+            band.class.as_deref().unwrap_or_default(),
+        )
+    });
+
+    let reference_lines = reference_lines(axis_value, cx.props.reference_lines.as_deref());
+    let reference_bands = cx
+        .props
+        .reference_bands
+        .iter()
+        .flatten()
+        .filter_map(|(a, b)| axis_value.cross_band(*a, *b))
+        .collect::<Vec<Rect>>();
+
+    let diverging = cx.props.diverging.filter(|_| !cx.props.monochrome);
+    let (data_lowest, data_highest) = cx
+        .props
+        .series
+        .iter()
+        .flatten()
+        .fold((f32::MAX, f32::MIN), |(low, high), v| {
+            (low.min(*v), high.max(*v))
+        });
+
+    let (label_divisor, label_unit) = if cx.props.scale_value_labels {
+        (
+            cx.props.axis_divisor.filter(|d| *d > 0.0).unwrap_or(1.0),
+            cx.props.axis_unit,
+        )
+    } else {
+        (1.0, None)
+    };
+    let format_value = |v: f32| {
+        format_scaled(
+            v,
+            label_interpolation,
+            number_format.as_ref(),
+            label_divisor,
+            label_unit,
+        )
+    };
+
     let dotted_stroke = if cx.props.show_dotted_grid {
         &"2px"
     } else {
         &"0px"
     };
     let generated_labels = axis_value.generated_labels();
+    let emphasis = axis_value.emphasis(
+        cx.props.emphasized_ticks.as_deref(),
+        cx.props.label_emphasized,
+    );
 
+    // The emphasized tick labels are drawn bold on their own
     let grid_labels = if cx.props.show_labels {
         Some(
             axis_value
                 .text_data(generated_labels.len())
                 .into_iter()
                 .zip(generated_labels.iter())
+                .enumerate()
+                .filter(|(i, _)| !emphasis.ticks.contains(i))
+                .map(|(_, label)| label)
                 .collect::<Vec<(TextData, &String)>>(),
         )
     } else {
@@ -237,11 +1047,14 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
     };
 
     let grid_centered_labels = if cx.props.show_labels {
-        let rects = axis_label
-            .centered_text_rects(cx.props.labels.as_ref().unwrap().len() as i32)
-            .into_iter();
+        let rects = if let Some(spans) = column_spans {
+            axis_label.span_text_rects(spans)
+        } else {
+            axis_label.centered_text_rects(cx.props.labels.as_ref().unwrap().len() as i32)
+        }
+        .into_iter();
 
-        let labels = if cx.props.horizontal_bars {
+        let labels = if horizontal {
             rects
                 .zip(cx.props.labels.as_ref().unwrap().iter().rev())
                 .collect::<Vec<(Rect, &String)>>()
@@ -251,49 +1064,137 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                 .collect::<Vec<(Rect, &String)>>()
         };
 
-        Some(labels)
+        let overflow = axis_label.label_overflow(
+            &labels
+                .iter()
+                .map(|(_, label)| *label)
+                .collect::<Vec<&String>>(),
+            cx.props.tick_overflow,
+            cx.props.x_label_step.filter(|_| !horizontal),
+            label_rotation,
+        );
+
+        Some(
+            labels
+                .into_iter()
+                .zip(overflow)
+                .filter_map(|((mut rect, label), offset)| {
+                    offset.map(|offset| {
+                        rect.min.y += offset;
+                        (rect, label)
+                    })
+                })
+                .collect::<Vec<(Rect, &String)>>(),
+        )
     } else {
         None
     };
 
-    let stacked_bars_rects = if cx.props.stacked_bars {
-        let tick_centers = axis_label.tick_centers();
-        let mut all_series_rects = Vec::<Vec<Rect>>::new();
-        let mut last_bar_ends: Option<Vec<f32>> = None;
-
-        for a in cx.props.series.iter() {
-            let mut rects = Vec::<Rect>::new();
-            let mut view_bar_ends = Vec::<f32>::new();
+    let category_centers = if let Some(spans) = column_spans {
+        spans
+            .iter()
+            .map(|(start, width)| {
+                if horizontal {
+                    Point::new(view.min.x, start + width / 2.0)
+                } else {
+                    Point::new(start + width / 2.0, view.max.y)
+                }
+            })
+            .collect()
+    } else {
+        axis_label.tick_centers()
+    };
 
-            for (point, (i, v)) in tick_centers.iter().zip(a.iter().enumerate()) {
-                let rect = if let Some(bar_ends) = &last_bar_ends {
-                    let last_end = bar_ends[i];
-                    let end = axis_value.world_to_view(v + last_end, 0.0);
-                    view_bar_ends.push(v + last_end);
+    let offset_markers = offsets
+        .filter(|_| cx.props.show_offset_markers)
+        .map(|offsets| {
+            let group_width = if stacked {
+                0.0
+            } else {
+                (series.len() as f32 - 1.0) * bar_distance
+            };
 
-                    let last_end = axis_value.world_to_view(last_end, 0.0);
+            category_centers
+                .iter()
+                .zip(offsets)
+                .enumerate()
+                .map(|(j, (point, offset))| {
+                    let position = axis_value.value_to_view(*offset);
+                    let half_width = (group_width + column_thickness(j)) / 2.0;
 
-                    if cx.props.horizontal_bars {
-                        Rect::new(last_end, point.y, end, point.y)
+                    if horizontal {
+                        Rect::new(
+                            position,
+                            point.y - half_width,
+                            position,
+                            point.y + half_width,
+                        )
                     } else {
-                        Rect::new(point.x, last_end, point.x, end)
+                        Rect::new(
+                            point.x - half_width,
+                            position,
+                            point.x + half_width,
+                            position,
+                        )
                     }
-                } else {
-                    let end = axis_value.world_to_view(*v, 0.0);
-                    view_bar_ends.push(*v);
+                })
+                .collect::<Vec<Rect>>()
+        })
+        .unwrap_or_default();
 
-                    if cx.props.horizontal_bars {
-                        Rect::new(point.x, point.y, end, point.y)
-                    } else {
-                        Rect::new(point.x, point.y, point.x, end)
-                    }
+    // Where the bars start: zero, or the edge of the axis when zero is outside of it
+    let baseline = axis_value.value_to_view(axis_value.clamp_value(0.0));
+
+    let baseline_line = (axis_value.clamp_value(0.0) == 0.0).then(|| {
+        if horizontal {
+            Rect::new(baseline, view.min.y, baseline, view.max.y)
+        } else {
+            Rect::new(view.min.x, baseline, view.max.x, baseline)
+        }
+    });
+
+    // The series of the outermost positive and negative segments of every stacked category
+    let mut outermost_segments = vec![(None::<usize>, None::<usize>); categories];
+    let stacked_bars_rects = if stacked {
+        let tick_centers = &category_centers;
+        let mut all_series_rects = Vec::<(usize, Vec<Rect>)>::new();
+        // The ends of the stacks of every category, the positive values going up from the
+        // offsets and the negative ones down
+        let bases = (0..categories).map(category_offset).collect::<Vec<f32>>();
+        let (mut positive_ends, mut negative_ends) = (bases.clone(), bases);
+
+        for &series_index in &series_order {
+            let a = &series[series_index];
+            let mut rects = Vec::<Rect>::new();
+
+            for (point, (i, v)) in tick_centers.iter().zip(a.iter().enumerate()) {
+                let ends = if *v < 0.0 {
+                    &mut negative_ends
+                } else {
+                    &mut positive_ends
+                };
+                let start = if offsets.is_some() || ends[i] != 0.0 {
+                    axis_value.value_to_view(ends[i])
+                } else {
+                    baseline
                 };
+                ends[i] += v;
+                let end = axis_value.value_to_view(ends[i]);
 
-                rects.push(rect);
+                if *v > 0.0 {
+                    outermost_segments[i].0 = Some(series_index);
+                } else if *v < 0.0 {
+                    outermost_segments[i].1 = Some(series_index);
+                }
+
+                rects.push(if horizontal {
+                    Rect::new(start, point.y, end, point.y)
+                } else {
+                    Rect::new(point.x, start, point.x, end)
+                });
             }
 
-            all_series_rects.push(rects);
-            last_bar_ends = Some(view_bar_ends);
+            all_series_rects.push((series_index, rects));
         }
 
         Some(all_series_rects)
@@ -301,6 +1202,135 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
         None
     };
 
+    let outermost_segments = &outermost_segments;
+
+    let flows = stacked_bars_rects
+        .as_ref()
+        .filter(|_| cx.props.show_flows)
+        .map(|all_series_rects| {
+            all_series_rects
+                .iter()
+                .map(|(i, rects)| {
+                    let mut path = PathBuilder::with_capacity(6 * rects.len());
+
+                    for (j, pair) in rects.windows(2).enumerate() {
+                        let (from, to) = (&pair[0], &pair[1]);
+                        let from_edge = column_thickness(j) / 2.0;
+                        let to_edge = column_thickness(j + 1) / 2.0;
+
+                        // Built along the category axis, then swapped for horizontal bars
+                        let (start, end, a0, b0, a1, b1) = if horizontal {
+                            (
+                                from.min.y + from_edge,
+                                to.min.y - to_edge,
+                                from.min.x,
+                                from.max.x,
+                                to.min.x,
+                                to.max.x,
+                            )
+                        } else {
+                            (
+                                from.min.x + from_edge,
+                                to.min.x - to_edge,
+                                from.min.y,
+                                from.max.y,
+                                to.min.y,
+                                to.max.y,
+                            )
+                        };
+                        let middle = (start + end) / 2.0;
+                        let point = |along: f32, across: f32| {
+                            if horizontal {
+                                Point::new(across, along)
+                            } else {
+                                Point::new(along, across)
+                            }
+                        };
+
+                        path.move_to(point(start, a0))
+                            .cubic_to(point(middle, a0), point(middle, a1), point(end, a1))
+                            .line_to(point(end, b1))
+                            .cubic_to(point(middle, b1), point(middle, b0), point(start, b0));
+                        path.close();
+                    }
+
+                    (*i, path.build())
+                })
+                .collect::<Vec<(usize, String)>>()
+        });
+
+    let break_positions = axis_value.break_positions();
+    let break_glyphs = if break_positions.is_empty() {
+        Vec::new()
+    } else {
+        // The bars as (center across the value axis, start and end along it, thickness)
+        let bars = if let Some(all_series_rects) = &stacked_bars_rects {
+            all_series_rects
+                .iter()
+                .flat_map(|(_, rects)| rects.iter().enumerate())
+                .map(|(j, rect)| {
+                    let thickness = column_thickness(j);
+
+                    if horizontal {
+                        (rect.min.y, rect.min.x, rect.max.x, thickness)
+                    } else {
+                        (rect.min.x, rect.min.y, rect.max.y, thickness)
+                    }
+                })
+                .collect::<Vec<(f32, f32, f32, f32)>>()
+        } else {
+            let tick_centers = axis_label.tick_centers();
+
+            series
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| {
+                    let offset = (i as f32 - (series.len() as f32 - 1.0) / 2.0) * bar_distance;
+
+                    tick_centers.iter().zip(a.iter()).map(move |(point, v)| {
+                        let end = axis_value.value_to_view(*v);
+
+                        if horizontal {
+                            (point.y + offset, baseline, end, bar_thickness)
+                        } else {
+                            (point.x + offset, baseline, end, bar_thickness)
+                        }
+                    })
+                })
+                .collect()
+        };
+
+        let axis_marks = break_positions.iter().map(|position| {
+            if horizontal {
+                (Point::new(*position, view.max.y), 2.0 * BREAK_MARK_OVERHANG)
+            } else {
+                (Point::new(view.min.x, *position), 2.0 * BREAK_MARK_OVERHANG)
+            }
+        });
+        let bar_marks = bars.iter().flat_map(|(across, start, end, thickness)| {
+            break_positions
+                .iter()
+                .filter(move |position| {
+                    start.min(*end) < **position && **position < start.max(*end)
+                })
+                .map(move |position| {
+                    let center = if horizontal {
+                        Point::new(*position, *across)
+                    } else {
+                        Point::new(*across, *position)
+                    };
+                    (center, thickness + 2.0 * BREAK_MARK_OVERHANG)
+                })
+        });
+
+        axis_marks
+            .chain(bar_marks)
+            .map(|(center, size)| break_glyph(center, size, horizontal))
+            .collect::<Vec<(String, String)>>()
+    };
+
+    let (plot_width, plot_height) = (view.width(), view.height());
+
     cx.render(rsx! {
         div {
             svg {
@@ -310,14 +1340,67 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                 class: "{cx.props.class_chart_bar}",
                 preserveAspectRatio: "xMidYMid meet",
                 view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                cx.props.monochrome.then(|| rsx! {
+                    defs {
+                        key: "patterns",
+                        series.iter().enumerate().map(|(i, _)| {
+                            let fill = grey(i);
+                            let d = fill_pattern(i);
+
+                            rsx! {
+                                pattern {
+                                    key: "pattern-{i}",
+                                    id: "{pattern_prefix}-{i}",
+                                    width: "8",
+                                    height: "8",
+                                    patternUnits: "userSpaceOnUse",
+                                    rect {
+                                        width: "8",
+                                        height: "8",
+                                        fill: "{fill}",
+                                    },
+                                    path {
+                                        d: "{d}",
+                                        stroke: "white",
+                                        stroke_width: "1.5",
+                                        stroke_linecap: "round",
+                                    }
+                                }
+                            }
+                        })
+                    }
+                }),
+                cx.props.show_plot_background.then(|| rsx! {
+                    rect {
+                        key: "plot_background",
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_background}",
+                        fill: "white",
+                    }
+                }),
                 cx.props.show_grid.then(|| rsx! {
                     g {
                         key: "grid",
                         class: "{cx.props.class_grid}",
-                        lines.iter().map(|line| {
+                        minor_lines.iter().enumerate().map(|(n, line)| rsx! {
+                            line {
+                                key: "grid-minor-line-{n}",
+                                x1: "{line.min.x}",
+                                y1: "{line.min.y}",
+                                x2: "{line.max.x}",
+                                y2: "{line.max.y}",
+                                class: "{cx.props.class_grid_minor_line}",
+                                stroke: "rgba(20, 20, 20, 0.3)",
+                                stroke_dasharray: "{dotted_stroke}",
+                            }
+                        }),
+                        lines.iter().enumerate().map(|(n, line)| {
                             rsx! {
                                 line {
-                                    key: "{line.min}",
+                                    key: "grid-line-{n}",
                                     x1: "{line.min.x}",
                                     y1: "{line.min.y}",
                                     x2: "{line.max.x}",
@@ -328,20 +1411,118 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                                 }
                             }
                         }),
+                        emphasis.lines.iter().enumerate().map(|(n, line)| {
+                            rsx! {
+                                line {
+                                    key: "grid-emphasis-{n}",
+                                    x1: "{line.min.x}",
+                                    y1: "{line.min.y}",
+                                    x2: "{line.max.x}",
+                                    y2: "{line.max.y}",
+                                    class: "{cx.props.class_grid_emphasis}",
+                                    stroke: "rgb(20, 20, 20)",
+                                    stroke_width: "1.5",
+                                }
+                            }
+                        }),
+                    }
+                }),
+                summary_band.map(|(rect, center_line, class)| {
+                    let (width, height) = (rect.width(), rect.height());
+
+                    rsx! {
+                        g {
+                            key: "summary_band",
+                            class: "{cx.props.class_summary_band} {class}",
+                            rect {
+                                x: "{rect.min.x}",
+                                y: "{rect.min.y}",
+                                width: "{width}",
+                                height: "{height}",
+                                fill: "rgb(40, 40, 40)",
+                                fill_opacity: "0.1",
+                            },
+                            center_line.map(|line| rsx! {
+                                line {
+                                    x1: "{line.min.x}",
+                                    y1: "{line.min.y}",
+                                    x2: "{line.max.x}",
+                                    y2: "{line.max.y}",
+                                    stroke: "rgb(40, 40, 40)",
+                                    stroke_dasharray: "4px",
+                                }
+                            }),
+                        }
+                    }
+                }),
+                (!reference_bands.is_empty()).then(|| rsx! {
+                    g {
+                        key: "reference_bands",
+                        reference_bands.iter().enumerate().map(|(n, rect)| {
+                            let (width, height) = (rect.width(), rect.height());
+
+                            rsx! {
+                                rect {
+                                    key: "reference-band-{n}",
+                                    x: "{rect.min.x}",
+                                    y: "{rect.min.y}",
+                                    width: "{width}",
+                                    height: "{height}",
+                                    class: "{cx.props.class_reference_band}",
+                                    fill: "rgb(40, 40, 40)",
+                                    fill_opacity: "0.08",
+                                }
+                            }
+                        })
+                    }
+                }),
+                cx.props.show_plot_border.then(|| rsx! {
+                    rect {
+                        key: "plot_border",
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_border}",
+                        fill: "none",
+                        stroke: "rgba(20, 20, 20, 0.8)",
+                    }
+                }),
+                baseline_line.map(|line| rsx! {
+                    line {
+                        key: "baseline",
+                        x1: "{line.min.x}",
+                        y1: "{line.min.y}",
+                        x2: "{line.max.x}",
+                        y2: "{line.max.y}",
+                        class: "{cx.props.class_baseline}",
+                        stroke: "rgba(20, 20, 20, 0.8)",
                     }
                 }),
                 grid_labels.map(|labels| rsx! {
                     g {
                         key: "grid_labels",
                         class: "{cx.props.class_grid_labels}",
-                        labels.iter().map(|(text, label)| rsx! {
+                        labels.iter().enumerate().map(|(n, (text, label))| rsx! {
+                            MultilineText {
+                                key: "grid-label-{n}",
+                                x: text.x,
+                                y: text.y,
+                                text: label.as_str(),
+                                text_anchor: text.anchor,
+                                class: cx.props.class_grid_label,
+                                alignment_baseline: text.baseline,
+                            }
+                        }),
+                        emphasis.labels.iter().enumerate().map(|(n, (text, label))| rsx! {
                             text {
-                                key: "{label}",
+                                key: "grid-emphasis-label-{n}",
                                 dx: "{text.x}",
                                 dy: "{text.y}",
                                 text_anchor: "{text.anchor}",
-                                class: "{cx.props.class_grid_label}",
+                                class: "{cx.props.class_grid_label} {cx.props.class_tick_emphasis}",
                                 alignment_baseline: "{text.baseline}",
+                                font_weight: "bold",
                                 [label.as_str()]
                             }
                         })
@@ -351,39 +1532,65 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                     g {
                         key: "grid_centered_labels",
                         class: "{cx.props.class_grid_labels}",
-                        labels.iter().map(|(rect, label)| rsx! {
-                            foreignObject {
-                                key: "{label}",
-                                x: "{rect.min.x}",
-                                y: "{rect.min.y}",
-                                width: "{rect.max.x}",
-                                height: "{rect.max.y}",
-                                if cx.props.horizontal_bars {
-                                    rsx! {
-                                        span {
-                                            key: "{label}",
-                                            class: "{cx.props.class_grid_label}",
-                                            //width: "100%",
-                                            height: "100%",
-                                            display: "inline-flex",
-                                            align_items: "center",
-                                            line_height: "1",
-                                            float: "right",
-                                            text_align: "right",
-                                            [label.as_str()]
-                                        }
+                        labels.iter().enumerate().map(|(n, (rect, label))| {
+                            let key = if stable_keys {
+                                label.to_string()
+                            } else {
+                                n.to_string()
+                            };
+
+                            // Rotated labels hang from the middle of the top of their rect
+                            if label_rotation != 0.0 {
+                                let x = rect.min.x + rect.max.x / 2.0;
+                                let anchor = if label_rotation < 0.0 { "end" } else { "start" };
+
+                                return rsx! {
+                                    MultilineText {
+                                        key: "category-label-{key}",
+                                        x: x,
+                                        y: rect.min.y,
+                                        text: label.as_str(),
+                                        text_anchor: anchor,
+                                        class: cx.props.class_grid_label,
+                                        rotation: label_rotation,
                                     }
-                                } else {
-                                    rsx! {
-                                        span {
-                                            key: "{label}",
-                                            class: "{cx.props.class_grid_label}",
-                                            width: "100%",
-                                            height: "100%",
-                                            display: "inline-block",
-                                            line_height: "1",
-                                            text_align: "center",
-                                            [label.as_str()]
+                                };
+                            }
+
+                            rsx! {
+                                foreignObject {
+                                    key: "category-label-{key}",
+                                    x: "{rect.min.x}",
+                                    y: "{rect.min.y}",
+                                    width: "{rect.max.x}",
+                                    height: "{rect.max.y}",
+                                    if horizontal {
+                                        rsx! {
+                                            span {
+                                                class: "{cx.props.class_grid_label}",
+                                                //width: "100%",
+                                                height: "100%",
+                                                display: "inline-flex",
+                                                align_items: "center",
+                                                line_height: "1",
+                                                float: "right",
+                                                text_align: "right",
+                                                white_space: "pre-line",
+                                                [label.as_str()]
+                                            }
+                                        }
+                                    } else {
+                                        rsx! {
+                                            span {
+                                                class: "{cx.props.class_grid_label}",
+                                                width: "100%",
+                                                height: "100%",
+                                                display: "inline-block",
+                                                line_height: "1",
+                                                text_align: "center",
+                                                white_space: "pre-line",
+                                                [label.as_str()]
+                                            }
                                         }
                                     }
                                 }
@@ -391,26 +1598,150 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                         })
                     }
                 }),
+                cx.props.x_title.map(|title| rsx! {
+                    text {
+                        key: "x_title",
+                        x: "{x_title.x}",
+                        y: "{x_title.y}",
+                        text_anchor: "middle",
+                        alignment_baseline: "middle",
+                        class: "{cx.props.class_axis_title}",
+                        "{title}"
+                    }
+                }),
+                cx.props.y_title.map(|title| rsx! {
+                    text {
+                        key: "y_title",
+                        x: "{y_title.x}",
+                        y: "{y_title.y}",
+                        transform: "rotate(-90 {y_title.x} {y_title.y})",
+                        text_anchor: "middle",
+                        alignment_baseline: "middle",
+                        class: "{cx.props.class_axis_title}",
+                        "{title}"
+                    }
+                }),
+                flows.map(|flows| rsx! {
+                    g {
+                        key: "flows",
+                        class: "{cx.props.class_flow}",
+                        flows.iter().map(|(i, path)| {
+                            let fill = &series_strokes[*i];
+
+                            rsx! {
+                                path {
+                                    key: "flow-{i}",
+                                    d: "{path}",
+                                    class: "{cx.props.class_flow}-{i}",
+                                    fill: "{fill}",
+                                    fill_opacity: "0.2",
+                                }
+                            }
+                        })
+                    }
+                }),
                 stacked_bars_rects.map(|all_series_rects| rsx! {
-                    all_series_rects.iter().enumerate().map(|(i, series_rects)| {
-                        color_var -= 75.0 * (1.0 / (i + 1) as f32);
+                    all_series_rects.iter().map(|&(i, ref series_rects)| {
+                        let stroke = &series_strokes[i];
 
                         rsx! {
                             g {
-                                key: "{i}",
+                                key: "series-{i}",
                                 class: "{cx.props.class_bar_group}-{i}",
                                 {
-                                    series_rects.iter().map(|rect| {
+                                    series_rects
+                                        .iter()
+                                        .enumerate()
+                                        .filter(move |(j, _)| cx.props.series[i][*j] != 0.0)
+                                        .map(move |(j, rect)| {
+                                        let category_key = &category_keys[j];
+                                        let thickness = column_thickness(j);
+                                        let (x, y, width, height) = segment_bounds(rect, thickness);
+                                        let event = SegmentEvent {
+                                            series: i,
+                                            category: j,
+                                            value: cx.props.series[i][j],
+                                            x,
+                                            y,
+                                            width,
+                                            height,
+                                        };
+                                        let flashing = cx.props.flash_segment == Some((i, j));
+                                        let class_flash = if flashing {
+                                            cx.props.class_bar_flash
+                                        } else {
+                                            ""
+                                        };
+                                        let class_hover = if *hovered.get() == Some((i, j)) {
+                                            cx.props.class_bar_hover
+                                        } else {
+                                            ""
+                                        };
+                                        let (positive, negative) = outermost_segments[j];
+                                        let clip = (positive == Some(i) || negative == Some(i))
+                                            .then(|| rounded_clip(rect, thickness))
+                                            .flatten();
+                                        let clip_path = clip.map_or(String::new(), |_| format!("url(#{clip_prefix}-{i}-{j})"));
+
                                         rsx! {
+                                            clip.map(|(x, y, width, height, radius)| rsx! {
+                                                clipPath {
+                                                    key: "segment-clip-{category_key}",
+                                                    id: "{clip_prefix}-{i}-{j}",
+                                                    rect {
+                                                        x: "{x}",
+                                                        y: "{y}",
+                                                        width: "{width}",
+                                                        height: "{height}",
+                                                        rx: "{radius}",
+                                                    }
+                                                }
+                                            }),
                                             line {
-                                                key: "{rect}",
+                                                key: "segment-{category_key}",
+                                                clip_path: "{clip_path}",
                                                 x1: "{rect.min.x}",
                                                 y1: "{rect.min.y}",
                                                 x2: "{rect.max.x}",
                                                 y2: "{rect.max.y}",
-                                                class: "{cx.props.class_bar}",
-                                                stroke: "rgb({color_var}, 40, 40)",
-                                                stroke_width: "{cx.props.bar_width}",
+                                                class: "{cx.props.class_bar} {cx.props.class_series}-{i} {class_flash} {class_hover}",
+                                                stroke: "{stroke}",
+                                                stroke_width: "{thickness}",
+                                                style: "{segment_style}",
+                                                onclick: move |_| {
+                                                    if let Some(handler) = &cx.props.on_segment_activate {
+                                                        handler.call(event);
+                                                    }
+                                                    if let Some(handler) = &cx.props.onclick_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                onmouseover: move |_| {
+                                                    hovered.set(Some((i, j)));
+                                                    if let Some(handler) = &cx.props.onmouseover_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                onmouseout: move |_| {
+                                                    hovered.set(None);
+                                                    if let Some(handler) = &cx.props.onmouseout_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                prevent_default: "{prevent_context_menu}",
+                                                oncontextmenu: move |e| {
+                                                    if let Some(handler) = &cx.props.on_context_menu {
+                                                        handler.call(ContextMenu::new(event, &e.data, event.middle()));
+                                                    }
+                                                },
+                                                (flashing && !reduced_motion).then(|| rsx! {
+                                                    animate {
+                                                        attributeName: "opacity",
+                                                        values: "1;0.3;1",
+                                                        dur: "0.8s",
+                                                        repeatCount: "3",
+                                                    }
+                                                }),
                                             }
                                         }
                                     })
@@ -419,70 +1750,237 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                         }
                     })
                 }),
-                (!cx.props.stacked_bars).then(|| {
+                (!stacked).then(|| {
                     rsx! {
-                        cx.props.series.iter().enumerate().map(|(i, a)| {
-                            color_var -= 75.0 * (1.0 / (i + 1) as f32);
-                            let offset = (i as f32 - (cx.props.series.len() as f32 - 1.0) / 2.0) * cx.props.bar_distance;
+                        series.iter().enumerate().map(|(i, a)| {
+                            let stroke = &series_strokes[i];
+                            let offset = (i as f32 - (series.len() as f32 - 1.0) / 2.0) * bar_distance;
                             let tick_centers = axis_label.tick_centers();
+                            let whiskers = cx.props.bar_errors
+                                .as_ref()
+                                .and_then(|errors| errors.get(i))
+                                .map(|errors| {
+                                    let mut whiskers = PathBuilder::with_capacity(3 * errors.len());
+
+                                    for (j, (point, v)) in tick_centers.iter().zip(a.iter()).enumerate() {
+                                        if let Some((low, high)) = errors.get(j).copied().flatten() {
+                                            let across = if horizontal { point.y + offset } else { point.x + offset };
+                                            let v = v + category_offset(j);
+                                            axis_value.whisker(&mut whiskers, across, v - low, v + high, ERROR_CAP_WIDTH);
+                                        }
+                                    }
+
+                                    whiskers.build()
+                                })
+                                .filter(|whiskers| !whiskers.is_empty());
 
                             rsx! {
                                 g {
-                                    key: "{i}",
+                                    key: "series-{i}",
                                     class: "{cx.props.class_bar_group}-{i}",
                                     tick_centers
                                         .iter()
                                         .zip(a.iter())
-                                        .map(|(point, v)| {
+                                        .enumerate()
+                                        .map(|(j, (point, v))| {
+                                        let category_key = &category_keys[j];
+                                        let end = axis_value.value_to_view(v + category_offset(j));
+                                        let start = if offsets.is_some() {
+                                            axis_value.value_to_view(category_offset(j))
+                                        } else {
+                                            baseline
+                                        };
+                                        let bar_label = match cx.props.label_format {
+                                            _ if !cx.props.show_series_labels => String::new(),
+                                            Some(label_format) => label_format(BarLabel { series: i, category: j, value: *v }),
+                                            None => format_value(*v),
+                                        };
 
-                                        let end = axis_value.world_to_view(*v, 0.0);
-                                        let (rect, text) = if cx.props.horizontal_bars {
+                                        // Inside labels only stay inside the bars long enough to hold them
+                                        let inside = cx.props.value_position == ValuePosition::Inside && {
+                                            let room = if horizontal {
+                                                estimate_text_width(&bar_label, DEFAULT_FONT_SIZE)
+                                            } else {
+                                                DEFAULT_FONT_SIZE
+                                            };
+                                            (end - start).abs() >= room + 10.0
+                                        };
+                                        // The labels go towards the values growing, flipped for the
+                                        // negative bars and for the labels inside the bars
+                                        let negative = *v < 0.0;
+                                        let forward = negative == inside;
+                                        let (rect, text) = if horizontal {
                                             (
-                                                Rect::new(point.x, point.y + offset, end, point.y + offset),
+                                                Rect::new(start, point.y + offset, end, point.y + offset),
                                                 TextData {
-                                                    x: end + 5.0,
+                                                    x: if forward { end + 5.0 } else { end - 5.0 },
                                                     y: point.y + offset,
-                                                    anchor: "start",
+                                                    anchor: if forward { "start" } else { "end" },
                                                     baseline: "middle"
                                                 }
                                             )
                                         } else {
                                             (
-                                                Rect::new(point.x + offset, point.y, point.x + offset, end),
+                                                Rect::new(point.x + offset, start, point.x + offset, end),
                                                 TextData {
                                                     x: point.x + offset,
-                                                    y: end - 5.0,
+                                                    y: if forward { end - 5.0 } else { end + 5.0 },
                                                     anchor: "middle",
-                                                    baseline: "text-bottom"
+                                                    baseline: if forward { "text-bottom" } else { "hanging" }
                                                 }
                                             )
                                         };
 
-                                        let bar_label = {
-                                            if !cx.props.show_series_labels {
-                                                String::new()
-                                            } else if let Some(func) = cx.props.label_interpolation {
-                                                func(*v)
-                                            } else {
-                                                format!("{}", *v)
-                                            }
+                                        let bar_stroke = diverging
+                                            .map(|scale| scale.color_for_value(*v, data_lowest, data_highest))
+                                            .unwrap_or_else(|| stroke.clone());
+                                        let class_hover = if *hovered.get() == Some((i, j)) {
+                                            cx.props.class_bar_hover
+                                        } else {
+                                            ""
                                         };
+                                        let clip = rounded_clip(&rect, bar_thickness);
+                                        let clip_path = clip.map_or(String::new(), |_| format!("url(#{clip_prefix}-{i}-{j})"));
+                                        let (x, y, width, height) = segment_bounds(&rect, bar_thickness);
+                                        let event = SegmentEvent {
+                                            series: i,
+                                            category: j,
+                                            value: *v,
+                                            x,
+                                            y,
+                                            width,
+                                            height,
+                                        };
+
+                                        let comparison = comparison_series
+                                            .and_then(|comparison| comparison.get(i))
+                                            .and_then(|comparison| comparison.get(j))
+                                            .map(|c| {
+                                                let end = axis_value.value_to_view(c + category_offset(j));
+                                                let rect = if horizontal {
+                                                    Rect::new(start, point.y + offset, end, point.y + offset)
+                                                } else {
+                                                    Rect::new(point.x + offset, start, point.x + offset, end)
+                                                };
+                                                let (x, y, width, height) = segment_bounds(&rect, bar_thickness);
+
+                                                let delta = v - c;
+                                                let delta_label = if delta > 0.0 {
+                                                    format!("+{}", format_value(delta))
+                                                } else {
+                                                    format_value(delta)
+                                                };
+
+                                                // Placed past both bars, and past the value label
+                                                // when there is one.
+                                                let delta_text = if horizontal {
+                                                    let label_width = if cx.props.show_series_labels {
+                                                        estimate_text_width(&bar_label, DEFAULT_FONT_SIZE) + 5.0
+                                                    } else {
+                                                        0.0
+                                                    };
+
+                                                    TextData {
+                                                        x: rect.max.x.max(text.x - 5.0) + 5.0 + label_width,
+                                                        y: point.y + offset,
+                                                        anchor: "start",
+                                                        baseline: "middle",
+                                                    }
+                                                } else {
+                                                    let label_height = if cx.props.show_series_labels {
+                                                        DEFAULT_FONT_SIZE
+                                                    } else {
+                                                        0.0
+                                                    };
+
+                                                    TextData {
+                                                        x: point.x + offset,
+                                                        y: rect.max.y.min(text.y + 5.0) - 5.0 - label_height,
+                                                        anchor: "middle",
+                                                        baseline: "text-bottom",
+                                                    }
+                                                };
+
+                                                (x, y, width, height, delta_label, delta_text)
+                                            });
 
                                         rsx! {
+                                            comparison.map(|(x, y, width, height, delta_label, delta_text)| rsx! {
+                                                rect {
+                                                    key: "comparison-{category_key}",
+                                                    x: "{x}",
+                                                    y: "{y}",
+                                                    width: "{width}",
+                                                    height: "{height}",
+                                                    class: "{cx.props.class_bar_comparison}",
+                                                    fill: "none",
+                                                    stroke: "{stroke}",
+                                                    opacity: "0.6",
+                                                },
+                                                cx.props.show_comparison_delta.then(|| rsx! {
+                                                    text {
+                                                        key: "delta-{category_key}",
+                                                        dx: "{delta_text.x}",
+                                                        dy: "{delta_text.y}",
+                                                        text_anchor: "{delta_text.anchor}",
+                                                        class: "{cx.props.class_bar_delta}",
+                                                        alignment_baseline: "{delta_text.baseline}",
+                                                        [delta_label.as_str()]
+                                                    }
+                                                }),
+                                            }),
+                                            clip.map(|(x, y, width, height, radius)| rsx! {
+                                                clipPath {
+                                                    key: "bar-clip-{category_key}",
+                                                    id: "{clip_prefix}-{i}-{j}",
+                                                    rect {
+                                                        x: "{x}",
+                                                        y: "{y}",
+                                                        width: "{width}",
+                                                        height: "{height}",
+                                                        rx: "{radius}",
+                                                    }
+                                                }
+                                            }),
                                             line {
-                                                key: "{v}",
+                                                key: "bar-{category_key}",
+                                                clip_path: "{clip_path}",
                                                 x1: "{rect.min.x}",
                                                 y1: "{rect.min.y}",
                                                 x2: "{rect.max.x}",
                                                 y2: "{rect.max.y}",
-                                                class: "{cx.props.class_bar}",
-                                                stroke: "rgb({color_var}, 40, 40)",
-                                                stroke_width: "{cx.props.bar_width}",
+                                                class: "{cx.props.class_bar} {cx.props.class_series}-{i} {class_hover}",
+                                                stroke: "{bar_stroke}",
+                                                stroke_width: "{bar_thickness}",
+                                                style: "{bar_style}",
+                                                onclick: move |_| {
+                                                    if let Some(handler) = &cx.props.onclick_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                onmouseover: move |_| {
+                                                    hovered.set(Some((i, j)));
+                                                    if let Some(handler) = &cx.props.onmouseover_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                onmouseout: move |_| {
+                                                    hovered.set(None);
+                                                    if let Some(handler) = &cx.props.onmouseout_bar {
+                                                        handler.call((i, j));
+                                                    }
+                                                },
+                                                prevent_default: "{prevent_context_menu}",
+                                                oncontextmenu: move |e| {
+                                                    if let Some(handler) = &cx.props.on_context_menu {
+                                                        handler.call(ContextMenu::new(event, &e.data, event.middle()));
+                                                    }
+                                                },
                                             },
                                             cx.props.show_series_labels.then(|| {
                                                 rsx! {
                                                     text {
-                                                        key: "{bar_label}",
+                                                        key: "bar-label-{category_key}",
                                                         dx: "{text.x}",
                                                         dy: "{text.y}",
                                                         text_anchor: "{text.anchor}",
@@ -493,7 +1991,86 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
                                                 }
                                             }),
                                         }
-                                    })
+                                    }),
+                                    whiskers.map(|whiskers| rsx! {
+                                        path {
+                                            key: "errors",
+                                            d: "{whiskers}",
+                                            class: "{cx.props.class_error_bar}",
+                                            stroke: "rgba(20, 20, 20, 0.8)",
+                                            stroke_width: "1.5",
+                                            fill: "none",
+                                        }
+                                    }),
+                                }
+                            }
+                        })
+                    }
+                }),
+                (!reference_lines.is_empty()).then(|| rsx! {
+                    g {
+                        key: "reference_lines",
+                        reference_lines.iter().enumerate().map(|(n, (line, text, reference))| {
+                            let class = reference.class.as_deref().unwrap_or_default();
+
+                            rsx! {
+                                g {
+                                    key: "reference-line-{n}",
+                                    class: "{cx.props.class_reference_line} {class}",
+                                    line {
+                                        x1: "{line.min.x}",
+                                        y1: "{line.min.y}",
+                                        x2: "{line.max.x}",
+                                        y2: "{line.max.y}",
+                                        stroke: "rgb(40, 40, 40)",
+                                        stroke_dasharray: "6 3",
+                                    },
+                                    reference.label.as_ref().map(|label| rsx! {
+                                        text {
+                                            x: "{text.x}",
+                                            y: "{text.y}",
+                                            text_anchor: "{text.anchor}",
+                                            alignment_baseline: "{text.baseline}",
+                                            "{label}"
+                                        }
+                                    }),
+                                }
+                            }
+                        })
+                    }
+                }),
+                (!offset_markers.is_empty()).then(|| rsx! {
+                    g {
+                        key: "offset_markers",
+                        class: "{cx.props.class_offset_marker}",
+                        offset_markers.iter().enumerate().map(|(j, line)| rsx! {
+                            line {
+                                key: "offset-marker-{j}",
+                                x1: "{line.min.x}",
+                                y1: "{line.min.y}",
+                                x2: "{line.max.x}",
+                                y2: "{line.max.y}",
+                                stroke: "rgba(20, 20, 20, 0.4)",
+                                stroke_dasharray: "4px",
+                            }
+                        })
+                    }
+                }),
+                (!break_glyphs.is_empty()).then(|| rsx! {
+                    g {
+                        key: "axis_breaks",
+                        class: "{cx.props.class_axis_break}",
+                        break_glyphs.iter().enumerate().map(|(n, (gap, slashes))| rsx! {
+                            g {
+                                key: "axis-break-{n}",
+                                path {
+                                    d: "{gap}",
+                                    fill: "white",
+                                },
+                                path {
+                                    d: "{slashes}",
+                                    stroke: "rgba(20, 20, 20, 0.8)",
+                                    fill: "none",
                                 }
                             }
                         })
@@ -503,3 +2080,147 @@ pub fn BarChart<'a>(cx: Scope<'a, BarChartProps<'a>>) -> Element {
         }
     })
 }
+
+/// Splits the `length` of the plot along the category axis from `start` into one
+/// `(start, width)` span for each column, proportional to the weights and separated by
+/// [`WEIGHTED_COLUMN_GAP`]. Columns that would be narrower than `min_width` get exactly
+/// `min_width`, and the rest share what is left.
+fn column_spans(weights: &[f32], start: f32, length: f32, min_width: f32) -> Vec<(f32, f32)> {
+    let available = length - WEIGHTED_COLUMN_GAP * weights.len().saturating_sub(1) as f32;
+    let mut floored = weights
+        .iter()
+        .enumerate()
+        .map(|(j, weight)| {
+            if *weight > 0.0 {
+                false
+            } else {
+                warn!("column weight {weight} of category {j} clamped to the minimum width");
+                true
+            }
+        })
+        .collect::<Vec<bool>>();
+
+    // Flooring a column leaves less for the others, which may push more of them under the floor
+    loop {
+        let free = available - min_width * floored.iter().filter(|f| **f).count() as f32;
+        let total: f32 = weights
+            .iter()
+            .zip(&floored)
+            .filter(|(_, f)| !**f)
+            .map(|(w, _)| w)
+            .sum();
+        let mut changed = false;
+
+        for (weight, floor) in weights.iter().zip(floored.iter_mut()) {
+            if !*floor && weight / total * free < min_width {
+                *floor = true;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            let mut offset = start;
+
+            return weights
+                .iter()
+                .zip(&floored)
+                .map(|(weight, floor)| {
+                    let width = if *floor {
+                        min_width
+                    } else {
+                        weight / total * free
+                    };
+                    let span = (offset, width);
+                    offset += width + WEIGHTED_COLUMN_GAP;
+                    span
+                })
+                .collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::{duplicate_sibling_keys, rendered_attributes, rendered_keys};
+
+    fn bar_keys(app: Component) -> Vec<String> {
+        rendered_keys(app)
+            .into_iter()
+            .filter(|key| key.starts_with("bar-") && !key.starts_with("bar-label-"))
+            .collect()
+    }
+
+    // The two successive datasets of a live chart, where B overtook A and D replaced C
+    fn first(cx: Scope) -> Element {
+        cx.render(rsx! {
+            BarChart {
+                series: vec![vec![3.0, 2.0, 1.0]],
+                labels: vec!["A".into(), "B".into(), "C".into()],
+                stable_keys: true,
+            }
+        })
+    }
+    fn second(cx: Scope) -> Element {
+        cx.render(rsx! {
+            BarChart {
+                series: vec![vec![3.0, 2.0, 1.0]],
+                labels: vec!["B".into(), "A".into(), "D".into()],
+                stable_keys: true,
+            }
+        })
+    }
+
+    #[test]
+    fn stable_keys_follow_the_persisting_categories() {
+        let (before, after) = (bar_keys(first), bar_keys(second));
+
+        assert_eq!(before.len(), 3);
+        // A and B swapped places and keep their keys, C and D don't share one
+        assert_eq!((&before[0], &before[1]), (&after[1], &after[0]));
+        assert!(!after.contains(&before[2]));
+    }
+
+    #[test]
+    fn positional_keys_follow_the_positions() {
+        fn positional(cx: Scope) -> Element {
+            cx.render(rsx! {
+                BarChart {
+                    series: vec![vec![3.0, 2.0, 1.0]],
+                    labels: vec!["B".into(), "A".into(), "D".into()],
+                }
+            })
+        }
+
+        assert_eq!(bar_keys(positional), ["bar-0", "bar-1", "bar-2"]);
+    }
+
+    #[test]
+    fn duplicate_labels_get_distinct_keys() {
+        fn duplicates(cx: Scope) -> Element {
+            cx.render(rsx! {
+                BarChart {
+                    series: vec![vec![3.0, 2.0], vec![1.0, 4.0]],
+                    labels: vec!["Other".into(), "Other".into()],
+                    stable_keys: true,
+                }
+            })
+        }
+
+        assert!(rendered_keys(duplicates).len() > 2);
+        assert_eq!(duplicate_sibling_keys(duplicates), Vec::<String>::new());
+    }
+
+    #[test]
+    fn empty_series_render_the_empty_state() {
+        fn no_series(cx: Scope) -> Element {
+            cx.render(rsx! { BarChart { series: vec![] } })
+        }
+        fn empty_series(cx: Scope) -> Element {
+            cx.render(rsx! { BarChart { series: vec![vec![], vec![]], labels: vec![] } })
+        }
+
+        assert!(rendered_attributes(no_series, "class").contains(&"dx-empty".to_string()));
+        assert!(rendered_attributes(empty_series, "class").contains(&"dx-empty".to_string()));
+    }
+}
@@ -0,0 +1,346 @@
+use dioxus::prelude::*;
+
+use crate::charts::legend_row::{LegendRow, LEGEND_ROW_HEIGHT};
+use crate::types::Labels;
+use crate::utils::color::red_shade;
+use crate::utils::format::{round_to, RoundingMode};
+use crate::utils::text::{estimate_text_width, DEFAULT_FONT_SIZE};
+use crate::utils::transform::{sanitize_series, small_shares};
+
+/// The space kept on both sides of the percentage labels inside their segments.
+const SEGMENT_LABEL_PADDING: f32 = 4.0;
+
+/// The `CompositionBar` properties struct for the configuration of the composition bar.
+#[derive(Props)]
+pub struct CompositionBarProps<'a> {
+    series: Vec<f32>,
+    #[props(optional)]
+    labels: Option<Labels>,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+
+    #[props(default = "100%")]
+    width: &'a str,
+    #[props(default = "100%")]
+    height: &'a str,
+    #[props(default = 600)]
+    viewbox_width: i32,
+    #[props(default = 60)]
+    viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+
+    #[props(default)]
+    padding: f32,
+    #[props(default = 24.0)]
+    bar_height: f32,
+    #[props(default = 6.0)]
+    corner_radius: f32,
+    #[props(default = true)]
+    show_labels: bool,
+    #[props(default = true)]
+    show_legend: bool,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+    #[props(optional)]
+    min_visible_ratio: Option<f32>,
+    #[props(default = "Other")]
+    other_label: &'a str,
+
+    #[props(optional)]
+    onclick_segment: Option<EventHandler<'a, usize>>,
+    #[props(optional)]
+    onmouseover_segment: Option<EventHandler<'a, usize>>,
+
+    #[props(default = "dx-composition-bar")]
+    class_chart: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
+    #[props(default = "dx-composition-track")]
+    class_track: &'a str,
+    #[props(default = "dx-segment")]
+    class_segment: &'a str,
+    #[props(default = "dx-segment-label")]
+    class_label: &'a str,
+    #[props(default = "dx-legend")]
+    class_legend: &'a str,
+    #[props(default = "dx-legend-item")]
+    class_legend_item: &'a str,
+}
+
+/// This is the `CompositionBar` function used to render a single horizontal bar split into the
+/// shares of its values, like a storage usage breakdown, in an `Element`.
+/// In Dioxus, components are just functions, so this is the main `CompositionBar`
+/// component to be used inside `rsx!` macros in your code.
+///
+/// The bar spans the whole view box width, and the segments are laid out from left to right in
+/// the order of the series. Only the outer corners of the bar are rounded.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::CompositionBar;
+///
+/// fn app(cx: Scope) -> Element {
+///     cx.render(rsx! {
+///         CompositionBar {
+///             series: vec![120.0, 64.0, 22.0, 3.0, 2.0],
+///             labels: vec![
+///                 "Photos".into(),
+///                 "Apps".into(),
+///                 "System".into(),
+///                 "Mail".into(),
+///                 "Notes".into(),
+///             ],
+///             min_visible_ratio: 0.02,
+///         }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `series`: [Vec]<[f32]> (**required**): The values of the segments. `NaN`, infinite and
+///   negative values count as zero, and zero values have no segment.
+/// - `labels`: [Vec]<[String]> (optional): The names of the segments, shown in the legend.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the segments, cycled when there are
+///   more segments than colors. The generated shades of red are used when not set.
+/// ---
+/// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
+///   other CSS style, i.e., "200px"
+/// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
+/// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
+///   `viewbox_height` it is useful scaling up or down the chart and labels.
+/// - `viewbox_height`: [i32] (default: `60`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown on the empty track when the
+///   values add up to zero.
+/// ---
+/// - `padding`: [f32] (default: `0.0`): Padding for every side of the SVG view box.
+/// - `bar_height`: [f32] (default: `24.0`): The height of the bar.
+/// - `corner_radius`: [f32] (default: `6.0`): The radius of the outer corners of the bar.
+/// - `show_labels`: [bool] (default: `true`): Show the percentage of every segment inside it,
+///   when it is wide enough for the text.
+/// - `show_legend`: [bool] (default: `true`): Show the legend row with the `labels` below the
+///   bar.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the percentages, which are
+///   whole percentages when not set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the percentages are
+///   rounded to the `label_decimals`.
+/// - `min_visible_ratio`: [f32] (optional): Groups the values whose share of the bar is below
+///   it, i.e., `0.02` for 2%, into a single segment at the end, when there are at least two of
+///   them.
+/// - `other_label`: &[str] (default: `"Other"`): The legend label of the grouped segment.
+/// ---
+/// - `onclick_segment`: [EventHandler]<[usize]> (optional): Called with the index of the value
+///   in the series when its segment is clicked, or the length of the series for the grouped
+///   segment.
+/// - `onmouseover_segment`: [EventHandler]<[usize]> (optional): Called like `onclick_segment`
+///   when the mouse enters a segment.
+/// ---
+/// - `class_chart`: &[str] (default: `"dx-composition-bar"`): The HTML element `class` of the
+///   chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
+/// - `class_track`: &[str] (default: `"dx-composition-track"`): The HTML element `class` of the
+///   track behind the segments.
+/// - `class_segment`: &[str] (default: `"dx-segment"`): The HTML element `class` for every
+///   segment, also followed by the segment index, i.e., `dx-segment-0`.
+/// - `class_label`: &[str] (default: `"dx-segment-label"`): The HTML element `class` for the
+///   percentages inside the segments.
+/// - `class_legend`: &[str] (default: `"dx-legend"`): The HTML element `class` of the legend row.
+/// - `class_legend_item`: &[str] (default: `"dx-legend-item"`): The HTML element `class` for
+///   every legend label.
+#[allow(non_snake_case)]
+pub fn CompositionBar<'a>(cx: Scope<'a, CompositionBarProps<'a>>) -> Element<'a> {
+    let values = sanitize_series(&cx.props.series);
+    let total: f32 = values.iter().sum();
+
+    // The grouped values move to a single segment after the others
+    let grouped = cx
+        .props
+        .min_visible_ratio
+        .and_then(|ratio| small_shares(&values, total, ratio));
+    let (values, labels) = match &grouped {
+        Some(grouped) => {
+            let mut kept = values
+                .iter()
+                .zip(grouped)
+                .map(|(v, g)| if *g { 0.0 } else { *v })
+                .collect::<Vec<f32>>();
+            kept.push(
+                values
+                    .iter()
+                    .zip(grouped)
+                    .filter(|(_, g)| **g)
+                    .map(|(v, _)| v)
+                    .sum(),
+            );
+            let labels = cx.props.labels.clone().map(|mut labels| {
+                labels.resize(cx.props.series.len(), String::new());
+                labels.push(cx.props.other_label.to_string());
+                labels
+            });
+
+            (kept, labels)
+        }
+        None => (values, cx.props.labels.clone()),
+    };
+
+    let has_legend = cx.props.show_legend && labels.is_some() && total > 0.0;
+    let legend_height = if has_legend { LEGEND_ROW_HEIGHT } else { 0.0 };
+    let left = cx.props.padding;
+    let bar_width = (cx.props.viewbox_width as f32 - 2.0 * cx.props.padding).max(0.0);
+    let top = (cx.props.viewbox_height as f32 - legend_height - cx.props.bar_height) / 2.0;
+    let clip_id = format!("dx-composition-clip-{}", cx.scope_id().0);
+
+    let color = |i: usize| match &cx.props.colors {
+        Some(colors) if !colors.is_empty() => colors[i % colors.len()].clone(),
+        _ => format!("rgb({}, 40, 40)", red_shade(i)),
+    };
+
+    let mut x = left;
+    let segments = values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| **v > 0.0)
+        .map(|(i, v)| {
+            let ratio = v / total;
+            let width = ratio * bar_width;
+            let decimals = cx.props.label_decimals.unwrap_or(0);
+            let percent = round_to(f64::from(ratio * 100.0), decimals, cx.props.rounding);
+            let label = format!("{percent:.decimals$}%");
+            let fits = estimate_text_width(&label, DEFAULT_FONT_SIZE) + 2.0 * SEGMENT_LABEL_PADDING
+                <= width;
+            let segment = (i, x, width, color(i), fits.then_some(label));
+            x += width;
+
+            segment
+        })
+        .collect::<Vec<(usize, f32, f32, String, Option<String>)>>();
+
+    let legend_items = labels
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(i, _)| values.get(*i).is_some_and(|v| *v > 0.0))
+        .collect::<Vec<(usize, &String)>>();
+    let label_y = top + cx.props.bar_height / 2.0;
+    let center_x = left + bar_width / 2.0;
+
+    cx.render(rsx! {
+        div {
+            svg {
+                view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                width: "{cx.props.width}",
+                height: "{cx.props.height}",
+                class: "{cx.props.class_chart}",
+                preserveAspectRatio: "xMidYMid meet",
+                xmlns: "http://www.w3.org/2000/svg",
+                defs {
+                    clipPath {
+                        id: "{clip_id}",
+                        rect {
+                            x: "{left}",
+                            y: "{top}",
+                            width: "{bar_width}",
+                            height: "{cx.props.bar_height}",
+                            rx: "{cx.props.corner_radius}",
+                        }
+                    }
+                },
+                rect {
+                    x: "{left}",
+                    y: "{top}",
+                    width: "{bar_width}",
+                    height: "{cx.props.bar_height}",
+                    rx: "{cx.props.corner_radius}",
+                    class: "{cx.props.class_track}",
+                    fill: "rgba(20, 20, 20, 0.1)",
+                },
+                (total <= 0.0).then(|| rsx! {
+                    text {
+                        dx: "{center_x}",
+                        dy: "{label_y}",
+                        text_anchor: "middle",
+                        alignment_baseline: "middle",
+                        class: "{cx.props.class_empty}",
+                        "{cx.props.empty_text}"
+                    }
+                }),
+                g {
+                    clip_path: "url(#{clip_id})",
+                    segments.iter().map(|(i, x, width, color, label)| {
+                        let i = *i;
+                        let label_x = x + width / 2.0;
+
+                        rsx! {
+                            g {
+                                key: "segment-{i}",
+                                class: "{cx.props.class_segment} {cx.props.class_segment}-{i}",
+                                onclick: move |_| {
+                                    if let Some(handler) = &cx.props.onclick_segment {
+                                        handler.call(i);
+                                    }
+                                },
+                                onmouseover: move |_| {
+                                    if let Some(handler) = &cx.props.onmouseover_segment {
+                                        handler.call(i);
+                                    }
+                                },
+                                rect {
+                                    x: "{x}",
+                                    y: "{top}",
+                                    width: "{width}",
+                                    height: "{cx.props.bar_height}",
+                                    fill: "{color}",
+                                },
+                                label.as_ref().filter(|_| cx.props.show_labels).map(|label| rsx! {
+                                    text {
+                                        dx: "{label_x}",
+                                        dy: "{label_y}",
+                                        text_anchor: "middle",
+                                        alignment_baseline: "middle",
+                                        class: "{cx.props.class_label}",
+                                        fill: "white",
+                                        font_size: "{DEFAULT_FONT_SIZE}",
+                                        "{label}"
+                                    }
+                                }),
+                            }
+                        }
+                    })
+                },
+                has_legend.then(|| rsx! {
+                    LegendRow {
+                        items: legend_items
+                            .iter()
+                            .map(|(i, label)| (*i, color(*i), label.to_string()))
+                            .collect(),
+                        viewbox_width: cx.props.viewbox_width,
+                        viewbox_height: cx.props.viewbox_height,
+                        class: cx.props.class_legend,
+                        class_item: cx.props.class_legend_item,
+                    }
+                }),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::rendered_attributes;
+
+    #[test]
+    fn empty_data_renders_the_empty_state() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! { CompositionBar { series: vec![] } })
+        }
+
+        assert!(rendered_attributes(app, "class").contains(&"dx-empty".to_string()));
+    }
+}
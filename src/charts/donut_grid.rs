@@ -0,0 +1,343 @@
+use dioxus::prelude::*;
+
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::legend_row::{LegendRow, LEGEND_ROW_HEIGHT};
+use crate::types::Point;
+use crate::utils::color::red_shade;
+use crate::utils::format::{round_to, RoundingMode};
+use crate::utils::path::PathBuilder;
+use crate::utils::polar_to_cartesian;
+use crate::utils::transform::is_valid_share;
+
+/// A single metric of the [DonutGrid], drawn as a donut filled up to `value / max`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DonutMetric {
+    /// The name of the metric, shown in the legend.
+    pub label: String,
+    /// The current value. `NaN`, infinite and negative values count as zero.
+    pub value: f32,
+    /// The value that fills the whole donut.
+    pub max: f32,
+    /// The CSS color of the donut, overriding the default shade.
+    pub color: Option<String>,
+}
+
+/// The `DonutGrid` properties struct for the configuration of the donut grid.
+#[derive(Props)]
+pub struct DonutGridProps<'a> {
+    metrics: Vec<DonutMetric>,
+    #[props(default = 2)]
+    columns: usize,
+
+    #[props(default = "100%")]
+    width: &'a str,
+    #[props(default = "100%")]
+    height: &'a str,
+    #[props(default = 600)]
+    viewbox_width: i32,
+    #[props(default = 400)]
+    viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
+
+    #[props(default = 10.0)]
+    cell_padding: f32,
+    #[props(default = 16.0)]
+    donut_width: f32,
+    #[props(default = true)]
+    show_values: bool,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+    #[props(default = true)]
+    show_legend: bool,
+
+    #[props(optional)]
+    onclick_metric: Option<EventHandler<'a, usize>>,
+
+    #[props(default = "dx-donut-grid")]
+    class_chart: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
+    #[props(default = "dx-donut")]
+    class_donut: &'a str,
+    #[props(default = "dx-donut-track")]
+    class_track: &'a str,
+    #[props(default = "dx-donut-arc")]
+    class_arc: &'a str,
+    #[props(default = "dx-donut-value")]
+    class_value: &'a str,
+    #[props(default = "dx-legend")]
+    class_legend: &'a str,
+    #[props(default = "dx-legend-item")]
+    class_legend_item: &'a str,
+}
+
+/// This is the `DonutGrid` function used to render a grid of donut charts, one for every
+/// metric, in a single `Element`.
+/// In Dioxus, components are just functions, so this is the main `DonutGrid`
+/// component to be used inside `rsx!` macros in your code.
+///
+/// All the donuts share the same size, taken from an equal split of the view box into cells,
+/// and start at 12 o'clock. The metric labels go in a single legend row below the grid.
+///
+/// The donuts are drawn in their final state: the grid doesn't animate them, so there is no
+/// shared tween to keep their timing in step.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::charts::donut_grid::DonutMetric;
+/// use dioxus_charts::DonutGrid;
+///
+/// fn app(cx: Scope) -> Element {
+///     let selected = use_state(&cx, || None::<usize>);
+///     let metric = |label: &str, value| DonutMetric {
+///         label: label.into(),
+///         value,
+///         max: 100.0,
+///         color: None,
+///     };
+///
+///     cx.render(rsx! {
+///         DonutGrid {
+///             columns: 4,
+///             viewbox_height: 180,
+///             metrics: vec![
+///                 metric("CPU", 42.0),
+///                 metric("RAM", 71.5),
+///                 metric("Disk", 18.0),
+///                 metric("Network", 5.2),
+///             ],
+///             onclick_metric: move |i| selected.set(Some(i)),
+///         }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `metrics`: [Vec]<[`DonutMetric`]> (**required**): The metrics to draw, in order from left to
+///   right and then top to bottom.
+/// - `columns`: [usize] (default: `2`): The number of donuts in every row.
+/// ---
+/// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
+///   other CSS style, i.e., "200px"
+/// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
+/// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
+///   `viewbox_height` it is useful scaling up or down the chart and labels.
+/// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
+/// ---
+/// - `cell_padding`: [f32] (default: `10.0`): The space between a donut and the edges of its
+///   cell.
+/// - `donut_width`: [f32] (default: `16.0`): The width of the donut rings.
+/// - `show_values`: [bool] (default: `true`): Show the value of every metric as a percentage of
+///   its `max` in the middle of its donut.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the values, which are whole
+///   percentages when not set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the values are rounded
+///   to the `label_decimals`.
+/// - `show_legend`: [bool] (default: `true`): Show the legend row with the metric labels.
+/// ---
+/// - `onclick_metric`: [EventHandler]<[usize]> (optional): Called with the metric index when its
+///   donut is clicked.
+/// ---
+/// - `class_chart`: &[str] (default: `"dx-donut-grid"`): The HTML element `class` of the grid.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
+/// - `class_donut`: &[str] (default: `"dx-donut"`): The HTML element `class` for the group of
+///   every donut.
+/// - `class_track`: &[str] (default: `"dx-donut-track"`): The HTML element `class` for the empty
+///   ring behind the values.
+/// - `class_arc`: &[str] (default: `"dx-donut-arc"`): The HTML element `class` for the value
+///   arcs.
+/// - `class_value`: &[str] (default: `"dx-donut-value"`): The HTML element `class` for the values
+///   in the middle of the donuts.
+/// - `class_legend`: &[str] (default: `"dx-legend"`): The HTML element `class` of the legend row.
+/// - `class_legend_item`: &[str] (default: `"dx-legend-item"`): The HTML element `class` for
+///   every legend label.
+#[allow(non_snake_case)]
+pub fn DonutGrid<'a>(cx: Scope<'a, DonutGridProps<'a>>) -> Element<'a> {
+    if cx.props.metrics.is_empty() {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
+    let count = cx.props.metrics.len();
+    let columns = cx.props.columns.clamp(1, count);
+    let rows = count.div_ceil(columns);
+
+    let legend_height = if cx.props.show_legend {
+        LEGEND_ROW_HEIGHT
+    } else {
+        0.0
+    };
+    let cell_width = cx.props.viewbox_width as f32 / columns as f32;
+    let cell_height = (cx.props.viewbox_height as f32 - legend_height) / rows as f32;
+    // The ring is centered on the stroke, so the outer edge is half the donut width further
+    let radius = ((cell_width.min(cell_height) / 2.0 - cx.props.cell_padding)
+        - cx.props.donut_width / 2.0)
+        .max(0.0);
+
+    let donuts = cx
+        .props
+        .metrics
+        .iter()
+        .enumerate()
+        .map(|(i, metric)| {
+            let center = Point::new(
+                (i % columns) as f32 * cell_width + cell_width / 2.0,
+                (i / columns) as f32 * cell_height + cell_height / 2.0,
+            );
+            let ratio = if metric.max > 0.0 && is_valid_share(metric.value) {
+                (metric.value / metric.max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let end_angle = (ratio * 360.0).min(359.99);
+
+            let mut arc = PathBuilder::with_capacity(2);
+            arc.move_to(polar_to_cartesian(center, radius, 0.0)).arc_to(
+                radius,
+                end_angle > 180.0,
+                true,
+                polar_to_cartesian(center, radius, end_angle),
+            );
+
+            let color = metric
+                .color
+                .clone()
+                .unwrap_or_else(|| format!("rgb({}, 40, 40)", red_shade(i)));
+            let decimals = cx.props.label_decimals.unwrap_or(0);
+            let percent = round_to(f64::from(ratio * 100.0), decimals, cx.props.rounding);
+            let value = format!("{percent:.decimals$}%");
+
+            (center, (ratio > 0.0).then(|| arc.build()), color, value)
+        })
+        .collect::<Vec<(Point, Option<String>, String, String)>>();
+
+    cx.render(rsx! {
+        div {
+            svg {
+                view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                width: "{cx.props.width}",
+                height: "{cx.props.height}",
+                class: "{cx.props.class_chart}",
+                preserveAspectRatio: "xMidYMid meet",
+                xmlns: "http://www.w3.org/2000/svg",
+                donuts.iter().enumerate().map(|(i, (center, arc, color, value))| rsx! {
+                    g {
+                        key: "donut-{i}",
+                        class: "{cx.props.class_donut} {cx.props.class_donut}-{i}",
+                        onclick: move |_| {
+                            if let Some(handler) = &cx.props.onclick_metric {
+                                handler.call(i);
+                            }
+                        },
+                        circle {
+                            cx: "{center.x}",
+                            cy: "{center.y}",
+                            r: "{radius}",
+                            class: "{cx.props.class_track}",
+                            stroke: "rgba(20, 20, 20, 0.1)",
+                            stroke_width: "{cx.props.donut_width}",
+                            fill: "none",
+                        },
+                        arc.as_ref().map(|d| rsx! {
+                            path {
+                                d: "{d}",
+                                class: "{cx.props.class_arc}",
+                                stroke: "{color}",
+                                stroke_width: "{cx.props.donut_width}",
+                                fill: "none",
+                            }
+                        }),
+                        cx.props.show_values.then(|| rsx! {
+                            text {
+                                dx: "{center.x}",
+                                dy: "{center.y}",
+                                text_anchor: "middle",
+                                alignment_baseline: "middle",
+                                class: "{cx.props.class_value}",
+                                "{value}"
+                            }
+                        }),
+                    }
+                }),
+                cx.props.show_legend.then(|| rsx! {
+                    LegendRow {
+                        items: donuts
+                            .iter()
+                            .zip(&cx.props.metrics)
+                            .enumerate()
+                            .map(|(i, ((_, _, color, _), metric))| (i, color.clone(), metric.label.clone()))
+                            .collect(),
+                        viewbox_width: cx.props.viewbox_width,
+                        viewbox_height: cx.props.viewbox_height,
+                        class: cx.props.class_legend,
+                        class_item: cx.props.class_legend_item,
+                    }
+                }),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::{rendered_attributes, rendered_texts};
+
+    #[test]
+    fn empty_data_renders_the_empty_state() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! { DonutGrid { metrics: vec![] } })
+        }
+
+        assert!(rendered_attributes(app, "class").contains(&"dx-empty".to_string()));
+    }
+
+    #[test]
+    fn invalid_values_count_as_zero() {
+        fn app(cx: Scope) -> Element {
+            let metric = |value| DonutMetric {
+                label: "CPU".into(),
+                value,
+                max: 100.0,
+                color: None,
+            };
+
+            cx.render(rsx! {
+                DonutGrid {
+                    metrics: vec![metric(f32::NAN), metric(f32::INFINITY), metric(50.0)],
+                    columns: 3,
+                }
+            })
+        }
+
+        let texts = rendered_texts(app);
+
+        assert!(texts.iter().all(|t| !t.contains("NaN")), "{texts:?}");
+        assert_eq!(texts.iter().filter(|t| *t == "0%").count(), 2, "{texts:?}");
+        assert!(texts.contains(&"50%".to_string()), "{texts:?}");
+    }
+}
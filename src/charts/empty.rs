@@ -0,0 +1,52 @@
+use dioxus::prelude::*;
+
+/// The placeholder drawn by every chart when there is no data, configured from its props.
+pub(crate) struct EmptyState<'a> {
+    pub width: &'a str,
+    pub height: &'a str,
+    pub viewbox_width: i32,
+    pub viewbox_height: i32,
+    pub class_chart: &'a str,
+    pub class_empty: &'a str,
+    pub text: &'a str,
+    /// The content replacing the whole placeholder when set.
+    pub render: &'a Element<'a>,
+}
+
+/// Renders an empty chart of the configured size with the message in the middle, or the
+/// `render` override instead.
+pub(crate) fn render_empty<'a, P>(cx: Scope<'a, P>, state: EmptyState<'a>) -> Element<'a> {
+    if state.render.is_some() {
+        return cx.render(rsx! {
+            div {
+                state.render
+            }
+        });
+    }
+
+    let (x, y) = (
+        state.viewbox_width as f32 / 2.0,
+        state.viewbox_height as f32 / 2.0,
+    );
+
+    cx.render(rsx! {
+        div {
+            svg {
+                view_box: "0 0 {state.viewbox_width} {state.viewbox_height}",
+                width: "{state.width}",
+                height: "{state.height}",
+                class: "{state.class_chart}",
+                preserveAspectRatio: "xMidYMid meet",
+                xmlns: "http://www.w3.org/2000/svg",
+                text {
+                    dx: "{x}",
+                    dy: "{y}",
+                    text_anchor: "middle",
+                    alignment_baseline: "middle",
+                    class: "{state.class_empty}",
+                    "{state.text}"
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+
+use crate::charts::multiline_text::MultilineText;
+use crate::utils::text::DEFAULT_FONT_SIZE;
+
+/// The height of the legend row at the bottom of the viewbox.
+pub(crate) const LEGEND_ROW_HEIGHT: f32 = 24.0;
+/// The size of the legend color swatches.
+const LEGEND_SWATCH_SIZE: f32 = 10.0;
+/// The space between a swatch and its label.
+const LEGEND_SWATCH_GAP: f32 = 4.0;
+
+/// The `LegendRow` properties struct.
+#[derive(Props)]
+pub(crate) struct LegendRowProps<'a> {
+    /// The key, the CSS color and the label of every item.
+    items: Vec<(usize, String, String)>,
+    viewbox_width: i32,
+    viewbox_height: i32,
+    class: &'a str,
+    class_item: &'a str,
+}
+
+/// A legend of color swatches and labels spread evenly along a [`LEGEND_ROW_HEIGHT`] row at the
+/// bottom of the viewbox, which the chart leaves free.
+#[allow(non_snake_case)]
+pub(crate) fn LegendRow<'a>(cx: Scope<'a, LegendRowProps<'a>>) -> Element<'a> {
+    let y = cx.props.viewbox_height as f32 - LEGEND_ROW_HEIGHT / 2.0;
+    let step = cx.props.viewbox_width as f32 / cx.props.items.len().max(1) as f32;
+
+    cx.render(rsx! {
+        g {
+            class: "{cx.props.class}",
+            cx.props.items.iter().enumerate().map(|(n, (key, color, label))| {
+                let x = step * n as f32 + step / 2.0;
+                let swatch_x = x - LEGEND_SWATCH_SIZE - LEGEND_SWATCH_GAP;
+                let swatch_y = y - LEGEND_SWATCH_SIZE / 2.0;
+
+                rsx! {
+                    g {
+                        key: "legend-{key}",
+                        class: "{cx.props.class_item}",
+                        rect {
+                            x: "{swatch_x}",
+                            y: "{swatch_y}",
+                            width: "{LEGEND_SWATCH_SIZE}",
+                            height: "{LEGEND_SWATCH_SIZE}",
+                            fill: "{color}",
+                        },
+                        MultilineText {
+                            x: x,
+                            y: y,
+                            text: label.as_str(),
+                            text_anchor: "start",
+                            font_size: DEFAULT_FONT_SIZE,
+                        }
+                    }
+                }
+            })
+        }
+    })
+}
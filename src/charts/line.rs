@@ -1,17 +1,233 @@
 use dioxus::prelude::*;
+use log::warn;
 
-use crate::grid::{Axis, Grid};
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::multiline_text::MultilineText;
+use crate::geometry::labels::{resolve_overlaps, LabelBox};
+use crate::grid::{
+    axis_title_centers, break_glyph, reference_lines, reference_values, rotated_labels_height,
+    Axis, AxisBuilder, Grid, AXIS_TITLE_ROOM, LABEL_OFFSET,
+};
 use crate::types::*;
+use crate::utils::color::{dash_pattern, grey};
+use crate::utils::format::{label_number_format, NumberFormat, RoundingMode};
+use crate::utils::path::PathBuilder;
+use crate::utils::regression::fit;
+use crate::utils::stroke_width_to_view;
+use crate::utils::text::{estimate_text_width, format_scaled, text_lines, DEFAULT_FONT_SIZE};
+use crate::utils::transform::detect_outliers;
+
+pub use crate::grid::{AxisScale, DomainMode, NonPositive, ReferenceLine, TickOverflow, TickSteps};
+pub use crate::types::ContextMenu;
+pub use crate::utils::color::ColorRamp;
+pub use crate::utils::format::ValueKind;
+pub use crate::utils::regression::TrendKind;
+pub use crate::utils::transform::OutlierMethod;
+
+const LAST_VALUE_BOX_PADDING: f32 = 4.0;
+const CALLOUT_PADDING: f32 = 6.0;
+const CALLOUT_ARROW_SIZE: f32 = 8.0;
+const TREND_SAMPLES: usize = 32;
+const TREND_LABEL_MARGIN: f32 = 8.0;
+const ERROR_CAP_WIDTH: f32 = 8.0;
+const BREAK_MARK_SIZE: f32 = 12.0;
+const COLOR_LEGEND_GAP: f32 = 12.0;
+const COLOR_LEGEND_BAR_WIDTH: f32 = 12.0;
+const COLOR_LEGEND_LABEL_GAP: f32 = 4.0;
+/// The fill of the dots without a color value when `color_values` is set.
+const NO_COLOR_FILL: &str = "rgb(180, 180, 180)";
+/// The space kept between the line labels, and between the last value boxes, of different series.
+const END_LABEL_GAP: f32 = 2.0;
+/// The distance from its point from which a line label gets a leader tick.
+const LEADER_MIN_OFFSET: f32 = 3.0;
+/// The length of the dashed last value line bent into a moved last value box.
+const LEADER_RUN: f32 = 6.0;
+/// The space between the quadrant labels and the corners of the plot area.
+const QUADRANT_LABEL_INSET: f32 = 8.0;
+/// The fills of the shaded quadrants, alternating like a checkerboard.
+const QUADRANT_FILLS: [&str; 4] = [
+    "rgba(20, 20, 20, 0.06)",
+    "rgba(20, 20, 20, 0.02)",
+    "rgba(20, 20, 20, 0.02)",
+    "rgba(20, 20, 20, 0.06)",
+];
+const HIT_STROKE_WIDTH: f32 = 12.0;
+const FADED_OPACITY: f32 = 0.15;
+
+/// Which series of a [LineChart] have the area between their line and zero filled.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum AreaFill {
+    /// No series.
+    #[default]
+    None,
+    /// Every series.
+    All,
+    /// The series with `true` at their index, and none past the end.
+    Series(Vec<bool>),
+}
+
+impl AreaFill {
+    fn fills(&self, series: usize) -> bool {
+        match self {
+            AreaFill::None => false,
+            AreaFill::All => true,
+            AreaFill::Series(series_fills) => series_fills.get(series) == Some(&true),
+        }
+    }
+}
+
+/// `true` for [`AreaFill::All`].
+impl From<bool> for AreaFill {
+    fn from(all: bool) -> Self {
+        if all {
+            AreaFill::All
+        } else {
+            AreaFill::None
+        }
+    }
+}
+
+impl From<Vec<bool>> for AreaFill {
+    fn from(series: Vec<bool>) -> Self {
+        AreaFill::Series(series)
+    }
+}
+
+/// The payload of the `onmouseover_point` event of the [LineChart], with the point value, its
+/// comparison value and its error range, if any, and whether it is an outlier.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointEvent {
+    /// The index of the series of the point.
+    pub series: usize,
+    /// The index of the point in its series.
+    pub index: usize,
+    /// The value of the point.
+    pub value: f32,
+    /// The value at the same index of the comparison series, if there is one.
+    pub comparison: Option<f32>,
+    /// The value minus the comparison value, i.e., the change since the previous period.
+    pub delta: Option<f32>,
+    /// Whether the point is flagged as an outlier of its series by `mark_outliers`.
+    pub is_outlier: bool,
+    /// The `(low, high)` error of the point from `point_errors`, if any.
+    pub error: Option<(f32, f32)>,
+}
+
+impl PointEvent {
+    fn new(comparison_series: Option<&Series>, series: usize, index: usize, value: f32) -> Self {
+        let comparison = comparison_series
+            .and_then(|comparison| comparison.get(series)?.get(index))
+            .copied();
+
+        Self {
+            series,
+            index,
+            value,
+            comparison,
+            delta: comparison.map(|c| value - c),
+            is_outlier: false,
+            error: None,
+        }
+    }
+}
+
+/// A text annotation pointing at a single data point of a [LineChart] with an arrow.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Callout {
+    /// The index of the series the point belongs to.
+    pub series: usize,
+    /// The index of the point in its series.
+    pub index: usize,
+    /// The text of the callout, broken into lines on `\n` and wrapped to fit the
+    /// `callout_max_width` of the chart.
+    pub text: String,
+    /// The horizontal offset of the text box center from the point, in viewbox units.
+    pub dx: f32,
+    /// The vertical offset of the text box center from the point, in viewbox units.
+    pub dy: f32,
+    /// An extra `class` for this callout group, added to the chart `class_callout`.
+    pub class: Option<String>,
+}
+
+/// The four quadrants of a [LineChart] split at a point, i.e., for effort vs impact strategy
+/// charts with a "Quick wins" quadrant.
+///
+/// The quadrants are in the order top left, top right, bottom left and bottom right, for the
+/// `labels` and `classes` arrays and for [`QuadrantConfig::quadrant`].
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct QuadrantConfig {
+    /// The position of the vertical split, in points along the labels axis from the first one,
+    /// i.e., `2.5` for halfway between the third and fourth points.
+    pub x_split: f32,
+    /// The value of the horizontal split.
+    pub y_split: f32,
+    /// The labels drawn in the outer corner of every quadrant.
+    pub labels: [Option<String>; 4],
+    /// An extra `class` for the group of every quadrant, added to the chart `class_quadrant`.
+    pub classes: [Option<String>; 4],
+    /// Whether to tint the background of the quadrants.
+    pub shade: bool,
+}
+
+impl QuadrantConfig {
+    /// The quadrant of the point at `x` along the labels axis with the value `y`. Points on a
+    /// split belong to the right or top quadrant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dioxus_charts::charts::line::QuadrantConfig;
+    ///
+    /// let quadrants = QuadrantConfig {
+    ///     x_split: 2.5,
+    ///     y_split: 50.0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(quadrants.quadrant(0.0, 80.0), 0);
+    /// assert_eq!(quadrants.quadrant(3.0, 50.0), 1);
+    /// assert_eq!(quadrants.quadrant(1.0, 20.0), 2);
+    /// assert_eq!(quadrants.quadrant(4.0, 20.0), 3);
+    /// ```
+    pub fn quadrant(&self, x: f32, y: f32) -> usize {
+        usize::from(x >= self.x_split) + 2 * usize::from(y < self.y_split)
+    }
+}
+
+struct CalloutLayout<'c> {
+    callout: &'c Callout,
+    lines: Vec<String>,
+    point: Point,
+    bounds: Rect,
+    anchor: Point,
+    arrow: String,
+}
 
 /// The `LineChart` properties struct for the configuration of the line chart.
 #[allow(clippy::struct_excessive_bools)]
-#[derive(PartialEq, Props)]
+#[derive(Props)]
 pub struct LineChartProps<'a> {
     series: Series,
     #[props(optional)]
     labels: Option<Labels>,
     #[props(optional)]
     series_labels: Option<Labels>,
+    #[props(optional)]
+    point_errors: Option<PointErrors>,
+    #[props(optional)]
+    color_values: Option<Series>,
+    #[props(optional)]
+    color_ramp: Option<ColorRamp>,
+    #[props(optional)]
+    color_domain: Option<(f32, f32)>,
+    #[props(default = false)]
+    show_color_legend: bool,
+    #[props(optional)]
+    color_label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    reference_lines: Option<Vec<ReferenceLine>>,
+    #[props(optional)]
+    reference_bands: Option<Vec<(f32, f32)>>,
 
     #[props(default = "100%")]
     width: &'a str,
@@ -21,6 +237,10 @@ pub struct LineChartProps<'a> {
     viewbox_width: i32,
     #[props(default = 400)]
     viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
 
     #[props(default)]
     padding_top: i32,
@@ -34,24 +254,90 @@ pub struct LineChartProps<'a> {
     #[props(default = true)]
     show_grid: bool,
     #[props(default = true)]
+    show_grid_x: bool,
+    #[props(default = true)]
+    show_grid_y: bool,
+    #[props(default = false)]
+    show_minor_grid: bool,
+    #[props(default = true)]
     show_dotted_grid: bool,
     #[props(default = false)]
     show_grid_ticks: bool,
+    #[props(optional)]
+    emphasized_ticks: Option<Vec<f32>>,
+    #[props(default = false)]
+    label_emphasized: bool,
+    #[props(default = false)]
+    show_plot_background: bool,
+    #[props(default = false)]
+    show_plot_border: bool,
     #[props(default = true)]
     show_labels: bool,
     #[props(default = true)]
     show_dots: bool,
     #[props(default = true)]
     show_lines: bool,
+    #[props(default, into)]
+    show_area: AreaFill,
     #[props(default = true)]
     show_line_labels: bool,
+    #[props(default = false)]
+    show_last_value_line: bool,
+    #[props(optional)]
+    mark_outliers: Option<OutlierMethod>,
+    #[props(optional)]
+    trend_line: Option<TrendKind>,
+    #[props(default = false)]
+    show_trend_label: bool,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+    #[props(default = false)]
+    monochrome: bool,
+    #[props(default = false)]
+    isolate_on_hover: bool,
+    #[props(optional)]
+    isolated_series: Option<usize>,
+    #[props(optional)]
+    comparison_series: Option<Series>,
+    #[props(optional)]
+    comparison_label: Option<&'a str>,
+    #[props(optional)]
+    onmouseover_point: Option<EventHandler<'a, PointEvent>>,
+    #[props(optional)]
+    on_context_menu: Option<EventHandler<'a, ContextMenu<PointEvent>>>,
 
     #[props(default = "1%")]
     line_width: &'a str,
+    #[props(optional)]
+    stroke_widths: Option<Vec<f32>>,
     #[props(default = "3%")]
     dot_size: &'a str,
     #[props(optional)]
     label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    number_format: Option<NumberFormat>,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+    #[props(default)]
+    value_kind: ValueKind,
+    #[props(optional)]
+    axis_unit: Option<&'a str>,
+    #[props(optional)]
+    x_title: Option<&'a str>,
+    #[props(optional)]
+    y_title: Option<&'a str>,
+    #[props(optional)]
+    axis_divisor: Option<f32>,
+    #[props(default = false)]
+    scale_value_labels: bool,
+    #[props(default = TickOverflow::AutoSkip)]
+    tick_overflow: TickOverflow,
+    #[props(default)]
+    x_label_rotation: f32,
+    #[props(optional)]
+    x_label_step: Option<usize>,
 
     #[props(optional)]
     lowest: Option<f32>,
@@ -59,25 +345,92 @@ pub struct LineChartProps<'a> {
     highest: Option<f32>,
     #[props(default = 8)]
     max_ticks: i32,
+    #[props(default)]
+    tick_steps: TickSteps,
+    #[props(default = DomainMode::Nice)]
+    domain: DomainMode,
+    #[props(default)]
+    scale: AxisScale,
+    #[props(default)]
+    non_positive: NonPositive,
+    #[props(optional)]
+    axis_breaks: Option<Vec<(f32, f32)>>,
+    #[props(optional)]
+    aspect: Option<f32>,
+
+    #[props(optional)]
+    callouts: Option<Vec<Callout>>,
+    #[props(default = 160.0)]
+    callout_max_width: f32,
+    #[props(optional)]
+    quadrants: Option<QuadrantConfig>,
 
     #[props(default = "dx-chart-line")]
     class_chart_line: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
     #[props(default = "dx-line")]
     class_line: &'a str,
     #[props(default = "dx-line-path")]
     class_line_path: &'a str,
+    #[props(default = "dx-line-area")]
+    class_line_area: &'a str,
+    #[props(default = "dx-line-comparison")]
+    class_line_comparison: &'a str,
     #[props(default = "dx-line-dot")]
     class_line_dot: &'a str,
+    #[props(default = "dx-point-outlier")]
+    class_point_outlier: &'a str,
+    #[props(default = "dx-point-error")]
+    class_point_error: &'a str,
+    #[props(default = "dx-reference-line")]
+    class_reference_line: &'a str,
+    #[props(default = "dx-reference-band")]
+    class_reference_band: &'a str,
+    #[props(default = "dx-point-no-color")]
+    class_point_no_color: &'a str,
+    #[props(default = "dx-color-legend")]
+    class_color_legend: &'a str,
+    #[props(default = "dx-trend")]
+    class_trend: &'a str,
     #[props(default = "dx-line-label")]
     class_line_label: &'a str,
+    #[props(default = "dx-line--isolated")]
+    class_line_isolated: &'a str,
+    #[props(default = "dx-line--faded")]
+    class_line_faded: &'a str,
+    #[props(default = "dx-last-value")]
+    class_last_value: &'a str,
+    #[props(default = "dx-callout")]
+    class_callout: &'a str,
+    #[props(default = "dx-quadrant")]
+    class_quadrant: &'a str,
+    #[props(default = "dx-quadrant-split")]
+    class_quadrant_split: &'a str,
+    #[props(default = "dx-quadrant-label")]
+    class_quadrant_label: &'a str,
+    #[props(default = "dx-plot-background")]
+    class_plot_background: &'a str,
+    #[props(default = "dx-plot-border")]
+    class_plot_border: &'a str,
+    #[props(default = "dx-axis-break")]
+    class_axis_break: &'a str,
     #[props(default = "dx-grid")]
     class_grid: &'a str,
     #[props(default = "dx-grid-line")]
     class_grid_line: &'a str,
+    #[props(default = "dx-grid-minor-line")]
+    class_grid_minor_line: &'a str,
     #[props(default = "dx-grid-label")]
     class_grid_label: &'a str,
     #[props(default = "dx-grid-labels")]
     class_grid_labels: &'a str,
+    #[props(default = "dx-grid-emphasis")]
+    class_grid_emphasis: &'a str,
+    #[props(default = "dx-tick-emphasis")]
+    class_tick_emphasis: &'a str,
+    #[props(default = "dx-axis-title")]
+    class_axis_title: &'a str,
 }
 
 /// This is the `LineChart` function used to render the line chart `Element`.
@@ -116,68 +469,390 @@ pub struct LineChartProps<'a> {
 /// - `series`: [Vec]<[Vec]<[f32]>> (**required**): The series vector of vectors with the series values.
 /// - `labels`: [Vec]<[String]> (optional): Optional labels to show on the labels axis.
 /// - `series_labels`: [Vec]<[String]> (optional): Optional labels to show for each generated line.
+/// - `point_errors`: [Vec]<[Vec]<[Option]<([f32], [f32])>>> (optional): One vector for each
+///   series with the `(low, high)` error of every point, drawn as a vertical whisker with caps
+///   from `value - low` to `value + high`, cut at the plot area. Points with `None` have no
+///   whisker.
+/// - `color_values`: [Vec]<[Vec]<[f32]>> (optional): One vector for each series with a third
+///   value for every point, i.e., a temperature, used to color its dot with `color_ramp`. Dots
+///   with a `NaN` or missing value are drawn grey with the `class_point_no_color` class.
+/// - `color_ramp`: [`ColorRamp`] (optional): The colors for the `color_values`. Uses
+///   [`ColorRamp::default`] when not set.
+/// - `color_domain`: ([f32], [f32]) (optional): The `(min, max)` color values mapped to the ends
+///   of the ramp, to share one mapping between charts. The range of all the `color_values` when
+///   not set.
+/// - `show_color_legend`: [bool] (default: `false`): Show a vertical gradient bar of the ramp with
+///   the min, middle and max color values on the right of the plot area, which shrinks to fit it.
+/// - `color_label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the
+///   color legend labels.
+/// - `reference_lines`: [Vec]<[`ReferenceLine`]> (optional): Lines across the plot at values of
+///   the value axis, drawn over the lines with their label at the end.
+/// - `reference_bands`: [Vec]<([f32], [f32])> (optional): Ranges of values shaded across the plot
+///   behind the lines, clipped to the plot area.
 /// ---
 /// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
-/// other CSS style, i.e., "200px"
+///   other CSS style, i.e., "200px"
 /// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
 /// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
-/// `viewbox_height` it is useful for adjusting the aspect ratio for longer charts.
+///   `viewbox_height` it is useful for adjusting the aspect ratio for longer charts.
 /// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
 /// ---
 /// - `padding_top`: [i32] (default: `0`): Padding for the top side of the view box.
 /// - `padding_bottom`: [i32] (default: `0`): Padding for the bottom side of the view box.
 /// - `padding_left`: [i32] (default: `0`): Padding for the left side of the view box.
 /// - `padding_right`: [i32] (default: `0`): Padding for the right side of the view box.
 /// ---
-/// - `lowest`: [f32] (optional): The lowest number on the chart for the value axis.
-/// - `highest`: [f32] (optional): The highest number on the chart for the value axis.
+/// - `lowest`: [f32] (optional): The lowest number on the chart for the value axis. Lower
+///   points are clamped to the bottom of the plot.
+/// - `highest`: [f32] (optional): The highest number on the chart for the value axis. Higher
+///   points are clamped to the top of the plot.
 /// - `max_ticks`: [i32] (default: `8`): The maximum number of ticks on the generated value axis.
+/// - `tick_steps`: [`TickSteps`] (default: [`TickSteps::Fine`]): The round numbers the ticks of
+///   the generated value axis step by. [`TickSteps::Round`] keeps to 1, 2 or 5 times a power of
+///   ten.
+/// - `domain`: [`DomainMode`] (default: [`DomainMode::Nice`]): How the value axis range is
+///   picked from the data. A `Manual` range that is empty or inverted is an error.
+/// - `scale`: [`AxisScale`] (default: [`AxisScale::Linear`]): How the values are mapped along the
+///   value axis. A [`AxisScale::Log10`] axis has its ticks at powers of ten and ignores the
+///   `axis_breaks` and the `value_kind`.
+/// - `non_positive`: [`NonPositive`] (default: [`NonPositive::Clamp`]): What to do with the zero
+///   and negative values on a log value axis.
+/// - `axis_breaks`: [Vec]<([f32], [f32])> (optional): Ranges of values skipped by the value axis,
+///   i.e., to keep an outlier from flattening the other points. Every range is compressed to a
+///   small gap without ticks, marked with two slashes on the axis and across the lines going
+///   through it.
+/// - `aspect`: [f32] (optional): Keeps a fixed ratio between the units of both axes, as value
+///   axis units per label step, i.e., `1.0` for spatial data where a circle of points must look
+///   like a circle. The plot area shrinks along one side and stays centered, with the axes
+///   following it.
+/// ---
+/// - `callouts`: [Vec]<[`Callout`]> (optional): Text boxes annotating single points, drawn at an
+///   offset from the point with an arrow pointing back at it. Boxes that would leave the plot area
+///   are moved back inside it while the arrow still points at the real point. Callouts for
+///   missing series or points are ignored.
+/// - `callout_max_width`: [f32] (default: `160.0`): The width at which the callout text wraps.
+/// - `quadrants`: [`QuadrantConfig`] (optional): Splits the plot area into four quadrants with a
+///   vertical and a horizontal line, behind the data, with a label in the outer corner of every
+///   quadrant and optionally tinted backgrounds. Splits outside the plot area are moved to its
+///   edge with a logged warning.
 /// ---
 /// - `show_grid`: [bool] (default: `true`): Show/hide the chart grid.
+/// - `show_grid_x`: [bool] (default: `true`): Show/hide the vertical grid lines at the ticks of
+///   the horizontal axis.
+/// - `show_grid_y`: [bool] (default: `true`): Show/hide the horizontal grid lines at the ticks of
+///   the vertical axis.
+/// - `show_minor_grid`: [bool] (default: `false`): Show grid lines at 2 to 9 times every power of
+///   ten of a log value axis.
 /// - `show_dotted_grid`: [bool] (default: `true`): Show the chart grid with dotted style or not.
 /// - `show_grid_ticks`: [bool] (default: `false`): Show the chart grid ticks instead of drawing the
-/// whole grid lines for a cleaner look.
+///   whole grid lines for a cleaner look.
+/// - `emphasized_ticks`: [Vec]<[f32]> (optional): Values of the value axis whose grid line is
+///   drawn stronger and whose tick label is bold. Defaults to zero when the axis crosses it.
+///   Values between the ticks get their own line, without a label.
+/// - `label_emphasized`: [bool] (default: `false`): Also label the emphasized values between
+///   the ticks.
+/// - `show_plot_background`: [bool] (default: `false`): Draw a rect matching the plot area behind
+///   everything else, to style it apart from the page and to capture pointer events over the
+///   whole plot.
+/// - `show_plot_border`: [bool] (default: `false`): Draw a border around the plot area, above the
+///   grid and below the data.
 /// - `show_labels`: [bool] (default: `true`): Show/hide the labels.
 /// - `show_dots`: [bool] (default: `true`): Show/hide the line dots.
 /// - `show_lines`: [bool] (default: `true`): Show/hide the series lines.
-/// - `show_line_labels`: [bool] (default: `true`): Show/hide the labels for the lines.
+/// - `show_area`: [`AreaFill`] (default: [`AreaFill::None`]): Fill the area between the lines
+///   and zero, or the edge of the value axis when zero is outside of it, behind the lines. Also
+///   accepts a [bool] for every series, or a [Vec]<[bool]> for each one. Non-finite values split
+///   the area.
+/// - `show_line_labels`: [bool] (default: `true`): Show/hide the labels for the lines. Labels that
+///   would overlap at the end of the lines are moved apart, with a short tick to their point.
+/// - `show_last_value_line`: [bool] (default: `false`): Show a dashed line across the whole plot
+///   at the last value of each series, with a label box past the right edge showing the formatted
+///   value. The right padding grows if needed to fit the label box. Overlapping boxes are moved
+///   apart, and their dashed lines bend into them.
+/// - `mark_outliers`: [`OutlierMethod`] (optional): Circle the points flagged as outliers of their
+///   series by the given method.
+/// - `trend_line`: [`TrendKind`] (optional): Draw a dashed least squares trend line over every
+///   series. Series that can't define the fit, i.e., with too few points, are skipped.
+/// - `show_trend_label`: [bool] (default: `false`): Show the equation and R² of every trend line
+///   in the corner of the plot with the fewest points.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the series, cycled when there are more
+///   series than colors. They are used by the lines, dots, areas and every other mark of the
+///   series. The generated shades of red are used when not set.
+/// - `monochrome`: [bool] (default: `false`): Draw every series in a shade of grey with its own
+///   dash pattern and dot shape instead of colors, for printing in black and white.
+/// - `isolate_on_hover`: [bool] (default: `false`): Fade every other series when the pointer is
+///   over a line or its line label, so the hovered one is readable among many. Thin lines get a
+///   wider transparent path on top to be easier to hover.
+/// - `isolated_series`: [usize] (optional): The index of the series to isolate, overriding the
+///   hovered one, i.e., to isolate it from an external legend.
+/// - `comparison_series`: [Vec]<[Vec]<[f32]>> (optional): One vector for each series with the
+///   values of a previous period on the same labels, i.e., last week. They are drawn behind the
+///   series as muted dashed lines without dots, line labels, outlier marks or trends, and the
+///   value axis range includes them.
+/// - `comparison_label`: &[str] (optional): The name of the comparison period, shown as the
+///   tooltip of the comparison lines, i.e., `"Last week"`.
+/// - `onmouseover_point`: [EventHandler]<[`PointEvent`]> (optional): Called when the pointer
+///   enters a dot, with its value, the comparison value and their difference, its error range
+///   and whether it is an outlier.
+/// - `on_context_menu`: [EventHandler]<[`ContextMenu`]<[`PointEvent`]>> (optional): Called when a
+///   dot is right-clicked, with the point like `onmouseover_point`, the pointer position and the
+///   dot position in the viewbox. The browser menu is only prevented when it is set.
 /// ---
 /// - `line_width`: &[str] (default: `"1%"`): The width of the series lines.
+/// - `stroke_widths`: [Vec]<[f32]> (optional): The width of the line of each series, in viewbox
+///   units. The series past the end use `line_width`.
 /// - `dot_size`: &[str] (default: `"3%"`): The size of the line dots.
 /// - `label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the
-/// generated labels.
+///   generated labels.
+/// - `number_format`: [`NumberFormat`] (optional): The formatting of the generated labels when
+///   there is no `label_interpolation` function.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the generated labels, which
+///   keep their full precision when neither it nor `number_format` is set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the generated labels
+///   are rounded to the `label_decimals`, or to the decimals of the `number_format`.
+/// - `value_kind`: [`ValueKind`] (default: [`ValueKind::Number`]): The kind of the series values.
+///   With [`ValueKind::DurationSeconds`] the value axis ticks land on round durations, and the
+///   generated labels are formatted like `1h 23m` when there is no `label_interpolation`.
+/// - `axis_unit`: &[str] (optional): A unit appended to the value axis labels, i.e., `"MiB"`.
+/// - `x_title`: &[str] (optional): The title of the horizontal axis, centered below it. The plot
+///   is shrunk to make room for it.
+/// - `y_title`: &[str] (optional): The title of the vertical axis, rotated and centered left of
+///   it. The plot is shrunk to make room for it.
+/// - `axis_divisor`: [f32] (optional): The value axis labels show the values divided by it, i.e.,
+///   `1048576.0` for bytes in MiB. The ticks land on round divided values while the data and
+///   the last value labels keep the original values.
+/// - `scale_value_labels`: [bool] (default: `false`): Divide the last value labels too, and
+///   append the unit to them.
+/// - `tick_overflow`: [`TickOverflow`] (default: [`TickOverflow::AutoSkip`]): What to do with the
+///   labels of the labels axis when they don't fit side by side.
+/// - `x_label_rotation`: [f32] (default: `0.0`): The rotation in degrees of the labels of the
+///   labels axis around their anchor, i.e., `-45.0`. The bottom padding grows if needed to fit
+///   them, and [`TickOverflow::AutoSkip`] accounts for the rotation.
+/// - `x_label_step`: [usize] (optional): Show only every Nth label of the labels axis, whatever
+///   the `tick_overflow`. The first and the last labels are always shown.
 /// ---
 /// - `class_chart_line`: &[str] (default: `"dx-chart-line"`): The HTML element `class` of the
-/// chart.
+///   chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
 /// - `class_line`: &[str] (default: `"dx-line"`): The HTML element `class` of the whole line.
 /// - `class_line_path`: &[str] (default: `"dx-line"`): The HTML element `class` of the line path.
+/// - `class_line_area`: &[str] (default: `"dx-line-area"`): The HTML element `class` of the area
+///   under the line.
+/// - `class_line_comparison`: &[str] (default: `"dx-line-comparison"`): The HTML element `class`
+///   of the comparison lines.
 /// - `class_line_dot`: &[str] (default: `"dx-line-dot"`): The HTML element `class` of the line dot.
+/// - `class_point_outlier`: &[str] (default: `"dx-point-outlier"`): The HTML element `class` of the
+///   outlier markers.
+/// - `class_point_error`: &[str] (default: `"dx-point-error"`): The HTML element `class` of the
+///   error whiskers of every series.
+/// - `class_reference_line`: &[str] (default: `"dx-reference-line"`): The HTML element `class` of
+///   every reference line with its label.
+/// - `class_reference_band`: &[str] (default: `"dx-reference-band"`): The HTML element `class` of
+///   every reference band.
+/// - `class_point_no_color`: &[str] (default: `"dx-point-no-color"`): The HTML element `class`
+///   added to the dots without a color value.
+/// - `class_color_legend`: &[str] (default: `"dx-color-legend"`): The HTML element `class` of the
+///   color legend.
+/// - `class_trend`: &[str] (default: `"dx-trend"`): The HTML element `class` of the trend lines
+///   and their labels.
 /// - `class_line_label`: &[str] (default: `"dx-line-label"`): The HTML element `class` of the line
-/// labels.
+///   labels.
+/// - `class_line_isolated`: &[str] (default: `"dx-line--isolated"`): The HTML element `class`
+///   added to the whole line of the isolated series.
+/// - `class_line_faded`: &[str] (default: `"dx-line--faded"`): The HTML element `class` added to
+///   the whole line of the other series while one is isolated.
+/// - `class_last_value`: &[str] (default: `"dx-last-value"`): The HTML element `class` of the last
+///   value lines and labels.
+/// - `class_quadrant`: &[str] (default: `"dx-quadrant"`): The HTML element `class` of the group
+///   of every quadrant, with its background and label.
+/// - `class_quadrant_split`: &[str] (default: `"dx-quadrant-split"`): The HTML element `class` of
+///   the quadrant split lines.
+/// - `class_quadrant_label`: &[str] (default: `"dx-quadrant-label"`): The HTML element `class` of
+///   the quadrant labels.
+/// - `class_plot_background`: &[str] (default: `"dx-plot-background"`): The HTML element `class`
+///   of the plot area background.
+/// - `class_plot_border`: &[str] (default: `"dx-plot-border"`): The HTML element `class` of the
+///   plot area border.
+/// - `class_axis_break`: &[str] (default: `"dx-axis-break"`): The HTML element `class` of the
+///   axis break marks.
 /// - `class_grid`: &[str] (default: `"dx-grid"`): The HTML element `class` of the grid.
 /// - `class_grid_line`: &[str] (default: `"dx-grid-line"`): The HTML element `class` of every grid
-/// line.
+///   line.
+/// - `class_grid_minor_line`: &[str] (default: `"dx-grid-minor-line"`): The HTML element `class`
+///   of every minor grid line of a log value axis.
 /// - `class_grid_label`: &[str] (default: `"dx-grid-label"`): The HTML element `class` of the grid
-/// labels.
+///   labels.
 /// - `class_grid_labels`: &[str] (default: `"dx-grid-labels"`): The HTML element `class` of the
-/// group of grid labels.
+///   group of grid labels.
+/// - `class_grid_emphasis`: &[str] (default: `"dx-grid-emphasis"`): The HTML element `class` of
+///   every emphasized grid line.
+/// - `class_tick_emphasis`: &[str] (default: `"dx-tick-emphasis"`): The HTML element `class` of
+///   every emphasized tick label, next to the grid label class.
+/// - `class_axis_title`: &[str] (default: `"dx-axis-title"`): The HTML element `class` of the
+///   axis titles.
 #[allow(non_snake_case)]
-pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element {
+pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element<'a> {
+    let hovered_series = use_state(&cx, || None::<usize>);
+    // The browser menu is left alone without a handler
+    let prevent_context_menu = if cx.props.on_context_menu.is_some() {
+        "oncontextmenu"
+    } else {
+        ""
+    };
+
+    if cx.props.series.iter().all(Vec::is_empty) {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart_line,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
     for series in cx.props.series.iter() {
         if series.is_empty() {
-            return cx.render(rsx!("Pie chart error: empty series"));
+            return cx.render(rsx!("Line chart error: empty series"));
         }
     }
 
+    if cx.props.domain.bounds(0.0, 1.0).is_none() {
+        return cx.render(rsx!("Line chart error: empty or inverted manual domain"));
+    }
+
+    if cx.props.scale == AxisScale::Log10
+        && cx.props.non_positive == NonPositive::Error
+        && cx.props.series.iter().flatten().any(|v| *v <= 0.0)
+    {
+        return cx.render(rsx!(
+            "Line chart error: zero or negative values on a log axis"
+        ));
+    }
+
+    let label_interpolation = cx
+        .props
+        .label_interpolation
+        .or_else(|| cx.props.value_kind.label_interpolation());
+
+    let number_format = label_number_format(
+        cx.props.number_format.as_ref(),
+        cx.props.label_decimals,
+        cx.props.rounding,
+    );
+
+    let (label_divisor, label_unit) = if cx.props.scale_value_labels {
+        (
+            cx.props.axis_divisor.filter(|d| *d > 0.0).unwrap_or(1.0),
+            cx.props.axis_unit,
+        )
+    } else {
+        (1.0, None)
+    };
+    let format_value = |v: f32| {
+        format_scaled(
+            v,
+            label_interpolation,
+            number_format.as_ref(),
+            label_divisor,
+            label_unit,
+        )
+    };
+
+    let last_value_labels = if cx.props.show_last_value_line {
+        cx.props
+            .series
+            .iter()
+            .map(|a| format_value(a[a.len() - 1]))
+            .collect::<Labels>()
+    } else {
+        Labels::new()
+    };
+
+    let last_value_box_width = last_value_labels
+        .iter()
+        .map(|label| estimate_text_width(label, DEFAULT_FONT_SIZE) + 2.0 * LAST_VALUE_BOX_PADDING)
+        .fold(0.0, f32::max);
+
+    let color_ramp = cx
+        .props
+        .color_values
+        .as_ref()
+        .map(|_| cx.props.color_ramp.clone().unwrap_or_default());
+    let color_domain = cx.props.color_domain.or_else(|| {
+        cx.props
+            .color_values
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|v| v.is_finite())
+            .fold(None, |domain, v| match domain {
+                Some((min, max)) => Some((v.min(min), v.max(max))),
+                None => Some((*v, *v)),
+            })
+    });
+    let color_legend = match (&color_ramp, color_domain) {
+        (Some(_), Some((min, max))) if cx.props.show_color_legend => [max, (min + max) / 2.0, min]
+            .into_iter()
+            .map(|v| format_scaled(v, cx.props.color_label_interpolation, None, 1.0, None))
+            .collect::<Labels>(),
+        _ => Labels::new(),
+    };
+    let color_legend_width = if color_legend.is_empty() {
+        0.0
+    } else {
+        COLOR_LEGEND_GAP
+            + COLOR_LEGEND_BAR_WIDTH
+            + COLOR_LEGEND_LABEL_GAP
+            + color_legend
+                .iter()
+                .map(|label| estimate_text_width(label, DEFAULT_FONT_SIZE))
+                .fold(0.0, f32::max)
+    };
+
+    let padding_right =
+        (cx.props.padding_right as f32).max(last_value_box_width + color_legend_width);
+
+    // Rotated labels make room below the plot
+    let padding_bottom = match &cx.props.labels {
+        Some(labels) if cx.props.x_label_rotation != 0.0 && cx.props.show_labels => {
+            let height = rotated_labels_height(labels, cx.props.x_label_rotation);
+
+            (cx.props.padding_bottom as f32).max(height + LABEL_OFFSET)
+        }
+        _ => cx.props.padding_bottom as f32,
+    };
+    let title_room = |title: Option<&str>| title.map_or(0.0, |_| AXIS_TITLE_ROOM);
+
     let view = Rect::new(
-        cx.props.padding_left as f32,
+        cx.props.padding_left as f32 + title_room(cx.props.y_title),
         cx.props.padding_top as f32,
-        (cx.props.viewbox_width - cx.props.padding_right) as f32,
-        (cx.props.viewbox_height - cx.props.padding_bottom) as f32,
+        cx.props.viewbox_width as f32 - padding_right,
+        cx.props.viewbox_height as f32 - padding_bottom - title_room(cx.props.x_title),
     );
 
     let max_ticks = cx.props.max_ticks.max(3);
 
+    // The comparison values are part of the value axis range
+    let axis_series = cx.props.comparison_series.as_ref().map(|comparison| {
+        cx.props
+            .series
+            .iter()
+            .chain(comparison.iter().filter(|a| !a.is_empty()))
+            .cloned()
+            .collect::<Series>()
+    });
+
     let axis_x = Axis::builder()
         .with_view(view)
         .with_grid_ticks(cx.props.show_grid_ticks)
@@ -186,44 +861,485 @@ pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element {
     let axis_y = Axis::builder()
         .with_view(view)
         .with_max_ticks(max_ticks)
+        .with_tick_steps(cx.props.tick_steps)
         .with_grid_ticks(cx.props.show_grid_ticks)
-        .with_series(&cx.props.series)
-        .with_label_interpolation(cx.props.label_interpolation)
+        .with_series(axis_series.as_ref().unwrap_or(&cx.props.series))
+        .with_label_interpolation(label_interpolation)
+        .with_value_kind(cx.props.value_kind)
+        .with_number_format(number_format.as_ref())
+        .with_divisor(cx.props.axis_divisor)
+        .with_unit(cx.props.axis_unit)
         .with_highest(cx.props.highest)
-        .with_lowest(cx.props.lowest);
+        .with_domain(cx.props.domain)
+        .with_scale(cx.props.scale)
+        .with_breaks(cx.props.axis_breaks.as_deref())
+        .with_lowest(cx.props.lowest)
+        .with_included(reference_values(cx.props.reference_lines.as_deref()));
 
-    let grid = Grid::new(axis_x, axis_y);
-    let lines = grid.lines();
+    let (view, grid) = fit_grid(view, axis_x, axis_y, cx.props.aspect);
+    let lines = grid.lines(cx.props.show_grid_x, cx.props.show_grid_y);
+    let reference_lines = reference_lines(&grid.y, cx.props.reference_lines.as_deref());
+    // Where the areas under the lines end: zero, or the edge of the axis when zero is outside of it
+    let area_baseline = grid.y.value_to_view(grid.y.clamp_value(0.0));
+    let reference_bands = cx
+        .props
+        .reference_bands
+        .iter()
+        .flatten()
+        .filter_map(|(a, b)| grid.y.cross_band(*a, *b))
+        .collect::<Vec<Rect>>();
+    let (x_title, y_title) = axis_title_centers(&view, cx.props.viewbox_height as f32);
+    let minor_lines = if cx.props.show_minor_grid {
+        grid.y.minor_lines()
+    } else {
+        Vec::new()
+    };
     let generated_labels = grid.y.generated_labels();
+    let emphasis = grid.y.emphasis(
+        cx.props.emphasized_ticks.as_deref(),
+        cx.props.label_emphasized,
+    );
+    // The emphasized tick labels are drawn bold on their own
+    let value_labels = grid
+        .y
+        .text_data(generated_labels.len())
+        .into_iter()
+        .zip(generated_labels.iter())
+        .enumerate()
+        .filter(|(i, _)| !emphasis.ticks.contains(i))
+        .map(|(_, (text, label))| (text, label, 0.0));
 
     let grid_labels = if cx.props.show_labels {
         if let Some(labels) = cx.props.labels.as_ref() {
+            let rotation = cx.props.x_label_rotation;
+            let overflow = grid.x.label_overflow(
+                labels,
+                cx.props.tick_overflow,
+                cx.props.x_label_step,
+                rotation,
+            );
+
             Some(
-                grid.text_data(Some(labels.len()), Some(generated_labels.len()))
+                grid.x
+                    .text_data(labels.len())
                     .into_iter()
-                    .zip(labels.iter().chain(generated_labels.iter()))
-                    .collect::<Vec<(TextData, &String)>>(),
+                    .zip(labels.iter())
+                    .zip(overflow)
+                    .filter_map(|((mut text, label), offset)| {
+                        offset.map(|offset| {
+                            text.y += offset;
+                            // Rotated labels hang from their tick
+                            if rotation != 0.0 {
+                                text.anchor = if rotation < 0.0 { "end" } else { "start" };
+                                text.baseline = "middle";
+                            }
+                            (text, label, rotation)
+                        })
+                    })
+                    .chain(value_labels)
+                    .collect::<Vec<(TextData, &String, f32)>>(),
             )
         } else {
-            Some(
-                grid.y
-                    .text_data(generated_labels.len())
-                    .into_iter()
-                    .zip(generated_labels.iter())
-                    .collect::<Vec<(TextData, &String)>>(),
-            )
+            Some(value_labels.collect::<Vec<(TextData, &String, f32)>>())
         }
     } else {
         None
     };
 
     let mut color_var = 255.0;
+    let series_colors = &(0..cx.props.series.len())
+        .map(|i| {
+            color_var -= 75.0 * (1.0 / (i + 1) as f32);
+
+            match &cx.props.colors {
+                _ if cx.props.monochrome => grey(i),
+                Some(colors) if !colors.is_empty() => colors[i % colors.len()].clone(),
+                _ => format!("rgb({color_var}, 40, 40)"),
+            }
+        })
+        .collect::<Vec<String>>();
+    let series_widths = &(0..cx.props.series.len())
+        .map(|i| {
+            cx.props
+                .stroke_widths
+                .as_ref()
+                .and_then(|widths| widths.get(i))
+                .map_or_else(|| cx.props.line_width.to_owned(), f32::to_string)
+        })
+        .collect::<Vec<String>>();
+    let callouts = cx
+        .props
+        .callouts
+        .iter()
+        .flatten()
+        .filter_map(|callout| {
+            let value = cx.props.series.get(callout.series)?.get(callout.index)?;
+            let point = grid.world_to_view(callout.index as f32, *value, false);
+            let lines = text_lines(
+                &callout.text,
+                Some(cx.props.callout_max_width),
+                DEFAULT_FONT_SIZE,
+                "hanging",
+            )
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect::<Vec<String>>();
+
+            let width = lines
+                .iter()
+                .map(|line| estimate_text_width(line, DEFAULT_FONT_SIZE))
+                .fold(0.0, f32::max)
+                + 2.0 * CALLOUT_PADDING;
+            let height = lines.len() as f32 * DEFAULT_FONT_SIZE + 2.0 * CALLOUT_PADDING;
+
+            let x = (point.x + callout.dx - width / 2.0)
+                .min(view.max.x - width)
+                .max(view.min.x);
+            let y = (point.y + callout.dy - height / 2.0)
+                .min(view.max.y - height)
+                .max(view.min.y);
+            let bounds = Rect::new(x, y, x + width, y + height);
+
+            let anchor = Point::new(
+                point.x.clamp(bounds.min.x, bounds.max.x),
+                point.y.clamp(bounds.min.y, bounds.max.y),
+            );
+            let (ux, uy) = (point.x - anchor.x, point.y - anchor.y);
+            let length = ux.hypot(uy);
+            let arrow = if length > CALLOUT_ARROW_SIZE {
+                let (ux, uy) = (ux / length, uy / length);
+                let base_x = point.x - ux * CALLOUT_ARROW_SIZE;
+                let base_y = point.y - uy * CALLOUT_ARROW_SIZE;
+                let half = CALLOUT_ARROW_SIZE / 2.0;
+
+                format!(
+                    "{point} {},{} {},{}",
+                    base_x - uy * half,
+                    base_y + ux * half,
+                    base_x + uy * half,
+                    base_y - ux * half,
+                )
+            } else {
+                String::new()
+            };
+
+            Some(CalloutLayout {
+                callout,
+                lines,
+                point,
+                bounds,
+                anchor,
+                arrow,
+            })
+        })
+        .collect::<Vec<CalloutLayout>>();
+
+    let trends = &cx
+        .props
+        .trend_line
+        .map(|kind| {
+            cx.props
+                .series
+                .iter()
+                .enumerate()
+                .filter_map(|(i, a)| {
+                    // The gaps are left out of the fit, which warns when too few points remain
+                    let points = a
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, y)| y.is_finite())
+                        .map(|(x, y)| (x as f64, f64::from(*y)))
+                        .collect::<Vec<(f64, f64)>>();
+                    let fit = fit(&points, kind)?;
+
+                    let last = (a.len() - 1) as f64;
+                    let samples = if kind == TrendKind::Linear {
+                        1
+                    } else {
+                        TREND_SAMPLES
+                    };
+                    let mut path = PathBuilder::with_capacity(samples + 1);
+
+                    for n in 0..=samples {
+                        let x = last * n as f64 / samples as f64;
+                        let point = grid.world_to_view(x as f32, fit.eval(x) as f32, false);
+
+                        if n == 0 {
+                            path.move_to(point);
+                        } else {
+                            path.line_to(point);
+                        }
+                    }
+
+                    let label = format!("{fit}  R² = {:.2}", fit.r_squared);
+
+                    Some((i, path.build(), label))
+                })
+                .collect::<Vec<(usize, String, String)>>()
+        })
+        .unwrap_or_default();
+
+    // The trend labels go in the quadrant of the plot with the fewest points
+    let (trend_label_left, trend_label_top) = {
+        let middle = Point::new(
+            (view.min.x + view.max.x) / 2.0,
+            (view.min.y + view.max.y) / 2.0,
+        );
+        let mut counts = [0usize; 4];
+
+        for a in &cx.props.series {
+            for (index, v) in a.iter().enumerate() {
+                let point = grid.world_to_view(index as f32, *v, false);
+                let quadrant =
+                    usize::from(point.x >= middle.x) + 2 * usize::from(point.y >= middle.y);
+                counts[quadrant] += 1;
+            }
+        }
+
+        let quadrant = (0..4).min_by_key(|q| counts[*q]).unwrap_or(0);
+
+        (quadrant % 2 == 0, quadrant < 2)
+    };
+    let trend_labels = trends
+        .iter()
+        .enumerate()
+        .map(|(n, (i, _, label))| {
+            let row = if trend_label_top {
+                n
+            } else {
+                trends.len() - 1 - n
+            } as f32;
+            let text = TextData {
+                x: if trend_label_left {
+                    view.min.x + TREND_LABEL_MARGIN
+                } else {
+                    view.max.x - TREND_LABEL_MARGIN
+                },
+                y: if trend_label_top {
+                    view.min.y + TREND_LABEL_MARGIN + row * DEFAULT_FONT_SIZE
+                } else {
+                    view.max.y - TREND_LABEL_MARGIN - row * DEFAULT_FONT_SIZE
+                },
+                anchor: if trend_label_left { "start" } else { "end" },
+                baseline: if trend_label_top {
+                    "hanging"
+                } else {
+                    "text-bottom"
+                },
+            };
+
+            (*i, text, label)
+        })
+        .collect::<Vec<(usize, TextData, &String)>>();
+
+    let outlier_radius = stroke_width_to_view(
+        cx.props.dot_size,
+        cx.props.viewbox_width as f32,
+        cx.props.viewbox_height as f32,
+    );
+
     let dotted_stroke = if cx.props.show_dotted_grid {
         &"2px"
     } else {
         &"0px"
     };
 
+    let break_positions = grid.y.break_positions();
+    let break_glyphs = if break_positions.is_empty() {
+        Vec::new()
+    } else {
+        let axis_marks = break_positions
+            .iter()
+            .map(|position| Point::new(view.min.x, *position));
+        let line_marks = cx.props.series.iter().flat_map(|a| {
+            let points = a
+                .iter()
+                .enumerate()
+                .map(|(index, v)| grid.world_to_view(index as f32, *v, false))
+                .collect::<Vec<Point>>();
+            let break_positions = &break_positions;
+
+            (1..points.len()).flat_map(move |n| {
+                let (from, to) = (points[n - 1], points[n]);
+
+                break_positions
+                    .iter()
+                    .filter(move |y| from.y.min(to.y) < **y && **y < from.y.max(to.y))
+                    .map(move |y| {
+                        let x = from.x + (y - from.y) / (to.y - from.y) * (to.x - from.x);
+                        Point::new(x, *y)
+                    })
+            })
+        });
+
+        axis_marks
+            .chain(line_marks)
+            .map(|center| break_glyph(center, BREAK_MARK_SIZE, false))
+            .collect::<Vec<(String, String)>>()
+    };
+
+    // The quadrant rects and labels, split at a point kept inside the plot area
+    let quadrants = cx.props.quadrants.as_ref().map(|config| {
+        let split = grid.world_to_view(config.x_split, config.y_split, false);
+        let clamped = Point::new(
+            split.x.clamp(view.min.x, view.max.x),
+            split.y.clamp(view.min.y, view.max.y),
+        );
+        if clamped.x != split.x || clamped.y != split.y {
+            warn!(
+                "quadrant split ({}, {}) clamped to the plot area",
+                config.x_split, config.y_split
+            );
+        }
+
+        let regions = (0..4)
+            .map(|quadrant| {
+                let (left, top) = (quadrant % 2 == 0, quadrant < 2);
+                let rect = Rect::new(
+                    if left { view.min.x } else { clamped.x },
+                    if top { view.min.y } else { clamped.y },
+                    if left { clamped.x } else { view.max.x },
+                    if top { clamped.y } else { view.max.y },
+                );
+                let text = TextData {
+                    x: if left {
+                        view.min.x + QUADRANT_LABEL_INSET
+                    } else {
+                        view.max.x - QUADRANT_LABEL_INSET
+                    },
+                    y: if top {
+                        view.min.y + QUADRANT_LABEL_INSET
+                    } else {
+                        view.max.y - QUADRANT_LABEL_INSET
+                    },
+                    anchor: if left { "start" } else { "end" },
+                    baseline: if top { "hanging" } else { "text-bottom" },
+                };
+
+                (
+                    rect,
+                    config.labels[quadrant].as_ref().map(|label| (text, label)),
+                    config.classes[quadrant].as_deref().unwrap_or_default(),
+                )
+            })
+            .collect::<Vec<(Rect, Option<(TextData, &String)>, &str)>>();
+
+        (clamped, regions, config.shade)
+    });
+
+    // The labels at the end of the lines are moved apart where they overlap, preferring to move
+    // the ones with more room between their point and the plot edges
+    let end_points = cx
+        .props
+        .series
+        .iter()
+        .map(|a| grid.world_to_view((a.len() - 1) as f32, a[a.len() - 1], false))
+        .collect::<Vec<Point>>();
+    let end_label_box = |point: Point, height: f32| LabelBox {
+        anchor: point.y,
+        height,
+        mobility: (point.y - view.min.y).min(view.max.y - point.y).max(1.0),
+    };
+    let mut line_label_ys = vec![None::<f32>; end_points.len()];
+    let labelled = (0..end_points.len())
+        .filter(|i| {
+            cx.props.show_line_labels
+                && cx
+                    .props
+                    .series_labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(*i))
+                    .is_some_and(|label| !label.is_empty())
+        })
+        .collect::<Vec<usize>>();
+    for i in &labelled {
+        if line_label_ys[*i].is_some() {
+            continue;
+        }
+
+        // The labels of the series ending at the same point along the labels axis
+        let group = labelled
+            .iter()
+            .copied()
+            .filter(|j| end_points[*j].x == end_points[*i].x)
+            .collect::<Vec<usize>>();
+        let boxes = group
+            .iter()
+            .map(|j| end_label_box(end_points[*j], DEFAULT_FONT_SIZE))
+            .collect::<Vec<LabelBox>>();
+
+        for (j, y) in group.into_iter().zip(resolve_overlaps(
+            &boxes,
+            END_LABEL_GAP,
+            view.min.y,
+            view.max.y,
+        )) {
+            line_label_ys[j] = Some(y);
+        }
+    }
+    let last_value_ys = resolve_overlaps(
+        &end_points
+            .iter()
+            .take(last_value_labels.len())
+            .map(|point| end_label_box(*point, DEFAULT_FONT_SIZE + LAST_VALUE_BOX_PADDING))
+            .collect::<Vec<LabelBox>>(),
+        END_LABEL_GAP,
+        view.min.y,
+        view.max.y,
+    );
+
+    let (plot_width, plot_height) = (view.width(), view.height());
+    let color_legend_x = view.max.x + last_value_box_width + COLOR_LEGEND_GAP;
+    let color_gradient_id = &format!("dx-color-ramp-{}", cx.scope_id().0);
+    let color_gradient_stops = color_ramp
+        .as_ref()
+        .map(|ramp| {
+            let count = ramp.stops.len().max(2);
+
+            (0..count)
+                .map(|n| {
+                    let t = n as f32 / (count - 1) as f32;
+                    (t * 100.0, ramp.sample(t))
+                })
+                .collect::<Vec<(f32, String)>>()
+        })
+        .unwrap_or_default();
+
+    let comparison_paths = cx
+        .props
+        .comparison_series
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, a)| !a.is_empty())
+        .map(|(i, a)| {
+            let mut path = PathBuilder::with_capacity(a.len());
+
+            let mut gap = true;
+            for (index, v) in a.iter().enumerate() {
+                if !v.is_finite() {
+                    gap = true;
+                    continue;
+                }
+
+                let point = grid.world_to_view(index as f32, *v, false);
+
+                if gap {
+                    path.move_to(point);
+                } else {
+                    path.line_to(point);
+                }
+                gap = false;
+            }
+
+            let color = series_colors.get(i).cloned().unwrap_or_else(|| grey(i));
+
+            (i, path.build(), color)
+        })
+        .collect::<Vec<(usize, String, String)>>();
+
+    let isolated = cx.props.isolated_series.or(*hovered_series.get());
+
     cx.render(rsx! {
         div {
             svg {
@@ -233,13 +1349,80 @@ pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element {
                 class: "{cx.props.class_chart_line}",
                 preserveAspectRatio: "xMidYMid meet",
                 view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                cx.props.show_plot_background.then(|| rsx! {
+                    rect {
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_background}",
+                        fill: "white",
+                    }
+                }),
+                quadrants.as_ref().map(|(split, regions, shade)| rsx! {
+                    g {
+                        regions.iter().enumerate().map(|(n, (rect, label, class))| rsx! {
+                            g {
+                                key: "quadrant-{n}",
+                                class: "{cx.props.class_quadrant} {class}",
+                                shade.then(|| rsx! {
+                                    rect {
+                                        x: "{rect.min.x}",
+                                        y: "{rect.min.y}",
+                                        width: format_args!("{}", rect.width()),
+                                        height: format_args!("{}", rect.height()),
+                                        fill: format_args!("{}", QUADRANT_FILLS[n]),
+                                    }
+                                }),
+                                label.as_ref().map(|(text, label)| rsx! {
+                                    MultilineText {
+                                        x: text.x,
+                                        y: text.y,
+                                        text: label.as_str(),
+                                        text_anchor: text.anchor,
+                                        class: cx.props.class_quadrant_label,
+                                        alignment_baseline: text.baseline,
+                                    }
+                                })
+                            }
+                        }),
+                        line {
+                            x1: "{split.x}",
+                            y1: "{view.min.y}",
+                            x2: "{split.x}",
+                            y2: "{view.max.y}",
+                            class: "{cx.props.class_quadrant_split}",
+                            stroke: "rgba(20, 20, 20, 0.4)",
+                        }
+                        line {
+                            x1: "{view.min.x}",
+                            y1: "{split.y}",
+                            x2: "{view.max.x}",
+                            y2: "{split.y}",
+                            class: "{cx.props.class_quadrant_split}",
+                            stroke: "rgba(20, 20, 20, 0.4)",
+                        }
+                    }
+                }),
                 cx.props.show_grid.then(|| rsx! {
                     g {
                         class: "{cx.props.class_grid}",
-                        lines.iter().map(|line| {
+                        minor_lines.iter().enumerate().map(|(n, line)| rsx! {
+                            line {
+                                key: "grid-minor-line-{n}",
+                                x1: "{line.min.x}",
+                                y1: "{line.min.y}",
+                                x2: "{line.max.x}",
+                                y2: "{line.max.y}",
+                                class: "{cx.props.class_grid_minor_line}",
+                                stroke: "rgba(20, 20, 20, 0.3)",
+                                stroke_dasharray: "{dotted_stroke}",
+                            }
+                        }),
+                        lines.iter().enumerate().map(|(n, line)| {
                             rsx! {
                                 line {
-                                    key: "{line}",
+                                    key: "grid-line-{n}",
                                     x1: "{line.min.x}",
                                     y1: "{line.min.y}",
                                     x2: "{line.max.x}",
@@ -250,24 +1433,132 @@ pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element {
                                 }
                             }
                         }),
+                        emphasis.lines.iter().enumerate().map(|(n, line)| {
+                            rsx! {
+                                line {
+                                    key: "grid-emphasis-{n}",
+                                    x1: "{line.min.x}",
+                                    y1: "{line.min.y}",
+                                    x2: "{line.max.x}",
+                                    y2: "{line.max.y}",
+                                    class: "{cx.props.class_grid_emphasis}",
+                                    stroke: "rgb(20, 20, 20)",
+                                    stroke_width: "1.5",
+                                }
+                            }
+                        }),
+                    }
+                }),
+                (!reference_bands.is_empty()).then(|| rsx! {
+                    g {
+                        reference_bands.iter().enumerate().map(|(n, rect)| {
+                            let (width, height) = (rect.width(), rect.height());
+
+                            rsx! {
+                                rect {
+                                    key: "reference-band-{n}",
+                                    x: "{rect.min.x}",
+                                    y: "{rect.min.y}",
+                                    width: "{width}",
+                                    height: "{height}",
+                                    class: "{cx.props.class_reference_band}",
+                                    fill: "rgb(40, 40, 40)",
+                                    fill_opacity: "0.08",
+                                }
+                            }
+                        })
+                    }
+                }),
+                cx.props.show_plot_border.then(|| rsx! {
+                    rect {
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_border}",
+                        fill: "none",
+                        stroke: "rgba(20, 20, 20, 0.8)",
                     }
                 }),
                 grid_labels.map(|labels| rsx! {
                     g {
                         class: "{cx.props.class_grid_labels}",
-                        labels.iter().map(|(text, label)| rsx! {
+                        labels.iter().enumerate().map(|(n, (text, label, rotation))| rsx! {
+                            MultilineText {
+                                key: "grid-label-{n}",
+                                x: text.x,
+                                y: text.y,
+                                text: label.as_str(),
+                                text_anchor: text.anchor,
+                                class: cx.props.class_grid_label,
+                                alignment_baseline: text.baseline,
+                                rotation: *rotation,
+                            }
+                        }),
+                        emphasis.labels.iter().enumerate().map(|(n, (text, label))| rsx! {
                             text {
-                                key: "{label}",
+                                key: "grid-emphasis-label-{n}",
                                 dx: "{text.x}",
                                 dy: "{text.y}",
                                 text_anchor: "{text.anchor}",
-                                class: "{cx.props.class_grid_label}",
+                                class: "{cx.props.class_grid_label} {cx.props.class_tick_emphasis}",
                                 alignment_baseline: "{text.baseline}",
+                                font_weight: "bold",
                                 [label.as_str()]
                             }
                         })
                     }
                 }),
+                cx.props.x_title.map(|title| rsx! {
+                    text {
+                        x: "{x_title.x}",
+                        y: "{x_title.y}",
+                        text_anchor: "middle",
+                        alignment_baseline: "middle",
+                        class: "{cx.props.class_axis_title}",
+                        "{title}"
+                    }
+                }),
+                cx.props.y_title.map(|title| rsx! {
+                    text {
+                        x: "{y_title.x}",
+                        y: "{y_title.y}",
+                        transform: "rotate(-90 {y_title.x} {y_title.y})",
+                        text_anchor: "middle",
+                        alignment_baseline: "middle",
+                        class: "{cx.props.class_axis_title}",
+                        "{title}"
+                    }
+                }),
+                (!comparison_paths.is_empty()).then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_line_comparison}",
+                        comparison_paths.iter().map(|(i, path, color)| {
+                            // The comparison series past the series use the default width,
+                            // like they use the default colors
+                            let width = series_widths
+                                .get(*i)
+                                .map_or(cx.props.line_width, String::as_str);
+
+                            rsx! {
+                                path {
+                                    key: "comparison-{i}",
+                                    d: "{path}",
+                                    class: "{cx.props.class_line_comparison}-{i}",
+                                    stroke: "{color}",
+                                    stroke_width: "{width}",
+                                    stroke_dasharray: "6 4",
+                                    stroke_linecap: "round",
+                                    opacity: "0.5",
+                                    fill: "none",
+                                    cx.props.comparison_label.map(|label| rsx! {
+                                        title { "{label}" }
+                                    })
+                                }
+                            }
+                        })
+                    }
+                }),
                 cx.props.series
                     .iter()
                     .enumerate()
@@ -278,76 +1569,566 @@ pub fn LineChart<'a>(cx: Scope<'a, LineChartProps<'a>>) -> Element {
                         .chain(std::iter::repeat(&"".to_owned())))
                     .map(|((i, a), label)| {
 
-                    let mut commands = Vec::<String>::with_capacity(a.len());
-                    let mut dots = Vec::<Rect>::with_capacity(a.len());
+                    let mut path = PathBuilder::with_capacity(a.len());
+                    let mut dots = Vec::<(Rect, Option<Option<String>>, PointEvent)>::with_capacity(a.len());
+                    let point_colors = cx.props.color_values.as_ref().and_then(|values| values.get(i));
                     let mut text_point: Option<Point> = None;
+                    let mut outlier_points = Vec::<Point>::new();
+                    let errors = cx.props.point_errors.as_ref().and_then(|errors| errors.get(i));
+                    let mut whiskers = PathBuilder::with_capacity(0);
+                    let outliers = cx.props.mark_outliers
+                        .map(|method| detect_outliers(a, method))
+                        .unwrap_or_default();
 
-                    color_var -= 75.0 * (1.0 / (i + 1) as f32);
+                    let color = &series_colors[i];
+                    let width = &series_widths[i];
+                    let dash = if cx.props.monochrome { dash_pattern(i) } else { "none" };
+                    let (dot_cap, dot_rotation) = match i % 3 {
+                        _ if !cx.props.monochrome => ("round", 0.0),
+                        0 => ("round", 0.0),
+                        1 => ("square", 0.0),
+                        _ => ("square", 45.0),
+                    };
 
+                    let mut gap = true;
                     for (index, v) in a.iter().enumerate() {
+                        // Like the area, the line breaks at every missing value and starts
+                        // again at the next finite one
+                        if !v.is_finite() {
+                            gap = true;
+                            continue;
+                        }
+
                         let point = grid.world_to_view(index as f32, *v, false);
 
-                        if index == 0 {
-                            commands.push(format!("M{},{}", point.x, point.y));
+                        if gap {
+                            path.move_to(point);
                         } else {
-                            commands.push(format!("L{},{}", point.x, point.y));
+                            path.line_to(point);
                         }
+                        gap = false;
+
+                        let is_outlier = outliers.get(index) == Some(&true);
+                        let error = errors.and_then(|errors| errors.get(index)).copied().flatten();
 
                         if cx.props.show_dots {
-                            dots.push(Rect::new(point.x, point.y, point.x + 0.1, point.y));
+                            let dot_color = color_ramp.as_ref().map(|ramp| {
+                                point_colors
+                                    .and_then(|values| values.get(index))
+                                    .filter(|v| v.is_finite())
+                                    .zip(color_domain)
+                                    .map(|(v, (min, max))| ramp.color_for_value(*v, min, max))
+                            });
+                            let event = PointEvent {
+                                is_outlier,
+                                error,
+                                ..PointEvent::new(cx.props.comparison_series.as_ref(), i, index, *v)
+                            };
+
+                            dots.push((Rect::new(point.x, point.y, point.x + 0.1, point.y), dot_color, event));
+                        }
+
+                        if is_outlier {
+                            outlier_points.push(point);
                         }
 
-                        if !label.is_empty() && index == (a.len() - 1) {
+                        if let Some((low, high)) = error {
+                            grid.y.whisker(&mut whiskers, point.x, v - low, v + high, ERROR_CAP_WIDTH);
+                        }
+
+                        if cx.props.show_line_labels && !label.is_empty() && index == (a.len() - 1) {
                             text_point = Some(point);
                         }
                     }
 
-                    let commands = commands.join(" ");
+                    // Every run of finite values is closed down to the baseline on its own
+                    let area = cx.props.show_area.fills(i).then(|| {
+                        let points = a
+                            .iter()
+                            .enumerate()
+                            .map(|(index, v)| v.is_finite().then(|| grid.world_to_view(index as f32, *v, false)))
+                            .collect::<Vec<Option<Point>>>();
+                        let mut area = PathBuilder::with_capacity(a.len() + 3);
+
+                        for run in points.split(Option::is_none) {
+                            if let (Some(Some(first)), Some(Some(last))) = (run.first(), run.last()) {
+                                area.move_to(Point::new(first.x, area_baseline));
+                                for point in run.iter().flatten() {
+                                    area.line_to(*point);
+                                }
+                                area.line_to(Point::new(last.x, area_baseline)).close();
+                            }
+                        }
+
+                        area.build()
+                    });
+
+                    let commands = path.build();
+                    let whiskers = whiskers.build();
+                    let last_value = last_value_labels.get(i).map(|value| {
+                        let point = end_points[i];
+                        let box_width = estimate_text_width(value, DEFAULT_FONT_SIZE) + 2.0 * LAST_VALUE_BOX_PADDING;
+                        let box_height = DEFAULT_FONT_SIZE + LAST_VALUE_BOX_PADDING;
+                        let y = last_value_ys.get(i).copied().unwrap_or(point.y);
+                        // A moved box is reached by bending the end of the dashed line
+                        let bend_x = if (y - point.y).abs() > LEADER_MIN_OFFSET {
+                            (view.max.x - LEADER_RUN).max(view.min.x)
+                        } else {
+                            view.max.x
+                        };
+
+                        (point, y, bend_x, value, box_width, box_height)
+                    });
+                    let label_y = line_label_ys[i];
+                    let (class_isolation, opacity) = match isolated {
+                        Some(isolated) if isolated == i => (cx.props.class_line_isolated, 1.0),
+                        Some(_) => (cx.props.class_line_faded, FADED_OPACITY),
+                        None => ("", 1.0),
+                    };
+                    let on_hover = move |hovered: Option<usize>| {
+                        if cx.props.isolate_on_hover {
+                            hovered_series.set(hovered);
+                        }
+                    };
 
                     rsx! {
                         g {
-                            key: "{label}",
-                            class: "{cx.props.class_line}-{i}",
-                            path {
-                                key: "{cx.props.class_line_path}",
-                                d: "{commands}",
-                                class: "{cx.props.class_line_path}",
-                                stroke: "rgb({color_var}, 40, 40)",
-                                stroke_width: "{cx.props.line_width}",
-                                stroke_linecap: "round",
-                                fill: "transparent",
-                            },
-                            dots.iter().map(|d| {
+                            key: "series-{i}",
+                            class: "{cx.props.class_line}-{i} {class_isolation}",
+                            opacity: "{opacity}",
+                            area.map(|area| rsx! {
+                                path {
+                                    d: "{area}",
+                                    class: "{cx.props.class_line_area}",
+                                    fill: "{color}",
+                                    fill_opacity: "0.3",
+                                    stroke: "none",
+                                }
+                            }),
+                            (!whiskers.is_empty()).then(|| rsx! {
+                                path {
+                                    d: "{whiskers}",
+                                    class: "{cx.props.class_point_error}",
+                                    stroke: "{color}",
+                                    stroke_width: "1.5",
+                                    fill: "none",
+                                }
+                            }),
+                            cx.props.show_lines.then(|| rsx! {
+                                path {
+                                    d: "{commands}",
+                                    class: "{cx.props.class_line_path}",
+                                    stroke: "{color}",
+                                    stroke_width: "{width}",
+                                    stroke_dasharray: "{dash}",
+                                    stroke_linecap: "round",
+                                    fill: "transparent",
+                                }
+                                cx.props.isolate_on_hover.then(|| rsx! {
+                                    path {
+                                        d: "{commands}",
+                                        stroke: "transparent",
+                                        stroke_width: "{HIT_STROKE_WIDTH}",
+                                        stroke_linecap: "round",
+                                        fill: "none",
+                                        onmouseover: move |_| on_hover(Some(i)),
+                                        onmouseout: move |_| on_hover(None),
+                                    }
+                                })
+                            }),
+                            dots.iter().map(|(d, dot_color, event)| {
+                                let (dot_color, class_no_color) = match dot_color {
+                                    Some(Some(c)) => (c.as_str(), ""),
+                                    Some(None) => (NO_COLOR_FILL, cx.props.class_point_no_color),
+                                    None => (color.as_str(), ""),
+                                };
+                                let center = d.min;
+                                let event = *event;
+
                                 rsx! {
                                     line {
-                                        key: "{i}",
+                                        key: "dot-{event.index}",
                                         x1: "{d.min.x}",
                                         y1: "{d.min.y}",
                                         x2: "{d.max.x}",
                                         y2: "{d.max.y}",
-                                        class: "{cx.props.class_line_dot}",
-                                        stroke: "rgb({color_var}, 40, 40)",
+                                        class: "{cx.props.class_line_dot} {class_no_color}",
+                                        stroke: "{dot_color}",
                                         stroke_width: "{cx.props.dot_size}",
-                                        stroke_linecap: "round",
+                                        stroke_linecap: "{dot_cap}",
+                                        transform: "rotate({dot_rotation} {d.min.x} {d.min.y})",
+                                        onmouseover: move |_| {
+                                            if let Some(handler) = &cx.props.onmouseover_point {
+                                                handler.call(event);
+                                            }
+                                        },
+                                        prevent_default: "{prevent_context_menu}",
+                                        oncontextmenu: move |e| {
+                                            if let Some(handler) = &cx.props.on_context_menu {
+                                                handler.call(ContextMenu::new(event, &e.data, center));
+                                            }
+                                        },
+                                    }
+                                }
+                            }),
+                            outlier_points.iter().enumerate().map(|(n, point)| {
+                                rsx! {
+                                    circle {
+                                        key: "outlier-{n}",
+                                        cx: "{point.x}",
+                                        cy: "{point.y}",
+                                        r: "{outlier_radius}",
+                                        class: "{cx.props.class_point_outlier}",
+                                        stroke: "{color}",
+                                        stroke_width: "2",
+                                        fill: "none",
                                     }
                                 }
                             }),
                             text_point.map(|point| {
+                                let y = label_y.unwrap_or(point.y);
+
                                 rsx! {cx,
+                                    ((y - point.y).abs() > LEADER_MIN_OFFSET).then(|| rsx! {
+                                        line {
+                                            x1: format_args!("{}", point.x + 2.0),
+                                            y1: "{point.y}",
+                                            x2: format_args!("{}", point.x + 8.0),
+                                            y2: "{y}",
+                                            class: "{cx.props.class_line_label}",
+                                            stroke: "{color}",
+                                        }
+                                    }),
                                     text {
                                         dx: format_args!("{}", point.x + 10.0),
-                                        dy: "{point.y}",
+                                        dy: "{y}",
                                         text_anchor: "start",
-                                        color: "rgb({color_var}, 40, 40)",
+                                        color: "{color}",
                                         class: "{cx.props.class_line_label}",
+                                        onmouseover: move |_| on_hover(Some(i)),
+                                        onmouseout: move |_| on_hover(None),
                                         [label.as_str()]
                                     }
                                 }
                             }),
+                            last_value.map(|(point, y, bend_x, value, box_width, box_height)| {
+                                rsx! {cx,
+                                    g {
+                                        class: "{cx.props.class_last_value}",
+                                        polyline {
+                                            points: "{view.min.x},{point.y} {bend_x},{point.y} {view.max.x},{y}",
+                                            stroke: "{color}",
+                                            stroke_dasharray: "4px",
+                                            fill: "none",
+                                        },
+                                        rect {
+                                            x: "{view.max.x}",
+                                            y: format_args!("{}", y - box_height / 2.0),
+                                            width: "{box_width}",
+                                            height: "{box_height}",
+                                            fill: "{color}",
+                                        },
+                                        text {
+                                            dx: format_args!("{}", view.max.x + LAST_VALUE_BOX_PADDING),
+                                            dy: "{y}",
+                                            text_anchor: "start",
+                                            alignment_baseline: "middle",
+                                            fill: "white",
+                                            [value.as_str()]
+                                        }
+                                    }
+                                }
+                            }),
+                        }
+                    }
+                }),
+                (!trends.is_empty()).then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_trend}",
+                        trends.iter().map(|(i, path, _)| {
+                            let color = &series_colors[*i];
+
+                            rsx! {
+                                path {
+                                    key: "trend-{i}",
+                                    d: "{path}",
+                                    stroke: "{color}",
+                                    stroke_width: "2",
+                                    stroke_dasharray: "6 4",
+                                    fill: "none",
+                                }
+                            }
+                        }),
+                        cx.props.show_trend_label.then(|| rsx! {
+                            trend_labels.iter().map(|(i, text, label)| {
+                                let color = &series_colors[*i];
+
+                                rsx! {
+                                    text {
+                                        key: "trend-label-{i}",
+                                        dx: "{text.x}",
+                                        dy: "{text.y}",
+                                        text_anchor: "{text.anchor}",
+                                        alignment_baseline: "{text.baseline}",
+                                        fill: "{color}",
+                                        [label.as_str()]
+                                    }
+                                }
+                            })
+                        }),
+                    }
+                }),
+                (!reference_lines.is_empty()).then(|| rsx! {
+                    g {
+                        reference_lines.iter().enumerate().map(|(n, (line, text, reference))| {
+                            let class = reference.class.as_deref().unwrap_or_default();
+
+                            rsx! {
+                                g {
+                                    key: "reference-line-{n}",
+                                    class: "{cx.props.class_reference_line} {class}",
+                                    line {
+                                        x1: "{line.min.x}",
+                                        y1: "{line.min.y}",
+                                        x2: "{line.max.x}",
+                                        y2: "{line.max.y}",
+                                        stroke: "rgb(40, 40, 40)",
+                                        stroke_dasharray: "6 3",
+                                    },
+                                    reference.label.as_ref().map(|label| rsx! {
+                                        text {
+                                            x: "{text.x}",
+                                            y: "{text.y}",
+                                            text_anchor: "{text.anchor}",
+                                            alignment_baseline: "{text.baseline}",
+                                            "{label}"
+                                        }
+                                    }),
+                                }
+                            }
+                        })
+                    }
+                }),
+                (!break_glyphs.is_empty()).then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_axis_break}",
+                        break_glyphs.iter().enumerate().map(|(n, (gap, slashes))| rsx! {
+                            g {
+                                key: "axis-break-{n}",
+                                path {
+                                    d: "{gap}",
+                                    fill: "white",
+                                },
+                                path {
+                                    d: "{slashes}",
+                                    stroke: "rgba(20, 20, 20, 0.8)",
+                                    fill: "none",
+                                }
+                            }
+                        })
+                    }
+                }),
+                callouts.iter().enumerate().map(|(i, layout)| {
+                    let point = layout.point;
+                    let class = layout.callout.class.as_deref().unwrap_or_default();
+                    let bounds = &layout.bounds;
+                    let (width, height) = (bounds.width(), bounds.height());
+
+                    rsx! {
+                        g {
+                            key: "callout-{i}",
+                            class: "{cx.props.class_callout} {class}",
+                            line {
+                                x1: "{layout.anchor.x}",
+                                y1: "{layout.anchor.y}",
+                                x2: "{point.x}",
+                                y2: "{point.y}",
+                                stroke: "rgb(40, 40, 40)",
+                            },
+                            (!layout.arrow.is_empty()).then(|| rsx! {
+                                polygon {
+                                    points: "{layout.arrow}",
+                                    fill: "rgb(40, 40, 40)",
+                                }
+                            }),
+                            rect {
+                                x: "{bounds.min.x}",
+                                y: "{bounds.min.y}",
+                                width: "{width}",
+                                height: "{height}",
+                                fill: "white",
+                                stroke: "rgb(40, 40, 40)",
+                            },
+                            text {
+                                text_anchor: "start",
+                                dominant_baseline: "hanging",
+                                layout.lines.iter().enumerate().map(|(n, line)| {
+                                    let y = bounds.min.y + CALLOUT_PADDING + n as f32 * DEFAULT_FONT_SIZE;
+
+                                    rsx! {
+                                        tspan {
+                                            key: "{n}",
+                                            x: format_args!("{}", bounds.min.x + CALLOUT_PADDING),
+                                            y: "{y}",
+                                            [line.as_str()]
+                                        }
+                                    }
+                                })
+                            }
+                        }
+                    }
+                }),
+                (!color_legend.is_empty()).then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_color_legend}",
+                        defs {
+                            linearGradient {
+                                id: "{color_gradient_id}",
+                                x1: "0",
+                                y1: "1",
+                                x2: "0",
+                                y2: "0",
+                                color_gradient_stops.iter().enumerate().map(|(n, (offset, stop_color))| rsx! {
+                                    stop {
+                                        key: "{n}",
+                                        offset: "{offset}%",
+                                        // `stop_color` is rendered as `stop_color` instead of `stop-color`
+                                        style: "stop-color: {stop_color}",
+                                    }
+                                })
+                            }
+                        }
+                        rect {
+                            x: "{color_legend_x}",
+                            y: "{view.min.y}",
+                            width: "{COLOR_LEGEND_BAR_WIDTH}",
+                            height: "{plot_height}",
+                            fill: "url(#{color_gradient_id})",
                         }
+                        color_legend.iter().enumerate().map(|(n, label)| {
+                            let x = color_legend_x + COLOR_LEGEND_BAR_WIDTH + COLOR_LEGEND_LABEL_GAP;
+                            let y = view.min.y + plot_height * n as f32 / 2.0;
+
+                            rsx! {
+                                text {
+                                    key: "color-label-{n}",
+                                    dx: "{x}",
+                                    dy: "{y}",
+                                    text_anchor: "start",
+                                    alignment_baseline: "middle",
+                                    [label.as_str()]
+                                }
+                            }
+                        })
                     }
                 }),
             }
         }
     })
 }
+
+/// The plot area and the grid of the chart, with the plot area shrunk to keep `aspect` value
+/// units per label step when it is set.
+fn fit_grid<'a>(
+    view: Rect,
+    axis_x: AxisBuilder<'a>,
+    axis_y: AxisBuilder<'a>,
+    aspect: Option<f32>,
+) -> (Rect, Grid) {
+    let grid = Grid::new(axis_x.clone(), axis_y.clone());
+
+    match aspect.filter(|aspect| *aspect > 0.0) {
+        Some(aspect) => {
+            // The points span the steps between the first and last label, not the whole axis
+            let view = view.fit_aspect(grid.x.step_span(), grid.y.world(), aspect);
+            (
+                view,
+                Grid::new(axis_x.with_view(view), axis_y.with_view(view)),
+            )
+        }
+        None => (view, grid),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::{rendered_attributes, rendered_keys, rendered_texts};
+
+    #[test]
+    fn aspect_keeps_one_unit_the_same_length_on_both_axes() {
+        let labels = (0..5).map(|i| i.to_string()).collect::<Labels>();
+        let series = vec![vec![0.0, 1.0, 4.0, 2.0, 3.0]];
+        let view = Rect::new(40.0, 10.0, 560.0, 390.0);
+        let axis_x = Axis::builder().with_view(view).with_labels(Some(&labels));
+        let axis_y = Axis::builder().with_view(view).with_series(&series);
+
+        let (fitted, grid) = fit_grid(view, axis_x, axis_y, Some(1.0));
+        let origin = grid.world_to_view(0.0, 0.0, false);
+        let right = grid.world_to_view(1.0, 0.0, false);
+        let up = grid.world_to_view(0.0, 1.0, false);
+
+        assert!(fitted.width() < view.width());
+        assert!(((right.x - origin.x) - (origin.y - up.y)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn missing_values_break_the_lines() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                LineChart {
+                    series: vec![
+                        vec![1.0, f32::NAN, 3.0, 4.0],
+                        vec![2.0, 2.5, f32::INFINITY, 1.0],
+                    ],
+                    labels: vec!["A".into(), "B".into(), "C".into(), "D".into()],
+                    comparison_series: vec![vec![f32::NAN, 1.0, 2.0, 2.0]],
+                    show_area: true,
+                    trend_line: TrendKind::Linear,
+                    show_trend_label: true,
+                }
+            })
+        }
+
+        let paths = rendered_attributes(app, "d");
+        let texts = rendered_texts(app);
+
+        assert!(!paths.is_empty());
+        assert!(paths.iter().all(|d| !d.contains("NaN")), "{paths:?}");
+        assert!(paths.iter().any(|d| d.matches('M').count() == 2));
+        assert_eq!(texts.iter().filter(|t| t.starts_with("y = ")).count(), 2);
+        assert!(texts.iter().all(|t| !t.contains("NaN")), "{texts:?}");
+    }
+
+    #[test]
+    fn dots_after_a_gap_keep_the_index_of_their_point() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                LineChart {
+                    series: vec![vec![1.0, f32::NAN, 3.0, 4.0]],
+                    labels: vec!["A".into(), "B".into(), "C".into(), "D".into()],
+                }
+            })
+        }
+
+        let dots = rendered_keys(app)
+            .into_iter()
+            .filter(|key| key.starts_with("dot-"))
+            .collect::<Vec<String>>();
+
+        assert_eq!(dots, ["dot-0", "dot-2", "dot-3"]);
+    }
+
+    #[test]
+    fn empty_series_render_the_empty_state() {
+        fn no_series(cx: Scope) -> Element {
+            cx.render(rsx! { LineChart { series: vec![] } })
+        }
+        fn empty_series(cx: Scope) -> Element {
+            cx.render(rsx! { LineChart { series: vec![vec![]], labels: vec![] } })
+        }
+
+        assert!(rendered_attributes(no_series, "class").contains(&"dx-empty".to_string()));
+        assert!(rendered_attributes(empty_series, "class").contains(&"dx-empty".to_string()));
+    }
+}
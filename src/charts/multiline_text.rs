@@ -0,0 +1,150 @@
+use dioxus::prelude::*;
+
+use crate::utils::text::{text_lines, DEFAULT_FONT_SIZE};
+
+/// The `MultilineText` properties struct, with the attributes of the SVG `text` element.
+#[derive(Props)]
+pub(crate) struct MultilineTextProps<'a> {
+    x: f32,
+    y: f32,
+    #[props(into)]
+    text: String,
+    #[props(optional)]
+    max_width: Option<f32>,
+    #[props(default = DEFAULT_FONT_SIZE)]
+    line_height: f32,
+    #[props(default = "middle")]
+    text_anchor: &'a str,
+    #[props(default = "middle")]
+    alignment_baseline: &'a str,
+    #[props(default = "")]
+    class: &'a str,
+    #[props(optional)]
+    font_size: Option<f32>,
+    #[props(default)]
+    rotation: f32,
+}
+
+/// An SVG label that breaks its text into stacked `tspan` lines on `\n`, or when it is wider
+/// than `max_width`, keeping the whole block aligned like a single line label would be. See
+/// [`text_lines`] for the alignment of the lines.
+///
+/// Labels with a single line are drawn as a plain `text` element, placed with the same `x` and
+/// `y` attributes as the lines of a wrapped label. A `rotation` in degrees turns the label
+/// around its `x` and `y` position.
+#[allow(non_snake_case)]
+pub(crate) fn MultilineText<'a>(cx: Scope<'a, MultilineTextProps<'a>>) -> Element<'a> {
+    let lines = text_lines(
+        &cx.props.text,
+        cx.props.max_width,
+        cx.props.line_height,
+        cx.props.alignment_baseline,
+    );
+
+    let font_size = cx
+        .props
+        .font_size
+        .map_or_else(|| "inherit".to_owned(), |size| size.to_string());
+    let transform = if cx.props.rotation == 0.0 {
+        String::new()
+    } else {
+        format!(
+            "rotate({} {} {})",
+            cx.props.rotation, cx.props.x, cx.props.y
+        )
+    };
+
+    if lines.len() == 1 {
+        return cx.render(rsx! {
+            text {
+                x: "{cx.props.x}",
+                y: "{cx.props.y}",
+                text_anchor: "{cx.props.text_anchor}",
+                class: "{cx.props.class}",
+                alignment_baseline: "{cx.props.alignment_baseline}",
+                font_size: "{font_size}",
+                transform: "{transform}",
+                "{cx.props.text}"
+            }
+        });
+    }
+
+    cx.render(rsx! {
+        text {
+            text_anchor: "{cx.props.text_anchor}",
+            class: "{cx.props.class}",
+            font_size: "{font_size}",
+            transform: "{transform}",
+            lines.iter().enumerate().map(|(n, (offset, line))| {
+                let y = cx.props.y + offset;
+
+                rsx! {
+                    tspan {
+                        key: "{n}",
+                        x: "{cx.props.x}",
+                        y: "{y}",
+                        alignment_baseline: "{cx.props.alignment_baseline}",
+                        "{line}"
+                    }
+                }
+            })
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::{rendered_attributes, rendered_tags};
+
+    #[test]
+    fn two_lines_are_two_tspans_centered_as_a_block() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                svg {
+                    MultilineText {
+                        x: 50.0,
+                        y: 100.0,
+                        text: "First\nSecond",
+                    }
+                }
+            })
+        }
+
+        let ys = rendered_attributes(app, "y")
+            .iter()
+            .map(|y| y.parse::<f32>().unwrap())
+            .collect::<Vec<f32>>();
+
+        assert_eq!(
+            rendered_tags(app)
+                .iter()
+                .filter(|tag| *tag == "tspan")
+                .count(),
+            2
+        );
+        assert_eq!(rendered_attributes(app, "x"), ["50", "50"]);
+        assert_eq!(ys.len(), 2);
+        assert!(ys[0] < 100.0 && ys[1] > 100.0, "{ys:?}");
+        assert!((100.0 - ys[0] - (ys[1] - 100.0)).abs() < 1e-3, "{ys:?}");
+    }
+
+    #[test]
+    fn one_line_is_placed_like_the_lines() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                svg {
+                    MultilineText {
+                        x: 50.0,
+                        y: 100.0,
+                        text: "Single",
+                    }
+                }
+            })
+        }
+
+        assert!(!rendered_tags(app).contains(&"tspan".to_string()));
+        assert_eq!(rendered_attributes(app, "x"), ["50"]);
+        assert_eq!(rendered_attributes(app, "y"), ["100"]);
+    }
+}
@@ -0,0 +1,669 @@
+use dioxus::prelude::*;
+
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::multiline_text::MultilineText;
+use crate::grid::{Axis, Grid};
+use crate::types::*;
+use crate::utils::format::{label_number_format, NumberFormat, RoundingMode};
+use crate::utils::path::PathBuilder;
+use crate::utils::text::format_scaled;
+use crate::utils::transform::{
+    cumulative_percentages, descending_order, sanitize_series, sort_descending_with_labels,
+};
+
+pub use crate::grid::{DomainMode, TickOverflow, TickSteps};
+
+/// The `ParetoChart` properties struct for the configuration of the pareto chart.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Props)]
+pub struct ParetoChartProps<'a> {
+    series: Vec<f32>,
+    labels: Labels,
+
+    #[props(default = "100%")]
+    width: &'a str,
+    #[props(default = "100%")]
+    height: &'a str,
+    #[props(default = 600)]
+    viewbox_width: i32,
+    #[props(default = 400)]
+    viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
+
+    #[props(default)]
+    padding_top: i32,
+    #[props(default)]
+    padding_bottom: i32,
+    #[props(default)]
+    padding_left: i32,
+    #[props(default)]
+    padding_right: i32,
+
+    #[props(default = 8)]
+    max_ticks: i32,
+    #[props(default)]
+    tick_steps: TickSteps,
+    #[props(default = DomainMode::Nice)]
+    domain: DomainMode,
+    #[props(default = 80.0)]
+    reference_value: f32,
+
+    #[props(default = true)]
+    show_grid: bool,
+    #[props(default = true)]
+    show_dotted_grid: bool,
+    #[props(default = false)]
+    show_grid_ticks: bool,
+    #[props(default = false)]
+    show_plot_background: bool,
+    #[props(default = false)]
+    show_plot_border: bool,
+    #[props(default = true)]
+    show_labels: bool,
+    #[props(default = true)]
+    show_series_labels: bool,
+    #[props(default = true)]
+    show_dots: bool,
+    #[props(default = true)]
+    show_reference_line: bool,
+
+    #[props(default = 60)]
+    label_size: i32,
+    #[props(default = TickOverflow::AutoSkip)]
+    tick_overflow: TickOverflow,
+    #[props(optional)]
+    label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    number_format: Option<NumberFormat>,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+    #[props(optional)]
+    axis_unit: Option<&'a str>,
+    #[props(optional)]
+    axis_divisor: Option<f32>,
+    #[props(default = false)]
+    scale_value_labels: bool,
+
+    #[props(default = "5%")]
+    bar_width: &'a str,
+    #[props(default = "1%")]
+    line_width: &'a str,
+    #[props(default = "3%")]
+    dot_size: &'a str,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+
+    #[props(optional)]
+    onclick_bar: Option<EventHandler<'a, usize>>,
+    #[props(optional)]
+    onmouseover_bar: Option<EventHandler<'a, usize>>,
+    #[props(optional)]
+    onmouseout_bar: Option<EventHandler<'a, usize>>,
+
+    #[props(default = "dx-chart-pareto")]
+    class_chart_pareto: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
+    #[props(default = "dx-bar")]
+    class_bar: &'a str,
+    #[props(default = "dx-bar-group")]
+    class_bar_group: &'a str,
+    #[props(default = "dx-bar-label")]
+    class_bar_label: &'a str,
+    #[props(default = "dx-line")]
+    class_line: &'a str,
+    #[props(default = "dx-line-path")]
+    class_line_path: &'a str,
+    #[props(default = "dx-line-dot")]
+    class_line_dot: &'a str,
+    #[props(default = "dx-reference-line")]
+    class_reference_line: &'a str,
+    #[props(default = "dx-plot-background")]
+    class_plot_background: &'a str,
+    #[props(default = "dx-plot-border")]
+    class_plot_border: &'a str,
+    #[props(default = "dx-grid")]
+    class_grid: &'a str,
+    #[props(default = "dx-grid-line")]
+    class_grid_line: &'a str,
+    #[props(default = "dx-grid-label")]
+    class_grid_label: &'a str,
+    #[props(default = "dx-grid-labels")]
+    class_grid_labels: &'a str,
+}
+
+/// This is the `ParetoChart` function used to render the pareto chart `Element`.
+/// In Dioxus, components are just functions; this is the main `ParetoChart`
+/// component to be used inside `rsx!` macros in your code.
+///
+/// The chart sorts the values in descending order, draws them as bars and
+/// overlays the cumulative percentage line on a secondary `0%` to `100%` axis on
+/// the right side, together with a reference line (`80%` by default).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::ParetoChart;
+///
+/// fn app(cx: Scope) -> Element {
+///     cx.render(rsx! {
+///         ParetoChart {
+///             padding_top: 30,
+///             padding_left: 50,
+///             padding_right: 50,
+///             padding_bottom: 30,
+///             series: vec![12.0, 48.0, 5.0, 21.0, 3.0],
+///             labels: vec!["Scratch".into(), "Dent".into(), "Crack".into(), "Stain".into(), "Other".into()],
+///         }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `series`: [Vec]<[f32]> (**required**): The series vector with the values. It doesn't need
+///   to be sorted. `NaN`, infinite and negative values count as zero.
+/// - `labels`: [Vec]<[String]> (**required**): The labels for each value of the series. They are
+///   kept paired with their values after sorting.
+/// ---
+/// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
+///   other CSS style, i.e., "200px"
+/// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
+/// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
+///   `viewbox_height` it is useful for adjusting the aspect ratio for longer charts.
+/// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
+/// ---
+/// - `padding_top`: [i32] (default: `0`): Padding for the top side of the view box.
+/// - `padding_bottom`: [i32] (default: `0`): Padding for the bottom side of the view box.
+/// - `padding_left`: [i32] (default: `0`): Padding for the left side of the view box.
+/// - `padding_right`: [i32] (default: `0`): Padding for the right side of the view box. The
+///   percentage axis is drawn here.
+/// ---
+/// - `max_ticks`: [i32] (default: `8`): The maximum number of ticks on the generated value axis.
+/// - `tick_steps`: [`TickSteps`] (default: [`TickSteps::Fine`]): The round numbers the ticks of
+///   the generated value axis step by. [`TickSteps::Round`] keeps to 1, 2 or 5 times a power of
+///   ten.
+/// - `domain`: [`DomainMode`] (default: [`DomainMode::Nice`]): How the value axis range is
+///   picked from the data. A `Manual` range that is empty or inverted is an error.
+/// - `reference_value`: [f32] (default: `80.0`): The percentage where the reference line is
+///   drawn.
+/// ---
+/// - `show_grid`: [bool] (default: `true`): Show/hide the chart grid.
+/// - `show_dotted_grid`: [bool] (default: `true`): Show the chart grid with dotted style or not.
+/// - `show_grid_ticks`: [bool] (default: `false`): Show the chart grid ticks instead of drawing the
+///   whole grid lines for a cleaner look.
+/// - `show_plot_background`: [bool] (default: `false`): Draw a rect matching the plot area behind
+///   everything else, to style it apart from the page and to capture pointer events over the
+///   whole plot.
+/// - `show_plot_border`: [bool] (default: `false`): Draw a border around the plot area, above the
+///   grid and below the data.
+/// - `show_labels`: [bool] (default: `true`): Show/hide the labels.
+/// - `show_series_labels`: [bool] (default: `true`): Show/hide the values labels at the top of
+///   bars.
+/// - `show_dots`: [bool] (default: `true`): Show/hide the cumulative line dots.
+/// - `show_reference_line`: [bool] (default: `true`): Show/hide the reference line.
+/// ---
+/// - `label_size`: [i32] (default: `60`): The maximum width of the label rect.
+/// - `tick_overflow`: [`TickOverflow`] (default: [`TickOverflow::AutoSkip`]): What to do with the
+///   category labels when they don't fit side by side.
+/// - `label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the
+///   generated labels for values.
+/// - `number_format`: [`NumberFormat`] (optional): The formatting of the generated labels when
+///   there is no `label_interpolation` function.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the generated labels, which
+///   keep their full precision when neither it nor `number_format` is set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the generated labels
+///   are rounded to the `label_decimals`, or to the decimals of the `number_format`.
+/// - `axis_unit`: &[str] (optional): A unit appended to the value axis labels, i.e., `"MiB"`.
+/// - `axis_divisor`: [f32] (optional): The value axis labels show the values divided by it, i.e.,
+///   `1048576.0` for bytes in MiB. The ticks land on round divided values while the data and
+///   the bar value labels keep the original values.
+/// - `scale_value_labels`: [bool] (default: `false`): Divide the bar value labels too, and
+///   append the unit to them.
+/// ---
+/// - `bar_width`: &[str] (default: `"5%"`): The width of each bar.
+/// - `line_width`: &[str] (default: `"1%"`): The width of the cumulative line.
+/// - `dot_size`: &[str] (default: `"3%"`): The size of the cumulative line dots.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the bars and the cumulative line, in
+///   that order, cycled when there is only one. The default shades of red are used when not set.
+/// ---
+/// - `onclick_bar`: [EventHandler]<[usize]> (optional): Called with the index in `series` of the
+///   value of the clicked bar, as given before the sorting. The bars get a pointer cursor when it
+///   is set.
+/// - `onmouseover_bar`: [EventHandler]<[usize]> (optional): Like `onclick_bar` when the pointer
+///   enters a bar.
+/// - `onmouseout_bar`: [EventHandler]<[usize]> (optional): Like `onclick_bar` when the pointer
+///   leaves a bar.
+/// ---
+/// - `class_chart_pareto`: &[str] (default: `"dx-chart-pareto"`): The HTML element `class` of
+///   the chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
+/// - `class_bar`: &[str] (default: `"dx-bar"`): The HTML element `class` of every bar.
+/// - `class_bar_group`: &[str] (default: `"dx-bar-group"`): The HTML element `class` of the
+///   group of bars.
+/// - `class_bar_label`: &[str] (default: `"dx-bar-label"`): The HTML element `class` of the bar
+///   labels.
+/// - `class_line`: &[str] (default: `"dx-line"`): The HTML element `class` of the cumulative
+///   line group.
+/// - `class_line_path`: &[str] (default: `"dx-line-path"`): The HTML element `class` of the
+///   cumulative line path.
+/// - `class_line_dot`: &[str] (default: `"dx-line-dot"`): The HTML element `class` of the
+///   cumulative line dots.
+/// - `class_reference_line`: &[str] (default: `"dx-reference-line"`): The HTML element `class`
+///   of the reference line.
+/// - `class_plot_background`: &[str] (default: `"dx-plot-background"`): The HTML element `class`
+///   of the plot area background.
+/// - `class_plot_border`: &[str] (default: `"dx-plot-border"`): The HTML element `class` of the
+///   plot area border.
+/// - `class_grid`: &[str] (default: `"dx-grid"`): The HTML element `class` of the grid.
+/// - `class_grid_line`: &[str] (default: `"dx-grid-line"`): The HTML element `class` of every grid
+///   line.
+/// - `class_grid_label`: &[str] (default: `"dx-grid-label"`): The HTML element `class` of the grid
+///   labels.
+/// - `class_grid_labels`: &[str] (default: `"dx-grid-labels"`): The HTML element `class` of the
+///   group of grid labels.
+#[allow(non_snake_case)]
+pub fn ParetoChart<'a>(cx: Scope<'a, ParetoChartProps<'a>>) -> Element<'a> {
+    if cx.props.series.is_empty() {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart_pareto,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
+    if cx.props.domain.bounds(0.0, 1.0).is_none() {
+        return cx.render(rsx!("Pareto chart error: empty or inverted manual domain"));
+    }
+
+    let sanitized = sanitize_series(&cx.props.series);
+    let (sorted_series, sorted_labels) = sort_descending_with_labels(&sanitized, &cx.props.labels);
+    // The index in `series` of every sorted bar, for the events
+    let order = &descending_order(&sanitized);
+    let cumulative = cumulative_percentages(&sorted_series);
+    let series = vec![sorted_series];
+
+    let view = Rect::new(
+        cx.props.padding_left as f32,
+        cx.props.padding_top as f32,
+        (cx.props.viewbox_width - cx.props.padding_right) as f32,
+        (cx.props.viewbox_height - cx.props.padding_bottom) as f32,
+    );
+
+    let max_ticks = cx.props.max_ticks.max(3);
+
+    let number_format = label_number_format(
+        cx.props.number_format.as_ref(),
+        cx.props.label_decimals,
+        cx.props.rounding,
+    );
+
+    let axis_x = Axis::builder()
+        .with_view(view)
+        .with_grid_ticks(cx.props.show_grid_ticks)
+        .with_label_size(cx.props.label_size)
+        .with_centered_labels(Some(&sorted_labels));
+
+    let axis_y = Axis::builder()
+        .with_view(view)
+        .with_max_ticks(max_ticks)
+        .with_tick_steps(cx.props.tick_steps)
+        .with_grid_ticks(cx.props.show_grid_ticks)
+        .with_series(&series)
+        .with_label_interpolation(cx.props.label_interpolation)
+        .with_number_format(number_format.as_ref())
+        .with_divisor(cx.props.axis_divisor)
+        .with_unit(cx.props.axis_unit)
+        .with_lowest(Some(0.0))
+        .with_domain(cx.props.domain);
+
+    let grid = Grid::new(axis_x, axis_y);
+    let lines = grid.lines(true, true);
+    let tick_centers = grid.x.tick_centers();
+    let percent_to_view = |p: f32| view.max.y - p / 100.0 * view.height();
+
+    let dotted_stroke = if cx.props.show_dotted_grid {
+        &"2px"
+    } else {
+        &"0px"
+    };
+    let generated_labels = grid.y.generated_labels();
+
+    let grid_labels = if cx.props.show_labels {
+        Some(
+            grid.y
+                .text_data(generated_labels.len())
+                .into_iter()
+                .zip(generated_labels.iter())
+                .collect::<Vec<(TextData, &String)>>(),
+        )
+    } else {
+        None
+    };
+
+    let percent_labels = if cx.props.show_labels {
+        Some(
+            (0..=100)
+                .step_by(20)
+                .map(|p| {
+                    (
+                        TextData {
+                            x: view.max.x + 6.0,
+                            y: percent_to_view(p as f32),
+                            anchor: "start",
+                            baseline: "middle",
+                        },
+                        format!("{p}%"),
+                    )
+                })
+                .collect::<Vec<(TextData, String)>>(),
+        )
+    } else {
+        None
+    };
+
+    let grid_centered_labels = if cx.props.show_labels {
+        Some(
+            grid.x
+                .centered_text_rects(sorted_labels.len() as i32)
+                .into_iter()
+                .zip(sorted_labels.iter())
+                .zip(
+                    grid.x
+                        .label_overflow(&sorted_labels, cx.props.tick_overflow, None, 0.0),
+                )
+                .filter_map(|((mut rect, label), offset)| {
+                    offset.map(|offset| {
+                        rect.min.y += offset;
+                        (rect, label)
+                    })
+                })
+                .collect::<Vec<(Rect, &String)>>(),
+        )
+    } else {
+        None
+    };
+
+    let line_points = tick_centers
+        .iter()
+        .zip(cumulative.iter())
+        .map(|(point, p)| Point::new(point.x, percent_to_view(*p)))
+        .collect::<Vec<Point>>();
+
+    let mut path = PathBuilder::with_capacity(line_points.len());
+    for (i, point) in line_points.iter().enumerate() {
+        if i == 0 {
+            path.move_to(*point);
+        } else {
+            path.line_to(*point);
+        }
+    }
+    let commands = path.build();
+
+    let reference_y = percent_to_view(cx.props.reference_value.clamp(0.0, 100.0));
+
+    let (label_divisor, label_unit) = if cx.props.scale_value_labels {
+        (
+            cx.props.axis_divisor.filter(|d| *d > 0.0).unwrap_or(1.0),
+            cx.props.axis_unit,
+        )
+    } else {
+        (1.0, None)
+    };
+
+    let (plot_width, plot_height) = (view.width(), view.height());
+    let (bar_color, line_color) = &match &cx.props.colors {
+        Some(colors) if !colors.is_empty() => (colors[0].clone(), colors[1 % colors.len()].clone()),
+        _ => (
+            "rgb(180, 40, 40)".to_string(),
+            "rgb(142.5, 40, 40)".to_string(),
+        ),
+    };
+    let bar_style = if cx.props.onclick_bar.is_some() {
+        "cursor: pointer"
+    } else {
+        ""
+    };
+
+    cx.render(rsx! {
+        div {
+            svg {
+                xmlns: "http://www.w3.org/2000/svg",
+                width: "{cx.props.width}",
+                height: "{cx.props.height}",
+                class: "{cx.props.class_chart_pareto}",
+                preserveAspectRatio: "xMidYMid meet",
+                view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                cx.props.show_plot_background.then(|| rsx! {
+                    rect {
+                        key: "plot_background",
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_background}",
+                        fill: "white",
+                    }
+                }),
+                cx.props.show_grid.then(|| rsx! {
+                    g {
+                        key: "grid",
+                        class: "{cx.props.class_grid}",
+                        lines.iter().enumerate().map(|(n, line)| {
+                            rsx! {
+                                line {
+                                    key: "grid-line-{n}",
+                                    x1: "{line.min.x}",
+                                    y1: "{line.min.y}",
+                                    x2: "{line.max.x}",
+                                    y2: "{line.max.y}",
+                                    class: "{cx.props.class_grid_line}",
+                                    stroke: "rgba(20, 20, 20, 0.8)",
+                                    stroke_dasharray: "{dotted_stroke}",
+                                }
+                            }
+                        }),
+                    }
+                }),
+                cx.props.show_plot_border.then(|| rsx! {
+                    rect {
+                        key: "plot_border",
+                        x: "{view.min.x}",
+                        y: "{view.min.y}",
+                        width: "{plot_width}",
+                        height: "{plot_height}",
+                        class: "{cx.props.class_plot_border}",
+                        fill: "none",
+                        stroke: "rgba(20, 20, 20, 0.8)",
+                    }
+                }),
+                grid_labels.map(|labels| rsx! {
+                    g {
+                        key: "grid_labels",
+                        class: "{cx.props.class_grid_labels}",
+                        labels.iter().enumerate().map(|(n, (text, label))| rsx! {
+                            MultilineText {
+                                key: "grid-label-{n}",
+                                x: text.x,
+                                y: text.y,
+                                text: label.as_str(),
+                                text_anchor: text.anchor,
+                                class: cx.props.class_grid_label,
+                                alignment_baseline: text.baseline,
+                            }
+                        })
+                    }
+                }),
+                percent_labels.map(|labels| rsx! {
+                    g {
+                        key: "percent_labels",
+                        class: "{cx.props.class_grid_labels}",
+                        labels.iter().enumerate().map(|(n, (text, label))| rsx! {
+                            MultilineText {
+                                key: "percent-label-{n}",
+                                x: text.x,
+                                y: text.y,
+                                text: label.as_str(),
+                                text_anchor: text.anchor,
+                                class: cx.props.class_grid_label,
+                                alignment_baseline: text.baseline,
+                            }
+                        })
+                    }
+                }),
+                grid_centered_labels.map(|labels| rsx! {
+                    g {
+                        key: "grid_centered_labels",
+                        class: "{cx.props.class_grid_labels}",
+                        labels.iter().enumerate().map(|(n, (rect, label))| rsx! {
+                            foreignObject {
+                                key: "category-label-{n}",
+                                x: "{rect.min.x}",
+                                y: "{rect.min.y}",
+                                width: "{rect.max.x}",
+                                height: "{rect.max.y}",
+                                span {
+                                    class: "{cx.props.class_grid_label}",
+                                    width: "100%",
+                                    height: "100%",
+                                    display: "inline-block",
+                                    line_height: "1",
+                                    text_align: "center",
+                                    [label.as_str()]
+                                }
+                            }
+                        })
+                    }
+                }),
+                g {
+                    key: "bars",
+                    class: "{cx.props.class_bar_group}-0",
+                    tick_centers.iter().zip(series[0].iter()).enumerate().map(|(i, (point, v))| {
+                        let index = order[i];
+                        let end = grid.y.world_to_view(*v, 0.0);
+                        let bar_label = format_scaled(
+                            *v,
+                            cx.props.label_interpolation,
+                            number_format.as_ref(),
+                            label_divisor,
+                            label_unit,
+                        );
+
+                        rsx! {
+                            line {
+                                key: "bar-{i}",
+                                x1: "{point.x}",
+                                y1: "{point.y}",
+                                x2: "{point.x}",
+                                y2: "{end}",
+                                class: "{cx.props.class_bar}",
+                                stroke: "{bar_color}",
+                                stroke_width: "{cx.props.bar_width}",
+                                style: "{bar_style}",
+                                onclick: move |_| {
+                                    if let Some(handler) = &cx.props.onclick_bar {
+                                        handler.call(index);
+                                    }
+                                },
+                                onmouseover: move |_| {
+                                    if let Some(handler) = &cx.props.onmouseover_bar {
+                                        handler.call(index);
+                                    }
+                                },
+                                onmouseout: move |_| {
+                                    if let Some(handler) = &cx.props.onmouseout_bar {
+                                        handler.call(index);
+                                    }
+                                },
+                            },
+                            cx.props.show_series_labels.then(|| rsx! {
+                                text {
+                                    key: "bar-label-{i}",
+                                    dx: "{point.x}",
+                                    dy: format_args!("{}", end - 5.0),
+                                    text_anchor: "middle",
+                                    class: "{cx.props.class_bar_label}",
+                                    alignment_baseline: "text-bottom",
+                                    [bar_label.as_str()]
+                                }
+                            }),
+                        }
+                    })
+                },
+                cx.props.show_reference_line.then(|| rsx! {
+                    line {
+                        key: "reference_line",
+                        x1: "{view.min.x}",
+                        y1: "{reference_y}",
+                        x2: "{view.max.x}",
+                        y2: "{reference_y}",
+                        class: "{cx.props.class_reference_line}",
+                        stroke: "rgba(20, 20, 20, 0.8)",
+                        stroke_dasharray: "6px",
+                    }
+                }),
+                g {
+                    key: "cumulative_line",
+                    class: "{cx.props.class_line}-0",
+                    path {
+                        d: "{commands}",
+                        class: "{cx.props.class_line_path}",
+                        stroke: "{line_color}",
+                        stroke_width: "{cx.props.line_width}",
+                        stroke_linecap: "round",
+                        fill: "transparent",
+                    },
+                    cx.props.show_dots.then(|| rsx! {
+                        line_points.iter().enumerate().map(|(i, point)| rsx! {
+                            line {
+                                key: "dot-{i}",
+                                x1: "{point.x}",
+                                y1: "{point.y}",
+                                x2: format_args!("{}", point.x + 0.1),
+                                y2: "{point.y}",
+                                class: "{cx.props.class_line_dot}",
+                                stroke: "{line_color}",
+                                stroke_width: "{cx.props.dot_size}",
+                                stroke_linecap: "round",
+                            }
+                        })
+                    }),
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::rendered_attributes;
+
+    #[test]
+    fn empty_data_renders_the_empty_state() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! { ParetoChart { series: vec![], labels: vec![] } })
+        }
+
+        assert!(rendered_attributes(app, "class").contains(&"dx-empty".to_string()));
+    }
+}
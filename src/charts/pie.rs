@@ -1,8 +1,38 @@
 use dioxus::prelude::*;
+use log::warn;
 
-use crate::types::{Labels, Point};
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::multiline_text::MultilineText;
+use crate::geometry::arc::RingSegment;
+use crate::types::{Labels, Point, Rect, TextData};
+use crate::utils::color::{is_light, red_shade};
+use crate::utils::format::{label_number_format, round_to, NumberFormat, RoundingMode};
+use crate::utils::path::PathBuilder;
+use crate::utils::text::{fit_font_size, format_scaled, DEFAULT_FONT_SIZE};
+use crate::utils::transform::{
+    check_total, is_valid_share, sanitize_series, small_shares, TotalError,
+};
 use crate::utils::{normalize_series, polar_to_cartesian};
 
+pub use crate::types::ContextMenu;
+
+/// The space between the arc tick marks and their values.
+const ARC_TICK_LABEL_GAP: f32 = 4.0;
+/// The length of the gauge needle relative to the radius.
+const NEEDLE_LENGTH: f32 = 0.9;
+/// The width of the gauge needle at the center.
+const NEEDLE_WIDTH: f32 = 6.0;
+/// The radius of the cap over the center of the gauge needle.
+const NEEDLE_CAP_RADIUS: f32 = 6.0;
+/// The shortest distance from the pie to the bend of the label leader lines.
+const LEADER_ELBOW: f32 = 10.0;
+/// The length of the horizontal end of the label leader lines.
+const LEADER_RUN: f32 = 12.0;
+/// The space between the leader lines and their labels.
+const LEADER_LABEL_GAP: f32 = 4.0;
+/// The fill of the slice covering the shortfall below `strict_total`.
+const UNACCOUNTED_FILL: &str = "rgb(220, 220, 220)";
+
 /// A hint for the automatic positioning of labels in the pie chart.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum LabelPosition {
@@ -14,12 +44,138 @@ pub enum LabelPosition {
     Center,
 }
 
+/// Where the labels of the pie chart are shown.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LabelsMode {
+    /// Draw the labels on the chart.
+    OnChart,
+    /// Don't show any labels.
+    None,
+    /// Don't draw any labels on the chart, leaving them to the parent through the `on_layout`
+    /// event, i.e., with a [PieLegendList].
+    External,
+}
+
+/// What the generated labels of the pie slices show.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LabelContent {
+    /// The formatted value of the slice.
+    Value,
+    /// The share of the pie arc taken by the slice, i.e., `23%`.
+    Percent,
+    /// The formatted value followed by the share, i.e., `12.5 (23%)`, or the label followed by
+    /// the share when there are labels, i.e., `Asia 23%`.
+    ValueAndPercent,
+}
+
+/// The order of the slices around the pie.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortOrder {
+    /// From the smallest slice to the largest one.
+    Ascending,
+    /// From the largest slice to the smallest one.
+    Descending,
+}
+
+/// The shape of the pie chart slices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PieShape {
+    /// Slices reaching the center of the pie.
+    Pie,
+    /// Ring segments around a hole, `donut_width` wide.
+    Donut,
+}
+
+/// `true` for [`PieShape::Donut`], like the former `donut` flag.
+impl From<bool> for PieShape {
+    fn from(donut: bool) -> Self {
+        if donut {
+            PieShape::Donut
+        } else {
+            PieShape::Pie
+        }
+    }
+}
+
+/// The direction the slices are drawn in around the pie, from `start_angle`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Clockwise, the default.
+    Clockwise,
+    /// Counter-clockwise, mirroring the clockwise pie across `start_angle`.
+    CounterClockwise,
+}
+
+/// The description of a pie slice as laid out, sent by the `on_layout` event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliceInfo {
+    /// The index of the slice value in the series.
+    pub index: usize,
+    /// The slice label, or its generated label following `label_content` when there are no
+    /// labels.
+    pub label: String,
+    /// The CSS color of the slice.
+    pub color: String,
+    /// The slice value as a ratio of the series total, from `0.0` to `1.0`.
+    pub ratio: f32,
+}
+
+/// The part of the pie a slice is drawn in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlicePart {
+    /// The `series`: the whole pie, its left half with `compare_series` or its outer ring with
+    /// `series_rings`.
+    Primary,
+    /// The right half, drawn from `compare_series`.
+    Compare,
+    /// A ring of `series_rings`, by its index in `series_rings`.
+    Ring(usize),
+    /// The remainder slice of `show_remainder`, sent with `remainder_in_events`. Its index is
+    /// the length of `series`.
+    Remainder,
+}
+
+/// The payload of the slice events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceEvent {
+    /// The index of the slice value in the series of its part.
+    pub index: usize,
+    /// The part of the pie the slice is drawn in.
+    pub part: SlicePart,
+}
+
+/// The description of a slice sent to the `label_format` function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliceLabel {
+    /// The index of the slice value in the series, or `series.len()` for the Other slice.
+    pub index: usize,
+    /// The slice value in the series.
+    pub value: f32,
+    /// The share of the pie arc taken by the slice, from `0.0` to `100.0`. For gauges it is
+    /// relative to the whole gauge, i.e., `59.5` for a `show_ratio` of `0.595`.
+    pub percentage: f32,
+    /// The slice label from `labels`, if any, or `other_label` for the Other slice.
+    pub label: Option<String>,
+    /// Whether the slice is the Other slice grouping the values below `other_threshold`.
+    pub is_other: bool,
+}
+
 /// The `PieChart` properties struct for the configuration of the pie chart.
-#[derive(PartialEq, Props)]
+#[derive(Props)]
 pub struct PieChartProps<'a> {
     series: Vec<f32>,
     #[props(optional)]
     labels: Option<Labels>,
+    #[props(optional)]
+    angle_values: Option<Vec<f32>>,
+    #[props(optional)]
+    compare_series: Option<Vec<f32>>,
+    #[props(optional)]
+    series_rings: Option<Vec<Vec<f32>>>,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+    #[props(optional)]
+    explode: Option<Vec<f32>>,
 
     #[props(default = "100%")]
     width: &'a str,
@@ -29,38 +185,148 @@ pub struct PieChartProps<'a> {
     viewbox_width: i32,
     #[props(default = 400)]
     viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
+    #[props(default = false)]
+    strict: bool,
 
     #[props(default = true)]
     show_labels: bool,
+    #[props(default = LabelsMode::OnChart)]
+    labels_mode: LabelsMode,
+    #[props(optional)]
+    on_layout: Option<EventHandler<'a, Vec<SliceInfo>>>,
+    #[props(optional)]
+    onclick_slice: Option<EventHandler<'a, SliceEvent>>,
+    #[props(optional)]
+    onmouseover_slice: Option<EventHandler<'a, SliceEvent>>,
+    #[props(optional)]
+    onmouseout_slice: Option<EventHandler<'a, SliceEvent>>,
+    #[props(optional)]
+    on_context_menu: Option<EventHandler<'a, ContextMenu<SliceEvent>>>,
     #[props(default=LabelPosition::Inside)]
     label_position: LabelPosition,
     #[props(default)]
     label_offset: f32,
+    #[props(default = false)]
+    label_leader_lines: bool,
+    #[props(default = false)]
+    label_all_rings: bool,
+    #[props(default = false)]
+    auto_label_contrast: bool,
     #[props(optional)]
     label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    label_format: Option<fn(SliceLabel) -> String>,
+    #[props(default)]
+    min_label_angle: f32,
+    #[props(default = LabelContent::Value)]
+    label_content: LabelContent,
+    #[props(default = 0)]
+    percent_decimals: u8,
+    #[props(optional)]
+    number_format: Option<NumberFormat>,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
 
     #[props(default)]
     start_angle: f32,
     #[props(optional)]
+    end_angle: Option<f32>,
+    #[props(default = Direction::Clockwise)]
+    direction: Direction,
+    #[props(optional)]
     total: Option<f32>,
     #[props(optional)]
     show_ratio: Option<f32>,
+    #[props(default = false)]
+    show_remainder: bool,
+    #[props(default = false)]
+    remainder_in_events: bool,
+
+    #[props(optional)]
+    strict_total: Option<f32>,
+    #[props(default = 0.01)]
+    total_tolerance: f32,
+    #[props(default = false)]
+    pad_to_total: bool,
     #[props(default)]
     padding: f32,
+    #[props(default)]
+    pad_angle: f32,
+    #[props(optional)]
+    sort: Option<SortOrder>,
+    #[props(optional)]
+    other_threshold: Option<f32>,
+    #[props(default = "Other")]
+    other_label: &'a str,
 
-    #[props(default = false)]
-    donut: bool,
+    #[props(default = PieShape::Pie, into)]
+    donut: PieShape,
     #[props(default = 40.0)]
     donut_width: f32,
+    #[props(default)]
+    ring_gap: f32,
+    #[props(default)]
+    corner_radius: f32,
+    #[props(default = false)]
+    ring_value_labels: bool,
+    #[props(optional)]
+    center_label: Option<String>,
+    #[props(default)]
+    center_content: Element<'a>,
+
+    #[props(default = false)]
+    show_arc_ticks: bool,
+    #[props(default = 5)]
+    arc_tick_count: usize,
+    #[props(optional)]
+    arc_tick_values: Option<Vec<f32>>,
+    #[props(default = 8.0)]
+    arc_tick_length: f32,
+    #[props(default = false)]
+    needle: bool,
+    #[props(optional)]
+    needle_value: Option<f32>,
 
     #[props(default = "dx-pie-chart")]
     class_chart: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
     #[props(default = "dx-series")]
     class_series: &'a str,
     #[props(default = "dx-slice")]
     class_slice: &'a str,
+    #[props(default = "dx-slice--hover")]
+    class_slice_hover: &'a str,
+    #[props(default = "dx-slice-unaccounted")]
+    class_unaccounted: &'a str,
     #[props(default = "dx-label")]
     class_label: &'a str,
+    #[props(optional)]
+    class_label_small: Option<&'a str>,
+    #[props(default = "dx-label--on-dark")]
+    class_label_on_dark: &'a str,
+    #[props(default = "dx-label--on-light")]
+    class_label_on_light: &'a str,
+    #[props(default = "dx-label-line")]
+    class_label_line: &'a str,
+    #[props(default = "dx-divider")]
+    class_divider: &'a str,
+    #[props(default = "dx-ring-label")]
+    class_ring_label: &'a str,
+    #[props(default = "dx-remainder")]
+    class_remainder: &'a str,
+    #[props(default = "dx-arc-tick")]
+    class_arc_tick: &'a str,
+    #[props(default = "dx-arc-tick-label")]
+    class_arc_tick_label: &'a str,
+    #[props(default = "dx-needle")]
+    class_needle: &'a str,
 }
 
 /// This is the `PieChart` function used to render the pie chart `Element`.
@@ -71,7 +337,7 @@ pub struct PieChartProps<'a> {
 ///
 /// ```rust,ignore
 /// use dioxus::prelude::*;
-/// use dioxus_charts::PieChart;
+/// use dioxus_charts::prelude::*;
 ///
 /// fn app(cx: Scope) -> Element {
 ///     cx.render(rsx! {
@@ -89,75 +355,818 @@ pub struct PieChartProps<'a> {
 ///
 /// # Props
 ///
-/// - `series`: [Vec]<[f32]> (**required**): The series vector with the values.
+/// - `series`: [Vec]<[f32]> (**required**): The series vector with the values. `NaN`, infinite
+///   and negative values have no slice, like zero values, unless `strict` is set.
 /// - `labels`: [Vec]<[String]> (optional): Optional labels to show for each value of the
-/// series.
+///   series, matched by index. A length other than the series one logs a warning: the extra
+///   labels are ignored, and the values without a label have no label on the chart, although
+///   `on_layout` still gets their formatted values.
+/// - `angle_values`: [Vec]<[f32]> (optional): Values used to compute the slice angles instead of
+///   `series`, which is still used for the generated labels. Must have the same length as
+///   `series`.
+/// - `compare_series`: [Vec]<[f32]> (optional): A series to compare against, i.e., last year's
+///   composition. When set, `series` is drawn on the left half of the pie and `compare_series` on
+///   the right half, each normalized to its own half, sharing the labels and colors. The labels
+///   of each half are spread outside of it with leader lines, whatever the `label_position`. The
+///   `start_angle`, `total` and `show_ratio` props are ignored in this mode.
+/// - `series_rings`: [Vec]<[Vec]<[f32]>> (optional): More series drawn as concentric rings inside
+///   the `series` ring, from the outside in, i.e., last year's composition inside this year's.
+///   Every ring is normalized to the whole arc on its own and shares the labels and colors of
+///   `series` by index. The ring widths are computed from the radius and `ring_gap`, or are
+///   `donut_width` wide for donut charts. The `total`, `show_ratio`, `strict_total`, `explode`
+///   and `other_threshold` props only apply to the outer `series` ring. Ignored with
+///   `compare_series`.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the slices, i.e., `"#4e79a7"`, used in
+///   order for the visible slices and cycled when there are more slices than colors. Zero values
+///   have no slice and take no color. The generated shades of red are used when not set.
+/// - `explode`: [Vec]<[f32]> (optional): An offset for every value of the series, in view box
+///   units, pulling its slice and label away from the center along the middle of the slice.
+///   `0.0` leaves the slice in place. With `compare_series` it applies to both halves.
 /// ---
 /// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
-/// other CSS style, i.e., "200px"
+///   other CSS style, i.e., "200px"
 /// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
 /// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
-/// `viewbox_height` it is useful scaling up or down the chart and labels.
+///   `viewbox_height` it is useful scaling up or down the chart and labels.
 /// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
+/// - `strict`: [bool] (default: `false`): Render an error for any `NaN`, infinite or negative
+///   value in `series`, `angle_values`, `compare_series` or `series_rings`, instead of skipping
+///   them like zero values. See [`sanitize_series`](crate::utils::transform::sanitize_series).
 /// ---
 /// - `show_labels`: [bool] (default: `true`): Show/hide labels.
+/// - `labels_mode`: [`LabelsMode`] (default: [`LabelsMode::OnChart`]): Where the labels are
+///   shown. Anything but `OnChart` hides all the labels drawn on the chart.
+/// - `on_layout`: [EventHandler]<[Vec]<[`SliceInfo`]>> (optional): Called with every value of
+///   `series`, in order, whenever their labels, colors or ratios change, including the first
+///   render. Zero values are included even though they have no slice.
+/// - `onclick_slice`: [EventHandler]<[`SliceEvent`]> (optional): Called with the index of the
+///   slice value and the part of the pie it is in, i.e., which half with `compare_series` or
+///   which ring with `series_rings`, when a slice is clicked.
+/// - `onmouseover_slice`: [EventHandler]<[`SliceEvent`]> (optional): Called with the slice like
+///   `onclick_slice` when the pointer enters a slice.
+/// - `onmouseout_slice`: [EventHandler]<[`SliceEvent`]> (optional): Called with the slice like
+///   `onclick_slice` when the pointer leaves a slice.
+/// - `on_context_menu`: [EventHandler]<[`ContextMenu`]<[`SliceEvent`]>> (optional): Called when a
+///   slice is right-clicked, with the slice like `onclick_slice`, the pointer position and the
+///   slice position in the viewbox. The browser menu is only prevented when it is set.
 /// - `label_position`: [`LabelPosition`] (default: [`LabelPosition::Inside`]): A hint for the
-/// automatic positioning of labels on the chart.
+///   automatic positioning of labels on the chart.
 /// - `label_offset`: [f32] (default: `0.0`): An extra offset for the labels relative to the center
-/// of the pie.
+///   of the pie.
+/// - `label_leader_lines`: [bool] (default: `false`): With [`LabelPosition::Outside`], spread the
+///   labels vertically on each side of the pie so they don't overlap, and draw a line from every
+///   slice to its label. The labels are aligned away from the pie.
+/// - `label_all_rings`: [bool] (default: `false`): With `series_rings`, label the slices of every
+///   ring in the middle of the ring, instead of only the outer `series` ring. It also applies to
+///   the ring value labels.
+/// - `auto_label_contrast`: [bool] (default: `false`): Make the labels drawn over the slices,
+///   with [`LabelPosition::Inside`], and the ring value labels readable on any slice color, in
+///   a group with either the `class_label_on_dark` class and a white fill, or the
+///   `class_label_on_light` class and a dark fill, depending on the luminance of the slice color.
+///   The slice colors come from `colors` or the generated shades, so fills set by external CSS
+///   aren't taken into account, and colors that [`is_light`](crate::utils::color::is_light)
+///   can't parse, i.e., named colors, keep the labels unchanged.
 /// - `label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the
-/// generated labels.
+///   generated labels.
+/// - `number_format`: [`NumberFormat`] (optional): The formatting of the generated labels when
+///   there is no `label_interpolation` function.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the generated labels, which
+///   keep their full precision when neither it nor `number_format` is set.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the generated labels
+///   are rounded to the `label_decimals`, or to the decimals of the `number_format`, and the shares
+///   to the `percent_decimals`.
+/// - `label_format`: fn([`SliceLabel`]) -> [String] (optional): Function for formatting every
+///   slice label from its value, percentage, index and label, i.e., `"Asia — 59.5%"`. It replaces
+///   `labels`, `label_interpolation` and `number_format` for the labels on the chart, the ring
+///   value labels and the `on_layout` labels, and shows the labels even with `labels` set.
+/// - `min_label_angle`: [f32] (default: `0.0`): Hides the labels of the slices narrower than this
+///   angle, in degrees, as drawn after the `total`, `show_ratio` and `pad_angle` adjustments,
+///   while the slices are still drawn. It applies to the `labels` and the generated labels, but
+///   not to the ring value labels, which are hidden when they don't fit anyway.
+/// - `label_content`: [`LabelContent`] (default: [`LabelContent::Value`]): What the generated
+///   labels show, including the ring value labels: the value, its share of the pie arc, or
+///   both. With `labels`, only [`LabelContent::ValueAndPercent`] changes them, appending the
+///   share to every label. For gauges the share is relative to the whole gauge, so a
+///   `show_ratio` of `0.23` shows `23%`.
+/// - `percent_decimals`: [u8] (default: `0`): The number of decimals of the shares shown by
+///   `label_content`.
 /// ---
 /// - `start_angle`: [f32] (default: `0.0`): The initial angle used for drawing the pie.
-/// - `total`: [f32] (optional): The series total sum. Can be used to make Gauge charts.
+/// - `end_angle`: [f32] (optional): The angle where the pie ends, clockwise from `start_angle`,
+///   mapping the whole series onto that arc, i.e., `start_angle: -90.0` and `end_angle: 90.0`
+///   for a top half-pie. The pie is scaled and moved to fill the view box, so a top half-pie only
+///   needs a `viewbox_height` of about half the `viewbox_width`, plus the room for the labels.
+///   Ignored with `compare_series`.
+/// - `direction`: [`Direction`] (default: [`Direction::Clockwise`]): The direction the slices are
+///   drawn in from `start_angle`, and towards `end_angle`. The slice classes still follow the
+///   drawing order. Ignored with `compare_series`.
+/// - `total`: [f32] (optional): The series total sum, or the `angle_values` one when given. Can be
+///   used to make Gauge charts.
 /// - `show_ratio`: [f32] (optional): Used for making Gauge charts more easily. `0.0001` to
-/// `1.0` is the same as `0%` to `100%`.
+///   `1.0` is the same as `0%` to `100%`.
+/// - `show_remainder`: [bool] (default: `false`): Draw the part of the arc left empty by
+///   `show_ratio` or `total` as a faint track slice, without a label, `pad_angle` away from
+///   the data slices. Ignored with `compare_series` or `pad_to_total`.
+/// - `remainder_in_events`: [bool] (default: `false`): Send the slice events for the remainder
+///   slice too, with [`SlicePart::Remainder`]. It has no events otherwise.
+/// - `strict_total`: [f32] (optional): The total the `series` must add up to, i.e., `100.0` for
+///   survey percentages, instead of normalizing them silently. A series sum over the total is an
+///   error, and so is a shortfall unless `pad_to_total` is set. It can't be combined with
+///   `total` or `show_ratio`. See [`check_total`](crate::utils::transform::check_total).
+/// - `total_tolerance`: [f32] (default: `0.01`): How far the series sum can be from
+///   `strict_total`, i.e., for rounding errors.
+/// - `pad_to_total`: [bool] (default: `false`): Cover the shortfall of the series sum below
+///   `strict_total` with a grey unaccounted slice at the end of the pie instead of an error. The
+///   slice has no label or events. Ignored with `compare_series`.
 /// - `padding`: [f32] (default: `0.0`): Padding for every side of the SVG view box.
+/// - `pad_angle`: [f32] (default: `0.0`): The gap between neighbouring slices, in degrees, split
+///   evenly between both sides of every slice. Slices thinner than the gap shrink to nothing,
+///   and a single slice is never padded.
+/// - `sort`: [`SortOrder`] (optional): Draws the slices sorted by their angle, from
+///   `start_angle` clockwise, instead of in the series order. The slices keep the colors of their
+///   series values, and the events and labels still get the indices of the series. The slice
+///   classes follow the drawing order.
+/// - `other_threshold`: [f32] (optional): Groups the values whose share of the pie arc is below
+///   the threshold, from `0.0` to `1.0`, into a single Other slice drawn last, i.e., `0.02` for
+///   the values under `2%`. For gauges the share is relative to the whole gauge. The Other slice
+///   gets the index `series.len()` in the events, the labels and `on_layout`, and it is only
+///   drawn when at least two values are grouped. Ignored with `compare_series`.
+/// - `other_label`: &[str] (default: `"Other"`): The label of the Other slice.
 /// ---
-/// - `donut`: [bool] (default: `false`): Draw the slices differently to make a donut-looking chart
-/// instead.
+/// - `donut`: [`PieShape`] (default: [`PieShape::Pie`]): Draw the slices differently to make a
+///   donut-looking chart instead. Also accepts a [bool], `true` for a donut.
 /// - `donut_width`: [f32] (default: `40.0`): The width of each donut slice.
+/// - `ring_gap`: [f32] (default: `0.0`): The space between the rings of `series_rings`.
+/// - `corner_radius`: [f32] (default: `0.0`): Rounds the four corners of every donut slice with
+///   arcs of this radius, shrunk for thin slices so their corners still fit. Only for donut
+///   charts. See [`RingSegment`](crate::geometry::arc::RingSegment).
+/// - `ring_value_labels`: [bool] (default: `false`): Show the formatted value of every slice in
+///   the middle of its ring segment, shrunk to fit or hidden when the segment is too narrow.
+///   Only for donut charts, and drawn together with the other labels.
+/// - `center_label`: [String] (optional): A text drawn in the middle of the donut hole with the
+///   `class_label` class, i.e., the value of a gauge. Only for donut charts.
+/// - `center_content`: [Element] (optional): Any SVG content drawn in the donut hole, after
+///   `center_label`. It is placed in a group translated to the center of the pie, so `(0, 0)` is
+///   the middle of the hole, and clipped to the hole. It doesn't catch any pointer events, so the
+///   slices keep working. Only for donut charts.
+/// ---
+/// - `show_arc_ticks`: [bool] (default: `false`): Draw tick marks with their values outside the
+///   arc, like a speedometer, for Gauge charts. The ticks go from `0.0` to the value at the end
+///   of the gauge arc: `total` when it is set, or the series sum otherwise. Ignored with
+///   `compare_series`.
+/// - `arc_tick_count`: [usize] (default: `5`): The number of evenly spaced ticks, including both
+///   ends of the arc.
+/// - `arc_tick_values`: [Vec]<[f32]> (optional): The values of the ticks, overriding
+///   `arc_tick_count`.
+/// - `arc_tick_length`: [f32] (default: `8.0`): The length of the tick marks.
+/// - `needle`: [bool] (default: `false`): Draw a gauge needle from the center of the pie, with a
+///   round cap, above the slices. It points at `needle_value`, or at the `show_ratio` of the
+///   arc, or at the end of the slices otherwise. Ignored with `compare_series`.
+/// - `needle_value`: [f32] (optional): The value the needle points at, on the same scale as the
+///   arc ticks.
 /// ---
 /// - `class_chart`: &[str] (default: `"dx-pie-chart"`): The HTML element `class` of the
-/// pie chart.
+///   pie chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
 /// - `class_series`: &[str] (default: `"dx-series"`): The HTML element `class` for the group of
-/// pie slices.
+///   pie slices. The slices of every ring of `series_rings` also get it suffixed with the ring
+///   number, i.e., `"dx-series-ring-1"` for the first inner ring and `"dx-series-ring-0"` for
+///   the outer `series` ring.
 /// - `class_slice`: &[str] (default: `"dx-slice"`): The HTML element `class` for all pie
-/// slices.
+///   slices.
+/// - `class_slice_hover`: &[str] (default: `"dx-slice--hover"`): The HTML element `class` added
+///   to the slice under the pointer.
+/// - `class_unaccounted`: &[str] (default: `"dx-slice-unaccounted"`): The HTML element `class`
+///   added to the unaccounted slice of `pad_to_total`.
 /// - `class_label`: &[str] (default: `"dx-label"`): The HTML element `class` for all labels.
+/// - `class_label_small`: &[str] (optional): The HTML element `class` replacing `class_label`
+///   for the labels of the slices narrower than `min_label_angle`, which are drawn with it
+///   instead of hidden, i.e., to style them smaller.
+/// - `class_label_on_dark`: &[str] (default: `"dx-label--on-dark"`): The HTML element `class` of
+///   the group of a label over a dark slice with `auto_label_contrast`.
+/// - `class_label_on_light`: &[str] (default: `"dx-label--on-light"`): The HTML element `class` of
+///   the group of a label over a light slice with `auto_label_contrast`.
+/// - `class_label_line`: &[str] (default: `"dx-label-line"`): The HTML element `class` for the
+///   label leader lines.
+/// - `class_divider`: &[str] (default: `"dx-divider"`): The HTML element `class` for the line
+///   dividing the halves when `compare_series` is set.
+/// - `class_ring_label`: &[str] (default: `"dx-ring-label"`): The HTML element `class` for the
+///   values inside the donut ring.
+/// - `class_remainder`: &[str] (default: `"dx-remainder"`): The HTML element `class` for the
+///   remainder slice.
+/// - `class_arc_tick`: &[str] (default: `"dx-arc-tick"`): The HTML element `class` for the arc
+///   tick marks.
+/// - `class_arc_tick_label`: &[str] (default: `"dx-arc-tick-label"`): The HTML element `class`
+///   for the arc tick values.
+/// - `class_needle`: &[str] (default: `"dx-needle"`): The HTML element `class` for the gauge
+///   needle and its cap.
 #[allow(non_snake_case)]
-pub fn PieChart<'a>(cx: Scope<'a, PieChartProps<'a>>) -> Element {
-    if cx.props.series.is_empty() {
-        return cx.render(rsx!("Pie chart error: empty series"));
+pub fn PieChart<'a>(cx: Scope<'a, PieChartProps<'a>>) -> Element<'a> {
+    let last_layout = use_ref(&cx, || None::<Vec<SliceInfo>>);
+    let hovered = use_state(&cx, || None::<SliceEvent>);
+    // The browser menu is left alone without a handler
+    let prevent_context_menu = if cx.props.on_context_menu.is_some() {
+        "oncontextmenu"
+    } else {
+        ""
+    };
+
+    // Invalid values are skipped like zeros, unless they are an error in strict mode
+    let invalid_value = [Some(&cx.props.series), cx.props.angle_values.as_ref()]
+        .into_iter()
+        .flatten()
+        .chain(&cx.props.compare_series)
+        .chain(cx.props.series_rings.iter().flatten())
+        .flatten()
+        .find(|v| !is_valid_share(**v));
+    if let Some(value) = invalid_value.filter(|_| cx.props.strict) {
+        return cx.render(rsx!(
+            "Pie chart error: invalid value {value}, values must be finite and not negative"
+        ));
+    }
+    let valid_series = &sanitize_series(&cx.props.series);
+
+    if valid_series.iter().all(|v| *v == 0.0) {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
+    let angle_values = &match cx.props.angle_values {
+        Some(ref values) if values.len() != cx.props.series.len() => {
+            return cx.render(rsx!(
+                "Pie chart error: angle values and series lengths don't match"
+            ));
+        }
+        Some(ref values) => sanitize_series(values),
+        None => valid_series.clone(),
+    };
+
+    if cx.props.compare_series.as_ref().is_some_and(Vec::is_empty) {
+        return cx.render(rsx!("Pie chart error: empty compare series"));
     }
 
+    // The labels are matched to the values by index, so extra labels are never shown and the
+    // values without one have no label on the chart
+    if let Some(labels) = &cx.props.labels {
+        let values = cx
+            .props
+            .compare_series
+            .as_ref()
+            .map_or(0, Vec::len)
+            .max(cx.props.series.len());
+        if labels.len() != values {
+            warn!(
+                "pie chart labels don't match the series: {} labels for {values} values",
+                labels.len()
+            );
+        }
+    }
+
+    let rings = cx
+        .props
+        .series_rings
+        .as_ref()
+        .filter(|rings| !rings.is_empty() && cx.props.compare_series.is_none());
+    if rings.is_some_and(|rings| rings.iter().any(Vec::is_empty)) {
+        return cx.render(rsx!("Pie chart error: empty series ring"));
+    }
+
+    let strict_total = match cx.props.strict_total {
+        Some(_) if cx.props.total.is_some() || cx.props.show_ratio.is_some() => {
+            return cx.render(rsx!(
+                "Pie chart error: strict total can't be combined with total or show ratio"
+            ));
+        }
+        Some(total) => match check_total(valid_series, total, cx.props.total_tolerance) {
+            Ok(()) => None,
+            Err(TotalError::Shortfall(_))
+                if cx.props.pad_to_total && cx.props.compare_series.is_none() =>
+            {
+                Some(total)
+            }
+            Err(error) => return cx.render(rsx!("Pie chart error: {error}")),
+        },
+        None => None,
+    };
+
+    // Counter-clockwise pies are laid out clockwise and mirrored across the start angle
+    let mirror_axis = (cx.props.direction == Direction::CounterClockwise
+        && cx.props.compare_series.is_none())
+    .then_some(cx.props.start_angle);
+    let directed = |angle: f32| mirror_axis.map_or(angle, |axis| 2.0 * axis - angle);
+
+    let sweep = match cx.props.end_angle {
+        Some(end_angle) if cx.props.compare_series.is_none() => {
+            match (directed(end_angle) - cx.props.start_angle).rem_euclid(360.0) {
+                sweep if sweep > 0.0 => sweep,
+                _ => 360.0,
+            }
+        }
+        _ => 360.0,
+    };
+
+    // Fits the bounds of the arc, which are the whole circle for a full pie, in the view box
+    let margin = 30.0 + cx.props.padding;
+    let available = Point::new(
+        cx.props.viewbox_width as f32 - 2.0 * margin,
+        cx.props.viewbox_height as f32 - 2.0 * margin,
+    );
+    let bounds = match mirror_axis {
+        Some(_) => arc_bounds(cx.props.start_angle - sweep, sweep),
+        None => arc_bounds(cx.props.start_angle, sweep),
+    };
+    let radius = (available.x / bounds.width()).min(available.y / bounds.height());
     let center = Point::new(
-        cx.props.viewbox_width as f32 / 2.0,
-        cx.props.viewbox_height as f32 / 2.0,
+        margin + (available.x - radius * bounds.width()) / 2.0 - radius * bounds.min.x,
+        margin + (available.y - radius * bounds.height()) / 2.0 - radius * bounds.min.y,
     );
-    let center_min = center.x.min(center.y);
-    let radius = center_min - 30.0 - cx.props.padding;
+
+    // The series is the outer ring, and the series rings fill the radius inwards
+    let ring_count = 1 + rings.map_or(0, Vec::len);
+    let ring_gap = cx.props.ring_gap.max(0.0);
+    let ring_width = {
+        let fitted = (radius - (ring_count - 1) as f32 * ring_gap) / ring_count as f32;
+        match cx.props.donut {
+            PieShape::Donut if ring_count > 1 => cx.props.donut_width.min(fitted),
+            PieShape::Donut => cx.props.donut_width,
+            PieShape::Pie => fitted,
+        }
+    };
+    let ring_bounds = |ring: usize| {
+        let outer = radius - ring as f32 * (ring_width + ring_gap);
+        let inner = (cx.props.donut == PieShape::Donut || ring + 1 < ring_count)
+            .then_some(outer - ring_width);
+        (outer, inner)
+    };
+
     let label_radius = match cx.props.label_position {
+        LabelPosition::Inside if rings.is_some() => {
+            radius - ring_width / 2.0 + cx.props.label_offset
+        }
         LabelPosition::Inside => radius / 2.0 + cx.props.label_offset,
         LabelPosition::Outside => radius + cx.props.label_offset,
         LabelPosition::Center => 0.0 + cx.props.label_offset,
     };
 
-    let normalized_series = normalize_series(&cx.props.series);
+    let normalized_series = normalize_series(angle_values);
     let normalized_sum: f32 = normalized_series.iter().sum();
 
     let values_total: f32 = if let Some(r) = cx.props.show_ratio {
         1.0 / r.clamp(0.0001, 1.0) * normalized_sum
     } else if let Some(v) = cx.props.total {
-        (normalized_sum / cx.props.series.iter().sum::<f32>() * v).max(normalized_sum)
+        (normalized_sum / angle_values.iter().sum::<f32>() * v).max(normalized_sum)
+    } else if let Some(v) = strict_total {
+        // The unaccounted slice takes the share of the shortfall, whatever the angle values
+        normalized_sum / valid_series.iter().sum::<f32>() * v
     } else {
         normalized_sum
     };
 
-    let mut m_start_angle = cx.props.start_angle;
-    let mut color_var = 255.0;
-    let mut class_index = 0;
-    let mut label_positions = Vec::<Point>::new();
+    // The values grouped into the Other slice, which is appended after the series values
+    let other = cx
+        .props
+        .other_threshold
+        .filter(|_| cx.props.compare_series.is_none())
+        .and_then(|threshold| small_shares(&normalized_series, values_total, threshold));
+    let (normalized_series, series, labels) = match other {
+        Some(ref grouped) => {
+            let sum_grouped = |values: &[f32]| {
+                values
+                    .iter()
+                    .zip(grouped)
+                    .filter(|(_, g)| **g)
+                    .map(|(v, _)| v)
+                    .sum::<f32>()
+            };
+            let mut normalized = normalized_series
+                .iter()
+                .zip(grouped)
+                .map(|(v, g)| if *g { 0.0 } else { *v })
+                .collect::<Vec<f32>>();
+            normalized.push(sum_grouped(&normalized_series));
+            let mut series = valid_series.clone();
+            series.push(sum_grouped(valid_series));
+            let labels = cx.props.labels.clone().map(|mut labels| {
+                labels.resize(cx.props.series.len(), String::new());
+                labels.push(cx.props.other_label.to_string());
+                labels
+            });
+
+            (normalized, series, labels)
+        }
+        None => (
+            normalized_series,
+            valid_series.clone(),
+            cx.props.labels.clone(),
+        ),
+    };
+    let other_index = other.is_some().then_some(cx.props.series.len());
+    let labels = &labels;
+
+    let geometry = PieGeometry {
+        center,
+        radius,
+        donut_radius: ring_bounds(0).1,
+        label_radius,
+        pad_angle: cx.props.pad_angle.max(0.0),
+        sort: cx.props.sort,
+        pin_last: other_index.is_some(),
+        mirror_axis,
+        corner_radius: cx.props.corner_radius.max(0.0),
+    };
+
+    let explode = cx.props.explode.as_deref().unwrap_or_default();
+    let mut halves = if let Some(ref compare_series) = cx.props.compare_series {
+        let normalized_compare = normalize_series(compare_series);
+        let compare_total: f32 = normalized_compare.iter().sum();
+
+        vec![
+            (
+                geometry.slices(&normalized_series, normalized_sum, 180.0, 180.0, explode),
+                &series,
+                format!("{}-left", cx.props.class_series),
+                geometry,
+            ),
+            (
+                geometry.slices(&normalized_compare, compare_total, 0.0, 180.0, explode),
+                compare_series,
+                format!("{}-right", cx.props.class_series),
+                geometry,
+            ),
+        ]
+    } else {
+        vec![(
+            geometry.slices(
+                &normalized_series,
+                values_total,
+                cx.props.start_angle,
+                sweep,
+                explode,
+            ),
+            &series,
+            if rings.is_some() {
+                format!("{}-ring-0", cx.props.class_series)
+            } else {
+                String::new()
+            },
+            geometry,
+        )]
+    };
+
+    // The rest of the arc after the data slices, laid out like one more slice and kept
+    // `pad_angle` away from them
+    let filled = if values_total > 0.0 {
+        normalized_sum / values_total * sweep
+    } else {
+        sweep
+    };
+    let remainder = if cx.props.show_remainder
+        && cx.props.compare_series.is_none()
+        && strict_total.is_none()
+        && filled + geometry.pad_angle < sweep
+    {
+        let start_angle = cx.props.start_angle + filled + geometry.pad_angle / 2.0;
+
+        geometry.slices(
+            &[1.0],
+            1.0,
+            start_angle,
+            sweep - filled - geometry.pad_angle,
+            &[],
+        )
+    } else {
+        Vec::new()
+    };
+    let remainder_event = SliceEvent {
+        index: series.len(),
+        part: SlicePart::Remainder,
+    };
+
+    for (ring_index, ring) in rings.into_iter().flatten().enumerate() {
+        let ring_index = ring_index + 1;
+        let (outer, inner) = ring_bounds(ring_index);
+        let ring_geometry = PieGeometry {
+            radius: outer,
+            donut_radius: inner,
+            label_radius: (outer + inner.unwrap_or_default()) / 2.0,
+            pin_last: false,
+            ..geometry
+        };
+        let normalized_ring = normalize_series(ring);
+
+        halves.push((
+            ring_geometry.slices(
+                &normalized_ring,
+                normalized_ring.iter().sum(),
+                cx.props.start_angle,
+                sweep,
+                &[],
+            ),
+            ring,
+            format!("{}-ring-{ring_index}", cx.props.class_series),
+            ring_geometry,
+        ));
+    }
+    // Only the outer ring is labelled unless asked otherwise
+    let labelled_halves = if rings.is_some() && !cx.props.label_all_rings {
+        1
+    } else {
+        halves.len()
+    };
+
+    let unaccounted = strict_total.and_then(|_| {
+        geometry
+            .slices(
+                &[1.0],
+                1.0,
+                cx.props.start_angle + filled,
+                sweep - filled,
+                &[],
+            )
+            .pop()
+            .map(|slice| slice.path)
+    });
+
+    // The compared halves keep their labels outside, on their own side of the divider
+    let labels_outside = cx.props.compare_series.is_some()
+        || cx.props.label_leader_lines && cx.props.label_position == LabelPosition::Outside;
+    if labels_outside {
+        // The labels of the inner rings stay inside their ring
+        let outside_halves = if rings.is_some() { 1 } else { halves.len() };
+        geometry.spread_labels(
+            halves
+                .iter_mut()
+                .take(outside_halves)
+                .flat_map(|(slices, _, _, _)| slices.iter_mut()),
+            cx.props.viewbox_height as f32,
+        );
+    }
+
+    // The colors follow the series values, skipping the zero values that have no slice
+    let index_colors = cx.props.compare_series.is_some() || rings.is_some();
+    let color_rank = |index: usize| {
+        normalized_series[..index]
+            .iter()
+            .filter(|v| **v != 0.0)
+            .count()
+    };
+    let slice_color = |color_index: usize| match &cx.props.colors {
+        Some(colors) if !colors.is_empty() => colors[color_index % colors.len()].clone(),
+        _ => format!("rgb({}, 40, 40)", red_shade(color_index)),
+    };
+    let color_index_of = |index: usize| {
+        if index_colors {
+            index
+        } else {
+            color_rank(index)
+        }
+    };
+    // The class and fill of the labels over the slice, to stand out from its color
+    let label_contrast = |index: usize| {
+        is_light(&slice_color(color_index_of(index)))
+            .filter(|_| cx.props.auto_label_contrast)
+            .map(|light| {
+                if light {
+                    (cx.props.class_label_on_light, "rgb(20, 20, 20)")
+                } else {
+                    (cx.props.class_label_on_dark, "white")
+                }
+            })
+    };
+    let inside_contrast = |index: usize| {
+        label_contrast(index).filter(|_| {
+            cx.props.label_position == LabelPosition::Inside && cx.props.compare_series.is_none()
+        })
+    };
+
+    let number_format = label_number_format(
+        cx.props.number_format.as_ref(),
+        cx.props.label_decimals,
+        cx.props.rounding,
+    );
+
+    let format_value = |value: f32| {
+        format_scaled(
+            value,
+            cx.props.label_interpolation,
+            number_format.as_ref(),
+            1.0,
+            None,
+        )
+    };
+
+    let format_percent = |ratio: f32| {
+        let decimals = cx.props.percent_decimals.into();
+        let percent = round_to(f64::from(ratio * 100.0), decimals, cx.props.rounding);
+        format!("{percent:.decimals$}%")
+    };
+
+    let format_slice = |values: &[f32], index: usize, ratio: f32| match cx.props.label_format {
+        Some(label_format) => label_format(SliceLabel {
+            index,
+            value: values[index],
+            percentage: ratio * 100.0,
+            label: if other_index == Some(index) {
+                Some(cx.props.other_label.to_string())
+            } else {
+                labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(index).cloned())
+            },
+            is_other: other_index == Some(index),
+        }),
+        None => match cx.props.label_content {
+            LabelContent::Value => format_value(values[index]),
+            LabelContent::Percent => format_percent(ratio),
+            LabelContent::ValueAndPercent => {
+                format!(
+                    "{} ({})",
+                    format_value(values[index]),
+                    format_percent(ratio)
+                )
+            }
+        },
+    };
+
+    if let Some(handler) = &cx.props.on_layout {
+        let (slices, values, _, _) = &halves[0];
+        let total: f32 = values[..cx.props.series.len()].iter().sum();
+        let arc_total = if cx.props.compare_series.is_some() {
+            normalized_sum
+        } else {
+            values_total
+        };
+        let layout = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let color_index =
+                    if index_colors || !slices.iter().any(|slice| slice.index == index) {
+                        index
+                    } else {
+                        color_rank(index)
+                    };
+
+                let ratio = if arc_total > 0.0 {
+                    normalized_series[index] / arc_total
+                } else {
+                    0.0
+                };
+                let label = if cx.props.label_format.is_some() {
+                    format_slice(values, index, ratio)
+                } else {
+                    labels
+                        .as_ref()
+                        .and_then(|labels| labels.get(index).cloned())
+                        .unwrap_or_else(|| format_slice(values, index, ratio))
+                };
+
+                SliceInfo {
+                    index,
+                    label,
+                    color: slice_color(color_index),
+                    ratio: if total > 0.0 { value / total } else { 0.0 },
+                }
+            })
+            .collect::<Vec<SliceInfo>>();
+
+        if last_layout.read().as_ref() != Some(&layout) {
+            *last_layout.write_silent() = Some(layout.clone());
+            handler.call(layout);
+        }
+    }
+
+    let on_chart_labels = cx.props.labels_mode == LabelsMode::OnChart;
+    // The labels of the narrow slices are hidden, or drawn with their own class
+    let small = |slice: &Slice| slice.sweep < cx.props.min_label_angle;
+    let labeled = |slice: &Slice| !small(slice) || cx.props.class_label_small.is_some();
+    let label_class = |slice: &Slice| match cx.props.class_label_small {
+        Some(class) if small(slice) => class,
+        _ => cx.props.class_label,
+    };
+    let hole_radius = halves
+        .last()
+        .and_then(|(_, _, _, geometry)| geometry.donut_radius)
+        .filter(|_| cx.props.center_label.is_some() || cx.props.center_content.is_some())
+        .map(|r| r.max(0.0));
+    let hole_clip_id = &format!("dx-donut-hole-{}", cx.scope_id().0);
+
+    let ring_labels =
+        if on_chart_labels && cx.props.donut == PieShape::Donut && cx.props.ring_value_labels {
+            halves
+                .iter()
+                .take(labelled_halves)
+                .flat_map(|(slices, values, _, geometry)| {
+                    let inner_radius = geometry.donut_radius.unwrap_or_default();
+                    let ring_radius = (geometry.radius + inner_radius) / 2.0;
+                    let ring_width = geometry.radius - inner_radius;
+
+                    slices.iter().filter_map(move |slice| {
+                        let label = format_slice(values, slice.index, slice.ratio);
+                        let arc_len = slice.sweep.to_radians() * ring_radius;
+                        let font_size =
+                            fit_font_size(&label, arc_len, ring_width, DEFAULT_FONT_SIZE)?;
+
+                        Some((
+                            polar_to_cartesian(center, ring_radius, slice.mid_angle) + slice.offset,
+                            font_size,
+                            label,
+                            label_contrast(slice.index),
+                        ))
+                    })
+                })
+                .collect::<Vec<(Point, f32, String, Option<(&str, &str)>)>>()
+        } else {
+            Vec::new()
+        };
+
+    // The value at the end of the whole arc
+    let full_circle = if normalized_sum > 0.0 {
+        values_total / normalized_sum * angle_values.iter().sum::<f32>()
+    } else {
+        0.0
+    };
+
+    let arc_ticks = if cx.props.show_arc_ticks && cx.props.compare_series.is_none() {
+        let arc_end = full_circle * cx.props.show_ratio.map_or(1.0, |r| r.clamp(0.0001, 1.0));
+
+        let values = cx.props.arc_tick_values.clone().unwrap_or_else(|| {
+            let count = cx.props.arc_tick_count.max(2);
+            (0..count)
+                .map(|i| arc_end * i as f32 / (count - 1) as f32)
+                .collect()
+        });
+
+        values
+            .into_iter()
+            .filter(|_| full_circle > 0.0)
+            .map(|value| {
+                let angle = directed(cx.props.start_angle + value / full_circle * sweep);
+                let outer = radius + cx.props.arc_tick_length;
+                let label = polar_to_cartesian(center, outer + ARC_TICK_LABEL_GAP, angle);
+                let (dx, dy) = (label.x - center.x, label.y - center.y);
+                let anchor = if dx > outer * 0.1 {
+                    "start"
+                } else if dx < -outer * 0.1 {
+                    "end"
+                } else {
+                    "middle"
+                };
+                let baseline = if dy > outer * 0.1 {
+                    "hanging"
+                } else if dy < -outer * 0.1 {
+                    "text-bottom"
+                } else {
+                    "middle"
+                };
+
+                (
+                    polar_to_cartesian(center, radius, angle),
+                    polar_to_cartesian(center, outer, angle),
+                    TextData {
+                        x: label.x,
+                        y: label.y,
+                        anchor,
+                        baseline,
+                    },
+                    format_value(value),
+                )
+            })
+            .collect::<Vec<(Point, Point, TextData, String)>>()
+    } else {
+        Vec::new()
+    };
+
+    let needle = (cx.props.needle && cx.props.compare_series.is_none()).then(|| {
+        let ratio = match (cx.props.needle_value, cx.props.show_ratio) {
+            (Some(value), _) if full_circle > 0.0 => value / full_circle,
+            (Some(_), _) => 0.0,
+            (None, Some(ratio)) => ratio,
+            (None, None) => normalized_sum / values_total,
+        };
+        let angle = directed(cx.props.start_angle + ratio.clamp(0.0, 1.0) * sweep);
+        let tip = polar_to_cartesian(center, radius * NEEDLE_LENGTH, angle);
+        let left = polar_to_cartesian(center, NEEDLE_WIDTH / 2.0, angle - 90.0);
+        let right = polar_to_cartesian(center, NEEDLE_WIDTH / 2.0, angle + 90.0);
+
+        format!("{left} {tip} {right}")
+    });
 
     cx.render(rsx! {
         div {
@@ -168,119 +1177,839 @@ pub fn PieChart<'a>(cx: Scope<'a, PieChartProps<'a>>) -> Element {
                 class: "{cx.props.class_chart}",
                 preserveAspectRatio: "xMidYMid meet",
                 xmlns: "http://www.w3.org/2000/svg",
-                normalized_series.iter().map(|v| {
-                    if *v != 0.0 {
-                        let mut end_angle = if values_total > 0.0 {
-                            m_start_angle + (v / values_total) * 360.0
-                        } else {
-                            0.0
-                        };
-                        let overlap_start_angle = if class_index != 0 {
-                            (m_start_angle - 0.4).max(0.0)
-                        } else {
-                            m_start_angle
-                        };
-                        if end_angle - overlap_start_angle >= 359.99 {
-                            end_angle = overlap_start_angle + 359.99
+                remainder.iter().map(|slice| {
+                    let event = remainder_event;
+                    // The handlers are only called with the opt-in
+                    let handler = |handler: &'a Option<EventHandler<'a, SliceEvent>>| {
+                        handler.as_ref().filter(|_| cx.props.remainder_in_events)
+                    };
+                    let middle = polar_to_cartesian(
+                        geometry.center,
+                        (geometry.radius + geometry.donut_radius.unwrap_or_default()) / 2.0,
+                        slice.mid_angle,
+                    ) + slice.offset;
+                    let prevent_context_menu = if cx.props.remainder_in_events {
+                        prevent_context_menu
+                    } else {
+                        ""
+                    };
+
+                    rsx! {cx,
+                        path {
+                            key: "remainder",
+                            d: "{slice.path}",
+                            class: "{cx.props.class_remainder}",
+                            fill: "rgb(40, 40, 40)",
+                            fill_opacity: "0.1",
+                            onclick: move |_| {
+                                if let Some(handler) = handler(&cx.props.onclick_slice) {
+                                    handler.call(event);
+                                }
+                            },
+                            onmouseover: move |_| {
+                                if let Some(handler) = handler(&cx.props.onmouseover_slice) {
+                                    handler.call(event);
+                                }
+                            },
+                            onmouseout: move |_| {
+                                if let Some(handler) = handler(&cx.props.onmouseout_slice) {
+                                    handler.call(event);
+                                }
+                            },
+                            prevent_default: "{prevent_context_menu}",
+                            oncontextmenu: move |e| {
+                                let handler = cx.props.on_context_menu.as_ref();
+                                if let Some(handler) = handler.filter(|_| cx.props.remainder_in_events) {
+                                    handler.call(ContextMenu::new(event, &e.data, middle));
+                                }
+                            },
                         }
+                    }
+                }),
+                halves.iter().enumerate().map(|(half_index, (slices, _, class_half, half_geometry))| {
+                    // The halves are the series, then the compare series or the rings
+                    let part = match half_index {
+                        0 => SlicePart::Primary,
+                        1 if cx.props.compare_series.is_some() => SlicePart::Compare,
+                        n => SlicePart::Ring(n - 1),
+                    };
 
-                        let start_position = polar_to_cartesian(center, radius, overlap_start_angle);
-                        let end_position = polar_to_cartesian(center, radius, end_angle);
-                        let large_arc = i32::from(end_angle - m_start_angle > 180.0);
-
-                        let dpath = if cx.props.donut {
-                            let donut_radius = radius - cx.props.donut_width;
-                            let start_inside_position = polar_to_cartesian(center, donut_radius, overlap_start_angle);
-                            let end_inside_position = polar_to_cartesian(center, donut_radius, end_angle);
-                            let large_arc_inside = large_arc;
-
-                            format!("M{end_position}\
-                                     A{radius},{radius},0,{large_arc},0,{start_position}\
-                                     L{start_inside_position}\
-                                     A{donut_radius},{donut_radius},0,{large_arc_inside},1,{end_inside_position}Z")
-                        } else {
-                            format!("M{end_position}\
-                                     A{radius},{radius},0,{large_arc},0,{start_position}\
-                                     L{center}Z")
-                        };
-
-                        let element = rsx! {cx,
-                            g {
-                                key: "{class_index}",
-                                class: "{cx.props.class_series} {cx.props.class_series}-{class_index}",
-                                path {
-                                    d: "{dpath}",
-                                    class: "{cx.props.class_slice}",
-                                    fill: "rgb({color_var}, 40, 40)",
-                                },
+                    rsx! {cx,
+                        slices.iter().enumerate().map(move |(class_index, slice)| {
+                            let color = slice_color(color_index_of(slice.index));
+                            let event = SliceEvent {
+                                index: slice.index,
+                                part,
+                            };
+                            let middle = polar_to_cartesian(
+                                half_geometry.center,
+                                (half_geometry.radius + half_geometry.donut_radius.unwrap_or_default()) / 2.0,
+                                slice.mid_angle,
+                            ) + slice.offset;
+                            let class_hover = if *hovered.get() == Some(event) {
+                                cx.props.class_slice_hover
+                            } else {
+                                ""
+                            };
+
+                            rsx! {cx,
+                                g {
+                                    key: "slice-{slice.index}",
+                                    class: "{cx.props.class_series} {cx.props.class_series}-{class_index} {class_half}",
+                                    transform: "translate({slice.offset.x}, {slice.offset.y})",
+                                    path {
+                                        d: "{slice.path}",
+                                        class: "{cx.props.class_slice} {class_hover}",
+                                        fill: "{color}",
+                                        onclick: move |_| {
+                                            if let Some(handler) = &cx.props.onclick_slice {
+                                                handler.call(event);
+                                            }
+                                        },
+                                        onmouseover: move |_| {
+                                            hovered.set(Some(event));
+                                            if let Some(handler) = &cx.props.onmouseover_slice {
+                                                handler.call(event);
+                                            }
+                                        },
+                                        onmouseout: move |_| {
+                                            hovered.set(None);
+                                            if let Some(handler) = &cx.props.onmouseout_slice {
+                                                handler.call(event);
+                                            }
+                                        },
+                                        prevent_default: "{prevent_context_menu}",
+                                        oncontextmenu: move |e| {
+                                            if let Some(handler) = &cx.props.on_context_menu {
+                                                handler.call(ContextMenu::new(event, &e.data, middle));
+                                            }
+                                        },
+                                    },
+                                }
                             }
-                        };
+                        })
+                    }
+                }),
+                unaccounted.as_ref().map(|d| rsx! {cx,
+                    path {
+                        d: "{d}",
+                        class: "{cx.props.class_slice} {cx.props.class_unaccounted}",
+                        fill: "{UNACCOUNTED_FILL}",
+                    }
+                }),
+                needle.as_ref().map(|points| rsx! {cx,
+                    g {
+                        class: "{cx.props.class_needle}",
+                        polygon {
+                            points: "{points}",
+                            fill: "rgb(40, 40, 40)",
+                        }
+                        circle {
+                            cx: "{center.x}",
+                            cy: "{center.y}",
+                            r: "{NEEDLE_CAP_RADIUS}",
+                            fill: "rgb(40, 40, 40)",
+                        }
+                    }
+                }),
+                cx.props.compare_series.is_some().then(|| rsx! {cx,
+                    line {
+                        x1: "{center.x}",
+                        y1: format_args!("{}", center.y - radius),
+                        x2: "{center.x}",
+                        y2: format_args!("{}", center.y + radius),
+                        class: "{cx.props.class_divider}",
+                        stroke: "white",
+                    }
+                }),
+                halves.iter().take(labelled_halves).map(|(slices, values, _, _)| {
+                    if !on_chart_labels {
+                        None
+                    } else if let (Some(labels), None) = (labels, cx.props.label_format) {
+                        rsx! {cx,
+                            g {
+                                slices.iter().filter(|slice| labeled(slice)).filter_map(|slice| labels.get(slice.index).map(|label| (slice, label))).map(|(slice, label)| {
+                                    let label = if cx.props.label_content == LabelContent::ValueAndPercent {
+                                        format!("{label} {}", format_percent(slice.ratio))
+                                    } else {
+                                        label.clone()
+                                    };
+                                    let text = rsx! {cx,
+                                        MultilineText {
+                                            key: "label-{slice.index}",
+                                            x: slice.label_position.x,
+                                            y: slice.label_position.y,
+                                            text: label,
+                                            text_anchor: slice.label_anchor,
+                                            class: label_class(slice),
+                                        }
+                                    };
+                                    let text = match inside_contrast(slice.index) {
+                                        Some((class, fill)) => rsx! {cx,
+                                            g {
+                                                key: "label-{slice.index}",
+                                                class: "{class}",
+                                                fill: "{fill}",
+                                                text
+                                            }
+                                        },
+                                        None => text,
+                                    };
 
-                        label_positions.push(polar_to_cartesian(center, label_radius, m_start_angle + (end_angle - m_start_angle) / 2.0));
+                                    rsx! {cx,
+                                        slice.leader.as_ref().map(|points| rsx! {cx,
+                                            polyline {
+                                                key: "label-line-{slice.index}",
+                                                points: "{points}",
+                                                class: "{cx.props.class_label_line}",
+                                                stroke: "rgba(20, 20, 20, 0.6)",
+                                                fill: "none",
+                                            }
+                                        }),
+                                        text
+                                    }
+                                })
+                            }
+                        }
+                    } else if cx.props.show_labels || labels.is_some() {
+                        rsx! {cx,
+                            g {
+                                slices.iter().filter(|slice| labeled(slice)).map(|slice| {
+                                    let label = format_slice(values, slice.index, slice.ratio);
+                                    let text = rsx! {cx,
+                                        MultilineText {
+                                            key: "label-{slice.index}",
+                                            x: slice.label_position.x,
+                                            y: slice.label_position.y,
+                                            text: label,
+                                            text_anchor: slice.label_anchor,
+                                            class: label_class(slice),
+                                        }
+                                    };
+                                    let text = match inside_contrast(slice.index) {
+                                        Some((class, fill)) => rsx! {cx,
+                                            g {
+                                                key: "label-{slice.index}",
+                                                class: "{class}",
+                                                fill: "{fill}",
+                                                text
+                                            }
+                                        },
+                                        None => text,
+                                    };
 
-                        color_var -= 75.0 * (1.0 / (class_index + 1) as f32);
-                        class_index += 1;
-                        m_start_angle = end_angle;
-                        element
+                                    rsx! {cx,
+                                        slice.leader.as_ref().map(|points| rsx! {cx,
+                                            polyline {
+                                                key: "label-line-{slice.index}",
+                                                points: "{points}",
+                                                class: "{cx.props.class_label_line}",
+                                                stroke: "rgba(20, 20, 20, 0.6)",
+                                                fill: "none",
+                                            }
+                                        }),
+                                        text
+                                    }
+                                })
+                            }
+                        }
                     } else {
-                        label_positions.push(Point::new(-1.0, -1.0));
                         None
                     }
                 }),
-                if let Some(ref labels) = cx.props.labels {
-                    rsx! {cx,
-                        g {
-                            label_positions.iter().zip(labels.iter()).map(|(position, label)| {
-                                if position.x > 0.0 {
-                                    rsx! {cx,
-                                        text {
-                                            key: "{label}",
-                                            dx: "{position.x}",
-                                            dy: "{position.y}",
-                                            text_anchor: "middle",
-                                            class: "{cx.props.class_label}",
-                                            alignment_baseline: "middle",
-                                            [label.as_str()]
-                                        }
-                                    }
-                                } else {
-                                    None
-                                }
-                            })
+                (!arc_ticks.is_empty()).then(|| rsx! {cx,
+                    g {
+                        arc_ticks.iter().enumerate().map(|(i, (start, end, text, label))| rsx! {cx,
+                            line {
+                                key: "arc-tick-{i}",
+                                x1: "{start.x}",
+                                y1: "{start.y}",
+                                x2: "{end.x}",
+                                y2: "{end.y}",
+                                class: "{cx.props.class_arc_tick}",
+                                stroke: "rgba(20, 20, 20, 0.8)",
+                            }
+                            text {
+                                key: "arc-tick-label-{i}",
+                                dx: "{text.x}",
+                                dy: "{text.y}",
+                                text_anchor: "{text.anchor}",
+                                class: "{cx.props.class_arc_tick_label}",
+                                alignment_baseline: "{text.baseline}",
+                                "{label}"
+                            }
+                        })
+                    }
+                }),
+                hole_radius.map(|hole_radius| rsx! {cx,
+                    defs {
+                        clipPath {
+                            id: "{hole_clip_id}",
+                            circle {
+                                cx: "0",
+                                cy: "0",
+                                r: "{hole_radius}",
+                            }
                         }
                     }
-                } else if cx.props.show_labels {
-                    rsx! {cx,
-                        g {
-                            label_positions.iter().zip(cx.props.series.iter()).map(|(position, value)| {
-                                let label = if let Some(func) = cx.props.label_interpolation {
-                                    func(*value)
-                                } else {
-                                    value.to_string()
-                                };
-
-                                if position.x > 0.0 {
-                                    rsx! {cx,
-                                        text {
-                                            key: "label",
-                                            dx: "{position.x}",
-                                            dy: "{position.y}",
-                                            text_anchor: "middle",
-                                            class: "{cx.props.class_label}",
-                                            alignment_baseline: "middle",
-                                            "{label}"
-                                        }
-                                    }
-                                } else {
-                                    None
+                    g {
+                        transform: "translate({center.x}, {center.y})",
+                        clip_path: "url(#{hole_clip_id})",
+                        pointer_events: "none",
+                        cx.props.center_label.as_ref().map(|label| rsx! {cx,
+                            MultilineText {
+                                x: 0.0,
+                                y: 0.0,
+                                text: label,
+                                class: cx.props.class_label,
+                            }
+                        }),
+                        &cx.props.center_content,
+                    }
+                }),
+                (!ring_labels.is_empty()).then(|| rsx! {cx,
+                    g {
+                        ring_labels.iter().enumerate().map(|(i, (position, font_size, label, contrast))| {
+                            let text = rsx! {cx,
+                                text {
+                                    key: "ring-label-{i}",
+                                    dx: "{position.x}",
+                                    dy: "{position.y}",
+                                    text_anchor: "middle",
+                                    class: "{cx.props.class_ring_label}",
+                                    alignment_baseline: "middle",
+                                    font_size: "{font_size}",
+                                    "{label}"
                                 }
-                            })
-                        }
+                            };
+
+                            match contrast {
+                                Some((class, fill)) => rsx! {cx,
+                                    g {
+                                        key: "ring-label-{i}",
+                                        class: "{class}",
+                                        fill: "{fill}",
+                                        text
+                                    }
+                                },
+                                None => text,
+                            }
+                        })
                     }
-                } else {
-                    None
+                })
+            }
+        }
+    })
+}
+
+/// The bounds of the arc from `start_angle` spanning `sweep` degrees of a unit circle centered at
+/// the origin, including the center.
+fn arc_bounds(start_angle: f32, sweep: f32) -> Rect {
+    let origin = Point::new(0.0, 0.0);
+    let first_quarter = (start_angle / 90.0).ceil() as i32;
+    let last_quarter = ((start_angle + sweep) / 90.0).floor() as i32;
+
+    (first_quarter..=last_quarter)
+        .map(|quarter| quarter as f32 * 90.0)
+        .chain([start_angle, start_angle + sweep])
+        .map(|angle| polar_to_cartesian(origin, 1.0, angle))
+        .fold(Rect::new(0.0, 0.0, 0.0, 0.0), |bounds, point| {
+            Rect::new(
+                bounds.min.x.min(point.x),
+                bounds.min.y.min(point.y),
+                bounds.max.x.max(point.x),
+                bounds.max.y.max(point.y),
+            )
+        })
+}
+
+/// The computed geometry of a single pie slice.
+struct Slice {
+    /// The index of the slice value in the series.
+    index: usize,
+    path: String,
+    label_position: Point,
+    /// The angle at the middle of the slice, in degrees.
+    mid_angle: f32,
+    /// The angular width of the slice, in degrees.
+    sweep: f32,
+    /// The translation of the exploded slice away from the center.
+    offset: Point,
+    /// The slice value as a ratio of the values total used for the angles.
+    ratio: f32,
+    label_anchor: &'static str,
+    /// The points of the polyline from the slice to its label, if any.
+    leader: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+struct PieGeometry {
+    center: Point,
+    radius: f32,
+    donut_radius: Option<f32>,
+    label_radius: f32,
+    /// The gap between neighbouring slices, in degrees.
+    pad_angle: f32,
+    sort: Option<SortOrder>,
+    /// Whether the last value stays last when sorting, for the Other slice.
+    pin_last: bool,
+    /// The angle the clockwise slices are mirrored across for counter-clockwise pies.
+    mirror_axis: Option<f32>,
+    /// The radius of the rounded corners of the donut slices.
+    corner_radius: f32,
+}
+
+impl PieGeometry {
+    /// Lays out the slices for the values along the arc from `start_angle` spanning `sweep`
+    /// degrees, where `values_total` fills the whole arc. Zero values are skipped, and the
+    /// slices with an `explode` offset are moved away from the center.
+    fn slices(
+        &self,
+        values: &[f32],
+        values_total: f32,
+        start_angle: f32,
+        sweep: f32,
+        explode: &[f32],
+    ) -> Vec<Slice> {
+        let PieGeometry {
+            center,
+            radius,
+            donut_radius,
+            label_radius,
+            pad_angle,
+            sort,
+            pin_last,
+            mirror_axis,
+            corner_radius,
+        } = *self;
+        let directed = |angle: f32| mirror_axis.map_or(angle, |axis| 2.0 * axis - angle);
+        let counter_clockwise = mirror_axis.is_some();
+
+        let padded = pad_angle > 0.0 && values.iter().filter(|v| **v != 0.0).count() > 1;
+        let mut slices = Vec::<Slice>::new();
+        let mut m_start_angle = start_angle;
+
+        let mut order = (0..values.len()).collect::<Vec<usize>>();
+        let sorted = &mut order[..values.len() - usize::from(pin_last && !values.is_empty())];
+        match sort {
+            Some(SortOrder::Ascending) => sorted.sort_by(|a, b| values[*a].total_cmp(&values[*b])),
+            Some(SortOrder::Descending) => sorted.sort_by(|a, b| values[*b].total_cmp(&values[*a])),
+            None => {}
+        }
+
+        for (index, v) in order.into_iter().map(|index| (index, &values[index])) {
+            if *v == 0.0 {
+                continue;
+            }
+
+            let mut end_angle = if values_total > 0.0 {
+                m_start_angle + (v / values_total) * sweep
+            } else {
+                0.0
+            };
+            let exploded = |index: usize| explode.get(index).is_some_and(|offset| *offset != 0.0);
+            // Padded, rounded and exploded slices don't touch their neighbours, so there is no seam
+            // to cover
+            let rounded = corner_radius > 0.0 && donut_radius.is_some();
+            let overlap_start_angle = if !padded
+                && !rounded
+                && slices
+                    .last()
+                    .is_some_and(|last| !exploded(last.index) && !exploded(index))
+            {
+                (m_start_angle - 0.4).max(start_angle)
+            } else {
+                m_start_angle
+            };
+            if end_angle - overlap_start_angle >= sweep.min(359.99) {
+                end_angle = overlap_start_angle + sweep.min(359.99)
+            }
+
+            let (arc_start_angle, arc_end_angle) = if padded {
+                let half_pad = (pad_angle / 2.0).min((end_angle - m_start_angle) / 2.0);
+                (m_start_angle + half_pad, end_angle - half_pad)
+            } else {
+                (overlap_start_angle, end_angle)
+            };
+
+            let start_position = polar_to_cartesian(center, radius, directed(arc_start_angle));
+            let end_position = polar_to_cartesian(center, radius, directed(arc_end_angle));
+            let large_arc = arc_end_angle - arc_start_angle.max(m_start_angle) > 180.0;
+
+            let path = match donut_radius {
+                Some(donut_radius) if rounded => {
+                    let (start_angle, end_angle) = if counter_clockwise {
+                        (directed(arc_end_angle), directed(arc_start_angle))
+                    } else {
+                        (arc_start_angle, arc_end_angle)
+                    };
+
+                    RingSegment {
+                        center_x: center.x,
+                        center_y: center.y,
+                        inner_radius: donut_radius.max(0.0),
+                        outer_radius: radius,
+                        start_angle,
+                        end_angle,
+                        corner_radius,
+                    }
+                    .path()
+                }
+                _ => {
+                    // Mirrored arcs go the other way around
+                    let mut path = PathBuilder::with_capacity(5).compact();
+                    path.move_to(end_position).arc_to(
+                        radius,
+                        large_arc,
+                        counter_clockwise,
+                        start_position,
+                    );
+
+                    if let Some(donut_radius) = donut_radius {
+                        let start_inside_position =
+                            polar_to_cartesian(center, donut_radius, directed(arc_start_angle));
+                        let end_inside_position =
+                            polar_to_cartesian(center, donut_radius, directed(arc_end_angle));
+
+                        path.line_to(start_inside_position).arc_to(
+                            donut_radius,
+                            large_arc,
+                            !counter_clockwise,
+                            end_inside_position,
+                        );
+                    } else {
+                        path.line_to(center);
+                    }
+                    path.close();
+                    path.build()
                 }
+            };
+
+            let mid_angle = directed(m_start_angle + (end_angle - m_start_angle) / 2.0);
+            let offset = polar_to_cartesian(
+                Point::new(0.0, 0.0),
+                explode.get(index).copied().unwrap_or_default(),
+                mid_angle,
+            );
+
+            slices.push(Slice {
+                index,
+                path,
+                label_position: polar_to_cartesian(center, label_radius, mid_angle) + offset,
+                mid_angle,
+                sweep: arc_end_angle - arc_start_angle.max(m_start_angle),
+                offset,
+                ratio: if values_total > 0.0 {
+                    v / values_total
+                } else {
+                    0.0
+                },
+                label_anchor: "middle",
+                leader: None,
+            });
+
+            m_start_angle = end_angle;
+        }
+
+        slices
+    }
+
+    /// Moves the labels of the slices outside of the pie, spread vertically on each side so that
+    /// they are at least a line apart and inside a view box of the given height, and adds the
+    /// leader lines from the slices to them.
+    fn spread_labels<'s>(&self, slices: impl Iterator<Item = &'s mut Slice>, height: f32) {
+        let line_height = DEFAULT_FONT_SIZE;
+        let elbow_radius = self.label_radius.max(self.radius + LEADER_ELBOW);
+        let (mut left, mut right): (Vec<_>, Vec<_>) = slices
+            .map(|slice| {
+                let elbow =
+                    polar_to_cartesian(self.center, elbow_radius, slice.mid_angle) + slice.offset;
+                (elbow, slice)
+            })
+            .partition(|(elbow, _)| elbow.x < self.center.x);
+
+        for (side, direction) in [(&mut left, -1.0), (&mut right, 1.0)] {
+            side.sort_by(|a, b| a.0.y.total_cmp(&b.0.y));
+
+            let mut ys = side.iter().map(|(elbow, _)| elbow.y).collect::<Vec<f32>>();
+            for i in 0..ys.len() {
+                let min = if i == 0 {
+                    line_height / 2.0
+                } else {
+                    ys[i - 1] + line_height
+                };
+                ys[i] = ys[i].max(min);
+            }
+            for i in (0..ys.len()).rev() {
+                let max = if i + 1 == ys.len() {
+                    height - line_height / 2.0
+                } else {
+                    ys[i + 1] - line_height
+                };
+                ys[i] = ys[i].min(max);
+            }
+
+            for ((elbow, slice), y) in side.iter_mut().zip(ys) {
+                let start =
+                    polar_to_cartesian(self.center, self.radius, slice.mid_angle) + slice.offset;
+                let end = Point::new(elbow.x + direction * LEADER_RUN, y);
+
+                slice.leader = Some(format!("{start} {elbow} {end}"));
+                slice.label_position = Point::new(end.x + direction * LEADER_LABEL_GAP, y);
+                slice.label_anchor = if direction < 0.0 { "end" } else { "start" };
             }
         }
+    }
+}
+
+/// The `PieLegendList` properties struct for the configuration of the pie legend list.
+#[derive(Props)]
+pub struct PieLegendListProps<'a> {
+    items: Vec<SliceInfo>,
+
+    #[props(default = true)]
+    show_ratio: bool,
+
+    #[props(default = "dx-legend-list")]
+    class_list: &'a str,
+    #[props(default = "dx-legend-item")]
+    class_item: &'a str,
+    #[props(default = "dx-legend-swatch")]
+    class_swatch: &'a str,
+}
+
+/// A plain HTML list of the pie slices with their colors, for showing the labels next to a
+/// [PieChart] with [`LabelsMode::External`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::charts::pie::{LabelsMode, PieLegendList, SliceInfo};
+/// use dioxus_charts::PieChart;
+///
+/// fn app(cx: Scope) -> Element {
+///     let items = use_state(&cx, Vec::<SliceInfo>::new);
+///
+///     cx.render(rsx! {
+///         PieChart {
+///             width: "80px",
+///             height: "80px",
+///             labels_mode: LabelsMode::External,
+///             on_layout: move |layout| items.set(layout),
+///             series: vec![59.54, 17.2, 9.59],
+///             labels: vec!["Asia".into(), "Africa".into(), "Europe".into()],
+///         }
+///         PieLegendList { items: items.get().clone() }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `items`: [Vec]<[`SliceInfo`]> (**required**): The slices as sent by the `on_layout` event of
+///   the pie chart.
+/// - `show_ratio`: [bool] (default: `true`): Show the percentage of every slice after its label.
+/// ---
+/// - `class_list`: &[str] (default: `"dx-legend-list"`): The HTML element `class` of the list.
+/// - `class_item`: &[str] (default: `"dx-legend-item"`): The HTML element `class` of every item.
+/// - `class_swatch`: &[str] (default: `"dx-legend-swatch"`): The HTML element `class` of the
+///   color swatches.
+#[allow(non_snake_case)]
+pub fn PieLegendList<'a>(cx: Scope<'a, PieLegendListProps<'a>>) -> Element<'a> {
+    cx.render(rsx! {
+        ul {
+            class: "{cx.props.class_list}",
+            list_style: "none",
+            cx.props.items.iter().map(|item| {
+                let ratio = if cx.props.show_ratio {
+                    format!(" {:.1}%", item.ratio * 100.0)
+                } else {
+                    String::new()
+                };
+
+                rsx! {
+                    li {
+                        key: "item-{item.index}",
+                        class: "{cx.props.class_item}",
+                        span {
+                            class: "{cx.props.class_swatch}",
+                            display: "inline-block",
+                            width: "0.8em",
+                            height: "0.8em",
+                            margin_right: "0.4em",
+                            background_color: "{item.color}",
+                        }
+                        "{item.label}{ratio}"
+                    }
+                }
+            })
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::{duplicate_sibling_keys, rendered_attributes, rendered_keys};
+
+    fn geometry(donut_radius: Option<f32>, mirror_axis: Option<f32>) -> PieGeometry {
+        PieGeometry {
+            center: Point::new(200.0, 200.0),
+            radius: 100.0,
+            donut_radius,
+            label_radius: 120.0,
+            pad_angle: 0.0,
+            sort: None,
+            pin_last: false,
+            mirror_axis,
+            corner_radius: 0.0,
+        }
+    }
+
+    /// The commands of a path with their arguments, i.e., `('A', [100, 100, 0, 0, 1, 200, 100])`.
+    fn commands(path: &str) -> Vec<(char, Vec<f32>)> {
+        let starts = path
+            .match_indices(|c: char| c.is_ascii_alphabetic())
+            .map(|(i, _)| i)
+            .chain([path.len()])
+            .collect::<Vec<usize>>();
+
+        starts
+            .windows(2)
+            .map(|bounds| {
+                let (command, args) = path[bounds[0]..bounds[1]].split_at(1);
+                let args = args
+                    .split(',')
+                    .filter(|arg| !arg.trim().is_empty())
+                    .map(|arg| arg.trim().parse().unwrap())
+                    .collect();
+                (command.chars().next().unwrap(), args)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn invalid_values_leave_no_nan_in_the_paths() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                PieChart {
+                    series: vec![4.0, f32::NAN, -2.0, 0.0, f32::INFINITY, 1.5],
+                    donut: true,
+                    show_labels: false,
+                }
+            })
+        }
+
+        let paths = rendered_attributes(app, "d");
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|d| !d.contains("NaN")), "{paths:?}");
+    }
+
+    #[test]
+    fn duplicate_labels_get_distinct_keys() {
+        fn duplicates(cx: Scope) -> Element {
+            cx.render(rsx! {
+                PieChart {
+                    series: vec![3.0, 2.0, 1.0],
+                    labels: vec!["Other".into(), "Other".into(), "A".into()],
+                }
+            })
+        }
+
+        assert!(rendered_keys(duplicates).len() > 3);
+        assert_eq!(duplicate_sibling_keys(duplicates), Vec::<String>::new());
+    }
+
+    #[test]
+    fn empty_series_render_the_empty_state() {
+        fn no_series(cx: Scope) -> Element {
+            cx.render(rsx! { PieChart { series: vec![] } })
+        }
+        fn all_zero(cx: Scope) -> Element {
+            cx.render(rsx! { PieChart { series: vec![0.0, 0.0] } })
+        }
+        fn empty_compare_series(cx: Scope) -> Element {
+            cx.render(rsx! { PieChart { series: vec![1.0, 2.0], compare_series: vec![] } })
+        }
+
+        assert!(rendered_attributes(no_series, "class").contains(&"dx-empty".to_string()));
+        assert!(rendered_attributes(all_zero, "class").contains(&"dx-empty".to_string()));
+        // An empty half is an error, instead of a panic
+        assert!(rendered_attributes(empty_compare_series, "d").is_empty());
+    }
+
+    #[test]
+    fn counter_clockwise_paths_mirror_the_clockwise_ones() {
+        for donut_radius in [None, Some(40.0)] {
+            let clockwise = geometry(donut_radius, None).slices(&[1.0, 3.0], 4.0, 0.0, 360.0, &[]);
+            let counter_clockwise =
+                geometry(donut_radius, Some(0.0)).slices(&[1.0, 3.0], 4.0, 0.0, 360.0, &[]);
+
+            assert_eq!(clockwise.len(), 2);
+            for (a, b) in clockwise.iter().zip(&counter_clockwise) {
+                // The slices keep their drawing order
+                assert_eq!(a.index, b.index);
+                let (a, b) = (commands(&a.path), commands(&b.path));
+                assert_eq!(a.len(), b.len());
+
+                for ((command, a), (mirrored, b)) in a.iter().zip(&b) {
+                    assert_eq!(command, mirrored);
+                    if *command == 'Z' {
+                        continue;
+                    }
+                    // Mirrored across the vertical line through the center, with the arcs going
+                    // the other way around
+                    let (a_point, b_point) = (&a[a.len() - 2..], &b[b.len() - 2..]);
+                    assert!(
+                        (a_point[0] - (400.0 - b_point[0])).abs() < 1e-3,
+                        "{a:?} {b:?}"
+                    );
+                    assert!((a_point[1] - b_point[1]).abs() < 1e-3, "{a:?} {b:?}");
+                    if *command == 'A' {
+                        assert_eq!(a[..4], b[..4]);
+                        assert_eq!(a[4], 1.0 - b[4]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn outside_labels_left_of_the_viewbox_are_drawn() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                PieChart {
+                    series: vec![1.0, 0.0, 1.0],
+                    labels: vec!["Right".into(), "Zero".into(), "Left".into()],
+                    label_position: LabelPosition::Outside,
+                    label_offset: 300.0,
+                }
+            })
+        }
+
+        let xs = rendered_attributes(app, "x")
+            .iter()
+            .map(|x| x.parse::<f32>().unwrap())
+            .collect::<Vec<f32>>();
+
+        // The zero value has no slice and no label, the left one is drawn past x=0
+        assert_eq!(xs.len(), 2, "{xs:?}");
+        assert!(xs[0] > 0.0 && xs[1] < 0.0, "{xs:?}");
+    }
+
+    #[test]
+    fn labels_past_the_series_are_not_drawn() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                PieChart {
+                    series: vec![1.0, 2.0],
+                    labels: vec!["A".into(), "B".into(), "C".into()],
+                }
+            })
+        }
+
+        assert_eq!(rendered_attributes(app, "x").len(), 2);
+    }
+}
@@ -0,0 +1,123 @@
+//! Renders the chart components in the tests, without a renderer, to check their markup.
+
+use dioxus::core::{DomEdit, VNode, VirtualDom};
+use dioxus::prelude::*;
+
+/// The values of the `field` attribute of every element rendered by `app`, in document order.
+pub(crate) fn rendered_attributes(app: Component, field: &str) -> Vec<String> {
+    let mut dom = VirtualDom::new(app);
+
+    dom.rebuild()
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            DomEdit::SetAttribute {
+                field: name, value, ..
+            } if name == field => Some(value.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The tag names of the elements rendered by `app`, in document order.
+pub(crate) fn rendered_tags(app: Component) -> Vec<String> {
+    let mut dom = VirtualDom::new(app);
+
+    dom.rebuild()
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            DomEdit::CreateElement { tag, .. } | DomEdit::CreateElementNs { tag, .. } => {
+                Some(tag.to_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The text nodes rendered by `app`, in document order.
+pub(crate) fn rendered_texts(app: Component) -> Vec<String> {
+    let mut dom = VirtualDom::new(app);
+
+    dom.rebuild()
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            DomEdit::CreateTextNode { text, .. } => Some(text.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The keys of every keyed element and component rendered by `app`, in document order, i.e., to
+/// check which elements the diff would keep between two renders.
+pub(crate) fn rendered_keys(app: Component) -> Vec<String> {
+    fn collect(dom: &VirtualDom, node: &VNode, keys: &mut Vec<String>) {
+        match node {
+            VNode::Element(element) => {
+                keys.extend(element.key.map(str::to_owned));
+                for child in element.children {
+                    collect(dom, child, keys);
+                }
+            }
+            VNode::Fragment(fragment) => {
+                for child in fragment.children {
+                    collect(dom, child, keys);
+                }
+            }
+            VNode::Component(component) => {
+                keys.extend(component.key.map(str::to_owned));
+                if let Some(scope) = component.scope.get().and_then(|id| dom.get_scope(id)) {
+                    collect(dom, scope.root_node(), keys);
+                }
+            }
+            VNode::Text(_) | VNode::Placeholder(_) => {}
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let _ = dom.rebuild();
+    let mut keys = Vec::new();
+    collect(&dom, dom.base_scope().root_node(), &mut keys);
+
+    keys
+}
+
+/// The keys rendered by `app` that are shared by siblings, which the diff would mix up.
+pub(crate) fn duplicate_sibling_keys(app: Component) -> Vec<String> {
+    fn collect(dom: &VirtualDom, node: &VNode, duplicates: &mut Vec<String>) {
+        let children = match node {
+            VNode::Element(element) => element.children,
+            VNode::Fragment(fragment) => fragment.children,
+            VNode::Component(component) => {
+                if let Some(scope) = component.scope.get().and_then(|id| dom.get_scope(id)) {
+                    collect(dom, scope.root_node(), duplicates);
+                }
+                return;
+            }
+            VNode::Text(_) | VNode::Placeholder(_) => return,
+        };
+
+        let mut keys = children
+            .iter()
+            .filter_map(VNode::key)
+            .collect::<Vec<&str>>();
+        keys.sort_unstable();
+        duplicates.extend(
+            keys.windows(2)
+                .filter(|pair| pair[0] == pair[1])
+                .map(|pair| pair[0].to_owned()),
+        );
+
+        for child in children {
+            collect(dom, child, duplicates);
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let _ = dom.rebuild();
+    let mut duplicates = Vec::new();
+    collect(&dom, dom.base_scope().root_node(), &mut duplicates);
+
+    duplicates
+}
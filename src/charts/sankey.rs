@@ -0,0 +1,285 @@
+use dioxus::prelude::*;
+
+pub use crate::geometry::sankey::{SankeyLink, SankeyNode};
+
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::multiline_text::MultilineText;
+use crate::geometry::sankey::sankey_layout;
+use crate::utils::color::red_shade;
+
+/// The `SankeyChart` properties struct for the configuration of the Sankey diagram.
+#[derive(Props)]
+pub struct SankeyChartProps<'a> {
+    nodes: Vec<SankeyNode>,
+    links: Vec<SankeyLink>,
+
+    #[props(default = "100%")]
+    width: &'a str,
+    #[props(default = "100%")]
+    height: &'a str,
+    #[props(default = 600)]
+    viewbox_width: i32,
+    #[props(default = 400)]
+    viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
+
+    #[props(default)]
+    padding_top: i32,
+    #[props(default)]
+    padding_bottom: i32,
+    #[props(default)]
+    padding_left: i32,
+    #[props(default)]
+    padding_right: i32,
+
+    #[props(default = 15.0)]
+    node_width: f32,
+    #[props(default = 10.0)]
+    node_padding: f32,
+    #[props(default = true)]
+    show_labels: bool,
+    #[props(default = 6.0)]
+    label_offset: f32,
+
+    #[props(optional)]
+    on_node_hover: Option<EventHandler<'a, usize>>,
+    #[props(optional)]
+    on_link_hover: Option<EventHandler<'a, usize>>,
+
+    #[props(default = "dx-sankey-chart")]
+    class_chart: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
+    #[props(default = "dx-node")]
+    class_node: &'a str,
+    #[props(default = "dx-link")]
+    class_link: &'a str,
+    #[props(default = "dx-label")]
+    class_label: &'a str,
+}
+
+/// This is the `SankeyChart` function used to render the Sankey diagram `Element`.
+/// In Dioxus, components are just functions, so this is the main `SankeyChart`
+/// component to be used inside `rsx!` macros in your code.
+///
+/// The nodes are placed in columns following the links, which must flow forward without any
+/// cycles. See [`sankey_layout`](crate::geometry::sankey::sankey_layout) for the details.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::SankeyChart;
+/// use dioxus_charts::charts::sankey::{SankeyLink, SankeyNode};
+///
+/// fn app(cx: Scope) -> Element {
+///     let node = |label: &str| SankeyNode { label: label.into(), ..Default::default() };
+///     let link = |source, target, value| SankeyLink { source, target, value };
+///
+///     cx.render(rsx! {
+///         SankeyChart {
+///             padding_top: 20,
+///             padding_bottom: 20,
+///             padding_left: 20,
+///             padding_right: 20,
+///             nodes: vec![node("Visit"), node("Sign up"), node("Leave"), node("Purchase")],
+///             links: vec![link(0, 1, 40.0), link(0, 2, 60.0), link(1, 3, 15.0)],
+///         }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `nodes`: [Vec]<[`SankeyNode`]> (**required**): The nodes, with their labels and optional
+///   classes and colors.
+/// - `links`: [Vec]<[`SankeyLink`]> (**required**): The flows between the nodes, referring to
+///   them by index.
+/// ---
+/// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
+///   other CSS style, i.e., "200px"
+/// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
+/// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
+///   `viewbox_height` it is useful scaling up or down the chart and labels.
+/// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
+/// ---
+/// - `padding_top`: [i32] (default: `0`): Padding for the top side of the view box.
+/// - `padding_bottom`: [i32] (default: `0`): Padding for the bottom side of the view box.
+/// - `padding_left`: [i32] (default: `0`): Padding for the left side of the view box.
+/// - `padding_right`: [i32] (default: `0`): Padding for the right side of the view box.
+/// ---
+/// - `node_width`: [f32] (default: `15.0`): The width of the node rects.
+/// - `node_padding`: [f32] (default: `10.0`): The vertical space between the nodes of a column.
+/// - `show_labels`: [bool] (default: `true`): Show/hide the node labels, drawn to the right of
+///   the nodes, or to their left for the last column.
+/// - `label_offset`: [f32] (default: `6.0`): The distance between the labels and their node.
+/// ---
+/// - `on_node_hover`: [EventHandler]<[usize]> (optional): Called with the node index when the
+///   pointer enters a node.
+/// - `on_link_hover`: [EventHandler]<[usize]> (optional): Called with the link index when the
+///   pointer enters a link ribbon.
+/// ---
+/// - `class_chart`: &[str] (default: `"dx-sankey-chart"`): The HTML element `class` of the
+///   Sankey diagram.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
+/// - `class_node`: &[str] (default: `"dx-node"`): The HTML element `class` for all the node
+///   rects.
+/// - `class_link`: &[str] (default: `"dx-link"`): The HTML element `class` for all the link
+///   ribbons.
+/// - `class_label`: &[str] (default: `"dx-label"`): The HTML element `class` for all labels.
+#[allow(non_snake_case)]
+pub fn SankeyChart<'a>(cx: Scope<'a, SankeyChartProps<'a>>) -> Element<'a> {
+    if cx.props.nodes.is_empty() {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
+    let plot_width =
+        (cx.props.viewbox_width - cx.props.padding_left - cx.props.padding_right) as f32;
+    let plot_height =
+        (cx.props.viewbox_height - cx.props.padding_top - cx.props.padding_bottom) as f32;
+
+    let layout = match sankey_layout(
+        cx.props.nodes.len(),
+        &cx.props.links,
+        plot_width,
+        plot_height,
+        cx.props.node_width,
+        cx.props.node_padding,
+    ) {
+        Ok(layout) => layout,
+        Err(error) => return cx.render(rsx!("Sankey chart error: {error}")),
+    };
+
+    let colors = cx
+        .props
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            node.color
+                .clone()
+                .unwrap_or_else(|| format!("rgb({}, 40, 40)", red_shade(i)))
+        })
+        .collect::<Vec<String>>();
+    let colors = &colors;
+    let layout = &layout;
+
+    cx.render(rsx! {
+        div {
+            svg {
+                view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                width: "{cx.props.width}",
+                height: "{cx.props.height}",
+                class: "{cx.props.class_chart}",
+                preserveAspectRatio: "xMidYMid meet",
+                xmlns: "http://www.w3.org/2000/svg",
+                g {
+                    transform: "translate({cx.props.padding_left}, {cx.props.padding_top})",
+                    g {
+                        layout.links.iter().enumerate().map(|(i, link)| {
+                            let color = &colors[link.source];
+
+                            rsx! {
+                                path {
+                                    key: "link-{i}",
+                                    d: "{link.path}",
+                                    class: "{cx.props.class_link}",
+                                    fill: "{color}",
+                                    fill_opacity: "0.4",
+                                    onmouseenter: move |_| {
+                                        if let Some(handler) = &cx.props.on_link_hover {
+                                            handler.call(i);
+                                        }
+                                    },
+                                }
+                            }
+                        })
+                    }
+                    g {
+                        layout.nodes.iter().enumerate().map(|(i, node)| {
+                            let color = &colors[i];
+                            let class_extra = cx.props.nodes[i].class.as_deref().unwrap_or("");
+
+                            rsx! {
+                                rect {
+                                    key: "node-{i}",
+                                    x: "{node.x}",
+                                    y: "{node.y}",
+                                    width: "{node.width}",
+                                    height: "{node.height}",
+                                    class: "{cx.props.class_node} {class_extra}",
+                                    fill: "{color}",
+                                    onmouseenter: move |_| {
+                                        if let Some(handler) = &cx.props.on_node_hover {
+                                            handler.call(i);
+                                        }
+                                    },
+                                }
+                            }
+                        })
+                    }
+                    cx.props.show_labels.then(|| rsx! {
+                        g {
+                            layout.nodes.iter().enumerate().map(|(i, node)| {
+                                let last = node.column + 1 == layout.columns && layout.columns > 1;
+                                let (x, anchor) = if last {
+                                    (node.x - cx.props.label_offset, "end")
+                                } else {
+                                    (node.x + node.width + cx.props.label_offset, "start")
+                                };
+                                let y = node.y + node.height / 2.0;
+                                let label = &cx.props.nodes[i].label;
+
+                                rsx! {
+                                    MultilineText {
+                                        key: "label-{i}",
+                                        x: x,
+                                        y: y,
+                                        text: label,
+                                        text_anchor: anchor,
+                                        class: cx.props.class_label,
+                                    }
+                                }
+                            })
+                        }
+                    })
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::rendered_attributes;
+
+    #[test]
+    fn empty_data_renders_the_empty_state() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! { SankeyChart { nodes: vec![], links: vec![] } })
+        }
+
+        assert!(rendered_attributes(app, "class").contains(&"dx-empty".to_string()));
+    }
+}
@@ -0,0 +1,502 @@
+use dioxus::prelude::*;
+
+use crate::charts::empty::{render_empty, EmptyState};
+use crate::charts::legend_row::{LegendRow, LEGEND_ROW_HEIGHT};
+use crate::geometry::arc::RingSegment;
+use crate::types::{Labels, Point};
+use crate::utils::color::red_shade;
+use crate::utils::format::{label_number_format, RoundingMode};
+use crate::utils::polar_to_cartesian;
+use crate::utils::scale::round_step;
+use crate::utils::text::{format_scaled, DEFAULT_FONT_SIZE};
+use crate::utils::transform::sanitize_series;
+
+/// The names of the 16 compass directions, clockwise from north.
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+/// The room around the rose for the direction labels.
+const DIRECTION_LABEL_MARGIN: f32 = 30.0;
+/// The space between the outer grid circle and the direction labels.
+const DIRECTION_LABEL_GAP: f32 = 10.0;
+
+/// What the length of the wind rose wedges stands for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoseScale {
+    /// The share of every value in the total of all the data, in percent.
+    Percent,
+    /// The values as they are, i.e., the number of observations.
+    Count,
+}
+
+/// The payload of the wind rose sector events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SectorEvent {
+    /// The index of the direction sector in the data.
+    pub sector: usize,
+    /// The value of every bin of the sector, in percent with [`RoseScale::Percent`].
+    pub values: Vec<f32>,
+    /// The sum of the sector values.
+    pub total: f32,
+}
+
+/// The `WindRoseChart` properties struct for the configuration of the wind rose.
+#[derive(Props)]
+pub struct WindRoseChartProps<'a> {
+    data: Vec<Vec<f32>>,
+    #[props(optional)]
+    sector_count: Option<usize>,
+    #[props(optional)]
+    bin_labels: Option<Labels>,
+    #[props(optional)]
+    direction_labels: Option<Labels>,
+    #[props(optional)]
+    colors: Option<Vec<String>>,
+
+    #[props(default = "100%")]
+    width: &'a str,
+    #[props(default = "100%")]
+    height: &'a str,
+    #[props(default = 600)]
+    viewbox_width: i32,
+    #[props(default = 400)]
+    viewbox_height: i32,
+    #[props(default = "No data")]
+    empty_text: &'a str,
+    #[props(default)]
+    empty_render: Element<'a>,
+
+    #[props(default = RoseScale::Percent)]
+    scale: RoseScale,
+    #[props(default)]
+    padding: f32,
+    #[props(default = 2.0)]
+    sector_gap: f32,
+    #[props(default = 4)]
+    grid_rings: usize,
+    #[props(default = true)]
+    show_grid: bool,
+    #[props(default = true)]
+    show_direction_labels: bool,
+    #[props(default = true)]
+    show_legend: bool,
+    #[props(optional)]
+    label_interpolation: Option<fn(f32) -> String>,
+    #[props(optional)]
+    label_decimals: Option<usize>,
+    #[props(default)]
+    rounding: RoundingMode,
+
+    #[props(optional)]
+    onclick_sector: Option<EventHandler<'a, SectorEvent>>,
+    #[props(optional)]
+    onmouseover_sector: Option<EventHandler<'a, SectorEvent>>,
+
+    #[props(default = "dx-wind-rose")]
+    class_chart: &'a str,
+    #[props(default = "dx-empty")]
+    class_empty: &'a str,
+    #[props(default = "dx-sector")]
+    class_sector: &'a str,
+    #[props(default = "dx-wedge")]
+    class_wedge: &'a str,
+    #[props(default = "dx-grid")]
+    class_grid: &'a str,
+    #[props(default = "dx-grid-line")]
+    class_grid_line: &'a str,
+    #[props(default = "dx-grid-label")]
+    class_grid_label: &'a str,
+    #[props(default = "dx-direction-label")]
+    class_direction_label: &'a str,
+    #[props(default = "dx-legend")]
+    class_legend: &'a str,
+    #[props(default = "dx-legend-item")]
+    class_legend_item: &'a str,
+}
+
+/// This is the `WindRoseChart` function used to render the wind rose `Element`, an angular
+/// histogram of stacked wedges for every direction, i.e., the frequency of the wind speeds
+/// coming from every direction.
+/// In Dioxus, components are just functions, so this is the main `WindRoseChart`
+/// component to be used inside `rsx!` macros in your code.
+///
+/// The sectors are centered on their direction, clockwise from north at the top, and their
+/// bins are stacked outwards in order.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::prelude::*;
+///
+/// fn app(cx: Scope) -> Element {
+///     cx.render(rsx! {
+///         WindRoseChart {
+///             data: vec![
+///                 vec![4.0, 6.0, 2.0],
+///                 vec![2.0, 3.0, 1.0],
+///                 vec![1.0, 2.0, 0.0],
+///                 vec![3.0, 5.0, 4.0],
+///             ],
+///             bin_labels: vec!["0-5 m/s".into(), "5-10 m/s".into(), "10+ m/s".into()],
+///             onmouseover_sector: move |event: SectorEvent| log::info!("{event:?}"),
+///         }
+///     })
+/// }
+/// ```
+///
+/// # Props
+///
+/// - `data`: [Vec]<[Vec]<[f32]>> (**required**): The values of every direction sector, one
+///   vector for each sector with a value for every bin, i.e., every wind speed range. Missing
+///   bins count as zero, and so do `NaN`, infinite and negative values.
+/// - `sector_count`: [usize] (optional): The number of sectors around the rose, which must match
+///   the length of `data`. It is the length of `data` when not set.
+/// - `bin_labels`: [Vec]<[String]> (optional): The labels of the bins, shown in the legend.
+/// - `direction_labels`: [Vec]<[String]> (optional): The labels of the sectors around the rose.
+///   For 4, 8 or 16 sectors the compass points are used when not set, i.e., `N`, `NE`, `E`, and
+///   the angles otherwise.
+/// - `colors`: [Vec]<[String]> (optional): CSS colors for the bins, cycled when there are more
+///   bins than colors. The generated shades of red are used when not set.
+/// ---
+/// - `width`: &[str] (default: `"100%"`): The SVG element width attribute. It also accepts any
+///   other CSS style, i.e., "200px"
+/// - `height`: &[str] (default: `"100%"`): The SVG height counter-part of the `width` prop above.
+/// - `viewbox_width`: [i32] (default: `600`): The SVG viewbox width. Together with
+///   `viewbox_height` it is useful scaling up or down the chart and labels.
+/// - `viewbox_height`: [i32] (default: `400`): The SVG viewbox height.
+/// - `empty_text`: &[str] (default: `"No data"`): The message shown in the middle of the view box
+///   instead of the chart when there is no data.
+/// - `empty_render`: [Element] (optional): Content rendered instead of the whole chart when there
+///   is no data, replacing the `empty_text` message.
+/// ---
+/// - `scale`: [`RoseScale`] (default: [`RoseScale::Percent`]): Whether the wedges and the grid
+///   show the share of the total of all the data, or the values as they are.
+/// - `padding`: [f32] (default: `0.0`): Padding for every side of the SVG view box.
+/// - `sector_gap`: [f32] (default: `2.0`): The gap between neighbouring sectors, in degrees.
+/// - `grid_rings`: [usize] (default: `4`): The approximate number of grid circles, which land on
+///   round values.
+/// - `show_grid`: [bool] (default: `true`): Show the grid circles with their values.
+/// - `show_direction_labels`: [bool] (default: `true`): Show the sector labels around the rose.
+/// - `show_legend`: [bool] (default: `true`): Show the legend row with the `bin_labels`.
+/// - `label_interpolation`: fn([f32]) -> [String] (optional): Function for formatting the grid
+///   values, which are suffixed with `%` with [`RoseScale::Percent`] otherwise.
+/// - `label_decimals`: [usize] (optional): The number of decimals of the grid values.
+/// - `rounding`: [`RoundingMode`] (default: [`RoundingMode::HalfUp`]): How the grid values are
+///   rounded to the `label_decimals`.
+/// ---
+/// - `onclick_sector`: [EventHandler]<[`SectorEvent`]> (optional): Called with the sector and its
+///   bin values when a sector is clicked.
+/// - `onmouseover_sector`: [EventHandler]<[`SectorEvent`]> (optional): Called like
+///   `onclick_sector` when the pointer enters a sector.
+/// ---
+/// - `class_chart`: &[str] (default: `"dx-wind-rose"`): The HTML element `class` of the chart.
+/// - `class_empty`: &[str] (default: `"dx-empty"`): The HTML element `class` of the no data
+///   message.
+/// - `class_sector`: &[str] (default: `"dx-sector"`): The HTML element `class` for the group of
+///   wedges of every sector, also suffixed with the sector index, i.e., `"dx-sector-0"`.
+/// - `class_wedge`: &[str] (default: `"dx-wedge"`): The HTML element `class` for every wedge,
+///   also suffixed with the bin index, i.e., `"dx-wedge-0"`.
+/// - `class_grid`: &[str] (default: `"dx-grid"`): The HTML element `class` of the grid.
+/// - `class_grid_line`: &[str] (default: `"dx-grid-line"`): The HTML element `class` of every
+///   grid circle.
+/// - `class_grid_label`: &[str] (default: `"dx-grid-label"`): The HTML element `class` of the
+///   grid values.
+/// - `class_direction_label`: &[str] (default: `"dx-direction-label"`): The HTML element `class`
+///   of the sector labels.
+/// - `class_legend`: &[str] (default: `"dx-legend"`): The HTML element `class` of the legend row.
+/// - `class_legend_item`: &[str] (default: `"dx-legend-item"`): The HTML element `class` for
+///   every legend label.
+#[allow(non_snake_case)]
+pub fn WindRoseChart<'a>(cx: Scope<'a, WindRoseChartProps<'a>>) -> Element<'a> {
+    let data = cx
+        .props
+        .data
+        .iter()
+        .map(|sector| sanitize_series(sector))
+        .collect::<Vec<Vec<f32>>>();
+    let grand_total: f32 = data.iter().flatten().sum();
+
+    if grand_total <= 0.0 {
+        return render_empty(
+            cx,
+            EmptyState {
+                width: cx.props.width,
+                height: cx.props.height,
+                viewbox_width: cx.props.viewbox_width,
+                viewbox_height: cx.props.viewbox_height,
+                class_chart: cx.props.class_chart,
+                class_empty: cx.props.class_empty,
+                text: cx.props.empty_text,
+                render: &cx.props.empty_render,
+            },
+        );
+    }
+
+    let sectors = data.len();
+    if let Some(count) = cx.props.sector_count.filter(|count| *count != sectors) {
+        return cx.render(rsx!(
+            "Wind rose error: {sectors} data sectors for a sector count of {count}"
+        ));
+    }
+
+    let values = data
+        .into_iter()
+        .map(|sector| match cx.props.scale {
+            RoseScale::Percent => sector.iter().map(|v| v / grand_total * 100.0).collect(),
+            RoseScale::Count => sector,
+        })
+        .collect::<Vec<Vec<f32>>>();
+    let totals = values
+        .iter()
+        .map(|sector| sector.iter().sum())
+        .collect::<Vec<f32>>();
+    let highest = totals.iter().copied().fold(0.0, f32::max);
+
+    // The grid circles land on round values, and the outer one bounds the longest sector
+    let step = round_step(f64::from(highest / cx.props.grid_rings.max(1) as f32)) as f32;
+    let rings = (highest / step).ceil().max(1.0) as usize;
+    let outer_value = rings as f32 * step;
+
+    let has_legend = cx.props.show_legend && cx.props.bin_labels.is_some();
+    let legend_height = if has_legend { LEGEND_ROW_HEIGHT } else { 0.0 };
+    let available_height = cx.props.viewbox_height as f32 - legend_height;
+    let center = Point::new(cx.props.viewbox_width as f32 / 2.0, available_height / 2.0);
+    let radius = ((cx.props.viewbox_width as f32).min(available_height) / 2.0
+        - DIRECTION_LABEL_MARGIN
+        - cx.props.padding)
+        .max(0.0);
+    let radius_of = |value: f32| value / outer_value * radius;
+
+    let sector_angle = 360.0 / sectors as f32;
+    let half_width = ((sector_angle - cx.props.sector_gap.max(0.0)) / 2.0).max(0.0);
+
+    let bin_color = |bin: usize| match &cx.props.colors {
+        Some(colors) if !colors.is_empty() => colors[bin % colors.len()].clone(),
+        _ => format!("rgb({}, 40, 40)", red_shade(bin)),
+    };
+
+    let wedges = values
+        .iter()
+        .enumerate()
+        .map(|(sector, bins)| {
+            let angle = sector as f32 * sector_angle;
+            let mut inner = 0.0;
+
+            bins.iter()
+                .enumerate()
+                .filter(|(_, v)| **v > 0.0)
+                .map(|(bin, v)| {
+                    let path = RingSegment {
+                        center_x: center.x,
+                        center_y: center.y,
+                        inner_radius: radius_of(inner),
+                        outer_radius: radius_of(inner + v),
+                        start_angle: angle - half_width,
+                        end_angle: angle + half_width,
+                        corner_radius: 0.0,
+                    }
+                    .path();
+                    inner += v;
+
+                    (bin, path, bin_color(bin))
+                })
+                .collect::<Vec<(usize, String, String)>>()
+        })
+        .collect::<Vec<Vec<(usize, String, String)>>>();
+
+    let number_format = label_number_format(None, cx.props.label_decimals, cx.props.rounding);
+    let format_grid = |value: f32| {
+        let label = format_scaled(
+            value,
+            cx.props.label_interpolation,
+            number_format.as_ref(),
+            1.0,
+            None,
+        );
+
+        match (cx.props.label_interpolation, cx.props.scale) {
+            (None, RoseScale::Percent) => format!("{label}%"),
+            _ => label,
+        }
+    };
+    // The grid values go along the gap between the first two sectors
+    let grid_label_angle = sector_angle / 2.0;
+    let grid = (1..=rings)
+        .map(|ring| {
+            let value = ring as f32 * step;
+            let r = radius_of(value);
+
+            (
+                r,
+                polar_to_cartesian(center, r, grid_label_angle),
+                format_grid(value),
+            )
+        })
+        .collect::<Vec<(f32, Point, String)>>();
+    let grid = &grid;
+
+    let direction_labels = (0..sectors)
+        .map(|sector| {
+            let angle = sector as f32 * sector_angle;
+            let label = match &cx.props.direction_labels {
+                Some(labels) => labels.get(sector).cloned().unwrap_or_default(),
+                None if 16 % sectors == 0 && sectors >= 4 => {
+                    COMPASS_POINTS[sector * 16 / sectors].to_string()
+                }
+                None => format!("{angle}°"),
+            };
+
+            (
+                polar_to_cartesian(center, radius + DIRECTION_LABEL_GAP, angle),
+                label,
+            )
+        })
+        .collect::<Vec<(Point, String)>>();
+
+    let bin_labels = cx.props.bin_labels.as_deref().unwrap_or_default();
+
+    cx.render(rsx! {
+        div {
+            svg {
+                view_box: "0 0 {cx.props.viewbox_width} {cx.props.viewbox_height}",
+                width: "{cx.props.width}",
+                height: "{cx.props.height}",
+                class: "{cx.props.class_chart}",
+                preserveAspectRatio: "xMidYMid meet",
+                xmlns: "http://www.w3.org/2000/svg",
+                cx.props.show_grid.then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_grid}",
+                        grid.iter().enumerate().map(|(n, (r, _, _))| rsx! {
+                            circle {
+                                key: "grid-line-{n}",
+                                cx: "{center.x}",
+                                cy: "{center.y}",
+                                r: "{r}",
+                                class: "{cx.props.class_grid_line}",
+                                stroke: "rgba(20, 20, 20, 0.3)",
+                                stroke_dasharray: "2px",
+                                fill: "none",
+                            }
+                        })
+                    }
+                }),
+                wedges.iter().enumerate().map(|(sector, wedges)| {
+                    let event = SectorEvent {
+                        sector,
+                        values: values[sector].clone(),
+                        total: totals[sector],
+                    };
+                    let hover_event = event.clone();
+
+                    rsx! {
+                        g {
+                            key: "sector-{sector}",
+                            class: "{cx.props.class_sector} {cx.props.class_sector}-{sector}",
+                            onclick: move |_| {
+                                if let Some(handler) = &cx.props.onclick_sector {
+                                    handler.call(event.clone());
+                                }
+                            },
+                            onmouseover: move |_| {
+                                if let Some(handler) = &cx.props.onmouseover_sector {
+                                    handler.call(hover_event.clone());
+                                }
+                            },
+                            wedges.iter().map(|(bin, path, color)| rsx! {
+                                path {
+                                    key: "wedge-{bin}",
+                                    d: "{path}",
+                                    class: "{cx.props.class_wedge} {cx.props.class_wedge}-{bin}",
+                                    fill: "{color}",
+                                    stroke: "white",
+                                    stroke_width: "0.5",
+                                }
+                            })
+                        }
+                    }
+                }),
+                cx.props.show_grid.then(|| rsx! {
+                    g {
+                        class: "{cx.props.class_grid}",
+                        grid.iter().enumerate().map(|(n, (_, position, label))| rsx! {
+                            text {
+                                key: "grid-label-{n}",
+                                dx: "{position.x}",
+                                dy: "{position.y}",
+                                text_anchor: "start",
+                                class: "{cx.props.class_grid_label}",
+                                alignment_baseline: "text-bottom",
+                                font_size: "{DEFAULT_FONT_SIZE}",
+                                "{label}"
+                            }
+                        })
+                    }
+                }),
+                cx.props.show_direction_labels.then(|| rsx! {
+                    g {
+                        direction_labels.iter().enumerate().map(|(n, (position, label))| {
+                            let (dx, dy) = (position.x - center.x, position.y - center.y);
+                            let anchor = if dx > radius * 0.1 {
+                                "start"
+                            } else if dx < -radius * 0.1 {
+                                "end"
+                            } else {
+                                "middle"
+                            };
+                            let baseline = if dy > radius * 0.1 {
+                                "hanging"
+                            } else if dy < -radius * 0.1 {
+                                "text-bottom"
+                            } else {
+                                "middle"
+                            };
+
+                            rsx! {
+                                text {
+                                    key: "direction-label-{n}",
+                                    dx: "{position.x}",
+                                    dy: "{position.y}",
+                                    text_anchor: "{anchor}",
+                                    class: "{cx.props.class_direction_label}",
+                                    alignment_baseline: "{baseline}",
+                                    "{label}"
+                                }
+                            }
+                        })
+                    }
+                }),
+                has_legend.then(|| rsx! {
+                    LegendRow {
+                        items: bin_labels
+                            .iter()
+                            .enumerate()
+                            .map(|(bin, label)| (bin, bin_color(bin), label.clone()))
+                            .collect(),
+                        viewbox_width: cx.props.viewbox_width,
+                        viewbox_height: cx.props.viewbox_height,
+                        class: cx.props.class_legend,
+                        class_item: cx.props.class_legend_item,
+                    }
+                }),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charts::render::rendered_attributes;
+
+    #[test]
+    fn empty_data_renders_the_empty_state() {
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! { WindRoseChart { data: vec![vec![], vec![]] } })
+        }
+
+        assert!(rendered_attributes(app, "class").contains(&"dx-empty".to_string()));
+    }
+}
@@ -0,0 +1,183 @@
+//! The outlines of pie and donut slices.
+
+use crate::types::Point;
+use crate::utils::path::PathBuilder;
+use crate::utils::polar_to_cartesian;
+
+/// A segment of a ring between two angles, like a donut slice, with optionally rounded corners.
+///
+/// The angles are in degrees, clockwise from the top, like the `start_angle` of the
+/// [`PieChart`](crate::charts::PieChart), and `start_angle` must not be after `end_angle`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct RingSegment {
+    /// The x coordinate of the center of the ring.
+    pub center_x: f32,
+    /// The y coordinate of the center of the ring.
+    pub center_y: f32,
+    /// The radius of the hole of the ring.
+    pub inner_radius: f32,
+    /// The radius of the outside of the ring.
+    pub outer_radius: f32,
+    /// The angle where the segment starts.
+    pub start_angle: f32,
+    /// The angle where the segment ends.
+    pub end_angle: f32,
+    /// The radius of the arcs rounding the four corners, before
+    /// [`clamped_corner_radius`](RingSegment::clamped_corner_radius).
+    pub corner_radius: f32,
+}
+
+impl RingSegment {
+    /// The corner radius actually used for the path: at most half the ring width, and small
+    /// enough for the corners of both ends to fit side by side along the inner and outer arcs,
+    /// so that thin segments don't turn inside out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dioxus_charts::geometry::arc::RingSegment;
+    ///
+    /// let segment = RingSegment {
+    ///     inner_radius: 60.0,
+    ///     outer_radius: 100.0,
+    ///     start_angle: 0.0,
+    ///     end_angle: 90.0,
+    ///     corner_radius: 8.0,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(segment.clamped_corner_radius(), 8.0);
+    ///
+    /// // Half the width of the ring
+    /// let wide = RingSegment { corner_radius: 50.0, ..segment };
+    /// assert_eq!(wide.clamped_corner_radius(), 20.0);
+    ///
+    /// // Small enough for both inner corners to fit in a thin segment
+    /// let thin = RingSegment { end_angle: 10.0, ..segment };
+    /// assert!((thin.clamped_corner_radius() - 5.729).abs() < 1e-3);
+    /// ```
+    pub fn clamped_corner_radius(&self) -> f32 {
+        let mut radius = self
+            .corner_radius
+            .min((self.outer_radius - self.inner_radius) / 2.0)
+            .max(0.0);
+        let half_sweep = ((self.end_angle - self.start_angle) / 2.0).to_radians();
+
+        if half_sweep < std::f32::consts::FRAC_PI_2 {
+            let sin = half_sweep.sin().max(0.0);
+            radius = radius
+                .min(self.inner_radius * sin / (1.0 - sin))
+                .min(self.outer_radius * sin / (1.0 + sin));
+        }
+
+        radius
+    }
+
+    /// The SVG path of the segment. Without a corner radius, it is the plain ring segment, or the
+    /// pie slice when the inner radius is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dioxus_charts::geometry::arc::RingSegment;
+    ///
+    /// let segment = RingSegment {
+    ///     inner_radius: 60.0,
+    ///     outer_radius: 100.0,
+    ///     start_angle: 0.0,
+    ///     end_angle: 90.0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // The outer and inner arcs joined by the straight edges
+    /// let path = segment.path();
+    /// assert_eq!(path.matches('A').count(), 2);
+    /// assert!(path.starts_with("M100,0A100,100,0,0,0,") && path.ends_with('Z'));
+    ///
+    /// // Plus one arc for every corner
+    /// let rounded = RingSegment { corner_radius: 8.0, ..segment }.path();
+    /// assert_eq!(rounded.matches('A').count(), 6);
+    /// assert_eq!(rounded.matches("A8,8,0,0,1,").count(), 4);
+    /// ```
+    pub fn path(&self) -> String {
+        let center = Point::new(self.center_x, self.center_y);
+        let (inner, outer) = (self.inner_radius, self.outer_radius);
+        let (start, end) = (self.start_angle, self.end_angle);
+        let corner = self.clamped_corner_radius();
+
+        if corner <= 0.0 {
+            let large_arc = end - start > 180.0;
+            let mut path = PathBuilder::with_capacity(5).compact();
+            path.move_to(polar_to_cartesian(center, outer, end)).arc_to(
+                outer,
+                large_arc,
+                false,
+                polar_to_cartesian(center, outer, start),
+            );
+
+            if inner > 0.0 {
+                path.line_to(polar_to_cartesian(center, inner, start))
+                    .arc_to(
+                        inner,
+                        large_arc,
+                        true,
+                        polar_to_cartesian(center, inner, end),
+                    );
+            } else {
+                path.line_to(center);
+            }
+
+            path.close();
+            return path.build();
+        }
+
+        // The corner circles touch both the radial edge and the arc, so they are moved along
+        // the arcs by the angle their radius takes at the distance of their centers
+        let outer_shift = (corner / (outer - corner)).asin().to_degrees();
+        let inner_shift = (corner / (inner + corner)).asin().to_degrees();
+        let outer_edge = ((outer - corner).powi(2) - corner.powi(2)).sqrt();
+        let inner_edge = ((inner + corner).powi(2) - corner.powi(2)).sqrt();
+
+        let mut path = PathBuilder::with_capacity(9).compact();
+        path.move_to(polar_to_cartesian(center, outer_edge, start))
+            .arc_to(
+                corner,
+                false,
+                true,
+                polar_to_cartesian(center, outer, start + outer_shift),
+            )
+            .arc_to(
+                outer,
+                end - start - 2.0 * outer_shift > 180.0,
+                true,
+                polar_to_cartesian(center, outer, end - outer_shift),
+            )
+            .arc_to(
+                corner,
+                false,
+                true,
+                polar_to_cartesian(center, outer_edge, end),
+            )
+            .line_to(polar_to_cartesian(center, inner_edge, end))
+            .arc_to(
+                corner,
+                false,
+                true,
+                polar_to_cartesian(center, inner, end - inner_shift),
+            )
+            .arc_to(
+                inner,
+                end - start - 2.0 * inner_shift > 180.0,
+                false,
+                polar_to_cartesian(center, inner, start + inner_shift),
+            )
+            .arc_to(
+                corner,
+                false,
+                true,
+                polar_to_cartesian(center, inner_edge, start),
+            )
+            .close();
+
+        path.build()
+    }
+}
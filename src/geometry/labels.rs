@@ -0,0 +1,133 @@
+//! The placement of labels stacked along a vertical line without overlapping.
+
+/// A label to place along a vertical line, i.e., the label at the end of a line series.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LabelBox {
+    /// The y coordinate of the labelled point, where the label is centered when it has room.
+    pub anchor: f32,
+    /// The height of the label.
+    pub height: f32,
+    /// How readily the label moves away from its anchor compared to the others, i.e., the
+    /// vertical room around its point. A label with twice the mobility of its neighbour moves
+    /// twice as far to make room.
+    pub mobility: f32,
+}
+
+/// The centers of the labels, in the order of `boxes`, moved apart so that there is at least
+/// `gap` between neighbours and kept between `min` and `max` when they fit.
+///
+/// The labels keep the order of their anchors. Every group of overlapping labels is stacked
+/// around the position that moves them the least, weighted by their mobility, and the groups
+/// are merged again while they overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::geometry::labels::{resolve_overlaps, LabelBox};
+///
+/// let label = |anchor: f32, mobility: f32| LabelBox {
+///     anchor,
+///     height: 10.0,
+///     mobility,
+/// };
+///
+/// // Labels with room are left alone
+/// assert_eq!(
+///     resolve_overlaps(&[label(50.0, 1.0), label(20.0, 1.0)], 2.0, 0.0, 100.0),
+///     vec![50.0, 20.0]
+/// );
+///
+/// // Two overlapping labels move apart evenly
+/// assert_eq!(
+///     resolve_overlaps(&[label(50.0, 1.0), label(52.0, 1.0)], 2.0, 0.0, 100.0),
+///     vec![45.0, 57.0]
+/// );
+///
+/// // The label with more room does most of the moving
+/// let centers = resolve_overlaps(&[label(50.0, 3.0), label(52.0, 1.0)], 2.0, 0.0, 100.0);
+/// assert_eq!(centers, vec![42.5, 54.5]);
+///
+/// // A chain of overlaps is stacked as one group, and kept inside the bounds
+/// let centers = resolve_overlaps(
+///     &[label(4.0, 1.0), label(6.0, 1.0), label(8.0, 1.0)],
+///     0.0,
+///     0.0,
+///     100.0,
+/// );
+/// assert_eq!(centers, vec![5.0, 15.0, 25.0]);
+/// ```
+pub fn resolve_overlaps(boxes: &[LabelBox], gap: f32, min: f32, max: f32) -> Vec<f32> {
+    let mut order = (0..boxes.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| boxes[*a].anchor.total_cmp(&boxes[*b].anchor));
+
+    // The groups of labels stacked together, as ranges of `order` with the top of the stack
+    let mut groups = Vec::<(usize, usize, f32)>::new();
+    let stack_height = |start: usize, end: usize| {
+        order[start..end]
+            .iter()
+            .map(|i| boxes[*i].height + gap)
+            .sum::<f32>()
+            - gap
+    };
+    let best_top = |start: usize, end: usize| {
+        let (mut offset, mut weighted, mut weights) = (0.0, 0.0, 0.0);
+
+        for i in &order[start..end] {
+            let label = boxes[*i];
+            let weight = 1.0 / label.mobility.max(f32::EPSILON);
+            weighted += weight * (label.anchor - offset - label.height / 2.0);
+            weights += weight;
+            offset += label.height + gap;
+        }
+
+        weighted / weights
+    };
+
+    for n in 0..order.len() {
+        let label = boxes[order[n]];
+        groups.push((n, n + 1, label.anchor - label.height / 2.0));
+
+        while groups.len() > 1 {
+            let (start, end, top) = groups[groups.len() - 1];
+            let (previous_start, previous_end, previous_top) = groups[groups.len() - 2];
+
+            if previous_top + stack_height(previous_start, previous_end) + gap <= top {
+                break;
+            }
+
+            groups.pop();
+            groups.pop();
+            groups.push((previous_start, end, best_top(previous_start, end)));
+            debug_assert_eq!(previous_end, start);
+        }
+    }
+
+    let mut centers = vec![0.0; boxes.len()];
+    let mut bottom = min - gap;
+
+    for (start, end, top) in groups {
+        let height = stack_height(start, end);
+        // Inside the bounds, and below the previous group pushed down by them
+        let mut y = top.min(max - height).max(min).max(bottom + gap);
+
+        for i in &order[start..end] {
+            centers[*i] = y + boxes[*i].height / 2.0;
+            y += boxes[*i].height + gap;
+        }
+
+        bottom = y - gap;
+    }
+
+    // The groups pushed down past the bottom are moved back up as far as they can
+    let mut top = max + gap;
+    for i in order.iter().rev() {
+        let half = boxes[*i].height / 2.0;
+        let limit = (top - gap - half).max(min + half);
+        if centers[*i] > limit {
+            centers[*i] = limit;
+        }
+        top = centers[*i] - half;
+    }
+
+    centers
+}
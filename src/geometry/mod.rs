@@ -0,0 +1,6 @@
+//! Layout algorithms for the charts that are more than a few lines of geometry, kept apart from
+//! the components so they can be used and checked on their own.
+
+pub mod arc;
+pub mod labels;
+pub mod sankey;
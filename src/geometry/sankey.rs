@@ -0,0 +1,361 @@
+//! The layout of Sankey diagrams: node columns, node positions and link ribbons.
+
+use std::fmt;
+
+use crate::types::Point;
+use crate::utils::path::PathBuilder;
+
+/// A node of the Sankey diagram, i.e., a funnel stage.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SankeyNode {
+    /// The label shown beside the node.
+    pub label: String,
+    /// An extra HTML element `class` for the node rect.
+    pub class: Option<String>,
+    /// The CSS color of the node and its outgoing links, overriding the default shade.
+    pub color: Option<String>,
+}
+
+/// A flow between two nodes of the Sankey diagram.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SankeyLink {
+    /// The index of the node the flow comes from.
+    pub source: usize,
+    /// The index of the node the flow goes to.
+    pub target: usize,
+    /// The size of the flow, which sets the thickness of the ribbon.
+    pub value: f32,
+}
+
+/// The reasons why the links can't be laid out.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SankeyError {
+    /// The links go around in a cycle, so the nodes can't be ordered in columns.
+    Cycle,
+    /// A link refers to a node index that doesn't exist.
+    InvalidNode(usize),
+}
+
+impl fmt::Display for SankeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SankeyError::Cycle => write!(f, "the links contain a cycle"),
+            SankeyError::InvalidNode(index) => write!(f, "a link refers to missing node {index}"),
+        }
+    }
+}
+
+/// The position and size of a laid out node.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SankeyNodeLayout {
+    /// The left side of the node.
+    pub x: f32,
+    /// The top side of the node.
+    pub y: f32,
+    /// The node width.
+    pub width: f32,
+    /// The node height, proportional to its throughput.
+    pub height: f32,
+    /// The column of the node, from `0` at the left.
+    pub column: usize,
+    /// The node throughput, the largest of its incoming and outgoing totals.
+    pub value: f32,
+}
+
+/// A laid out link ribbon.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SankeyLinkLayout {
+    /// The index of the node the flow comes from.
+    pub source: usize,
+    /// The index of the node the flow goes to.
+    pub target: usize,
+    /// The size of the flow.
+    pub value: f32,
+    /// The ribbon thickness, proportional to the value.
+    pub thickness: f32,
+    /// The SVG path of the ribbon, to be filled.
+    pub path: String,
+}
+
+/// The layout of a whole Sankey diagram, with nodes and links in their input order.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SankeyLayout {
+    /// The laid out nodes.
+    pub nodes: Vec<SankeyNodeLayout>,
+    /// The laid out links.
+    pub links: Vec<SankeyLinkLayout>,
+    /// The number of columns.
+    pub columns: usize,
+}
+
+/// Lays out a Sankey diagram of `node_count` nodes in a `width` by `height` box.
+///
+/// Every node goes in the column of the longest path of links leading to it, so all the links
+/// flow from left to right. The nodes of each column are ordered by the average position of
+/// their neighbours, once from the left and once from the right, to reduce the link crossings.
+/// Heights are proportional to the node throughput with the same scale for all the columns,
+/// which are separated vertically by `node_padding`.
+///
+/// Nodes without any links are placed in the first column with a zero height. Negative link
+/// values are treated as zero.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::geometry::sankey::{sankey_layout, SankeyError, SankeyLink};
+///
+/// let link = |source, target, value| SankeyLink { source, target, value };
+///
+/// // A chain puts every node in its own column, all with the same height
+/// let chain = sankey_layout(3, &[link(0, 1, 10.0), link(1, 2, 10.0)], 300.0, 100.0, 10.0, 10.0)
+///     .unwrap();
+/// let columns = chain.nodes.iter().map(|n| n.column).collect::<Vec<_>>();
+/// assert_eq!(columns, [0, 1, 2]);
+/// assert_eq!(chain.nodes[2].x, 290.0);
+/// assert!(chain.nodes.iter().all(|n| (n.height - 100.0).abs() < 1e-3));
+///
+/// // The longest path decides the column: 3 comes after 2, which comes after 1
+/// let links = [link(0, 1, 5.0), link(1, 2, 5.0), link(0, 3, 5.0), link(2, 3, 5.0)];
+/// let diamond = sankey_layout(4, &links, 300.0, 100.0, 10.0, 10.0).unwrap();
+/// let columns = diamond.nodes.iter().map(|n| n.column).collect::<Vec<_>>();
+/// assert_eq!(columns, [0, 1, 2, 3]);
+/// assert_eq!(diamond.nodes[3].value, 10.0);
+///
+/// // Link thickness shares the scale of the node heights
+/// let split = sankey_layout(3, &[link(0, 1, 30.0), link(0, 2, 10.0)], 200.0, 110.0, 10.0, 10.0)
+///     .unwrap();
+/// assert!((split.links[0].thickness - 75.0).abs() < 1e-3);
+/// assert!((split.links[1].thickness - 25.0).abs() < 1e-3);
+///
+/// let cycle = [link(0, 1, 1.0), link(1, 2, 1.0), link(2, 0, 1.0)];
+/// assert_eq!(sankey_layout(3, &cycle, 300.0, 100.0, 10.0, 10.0), Err(SankeyError::Cycle));
+/// assert_eq!(
+///     sankey_layout(2, &[link(0, 5, 1.0)], 300.0, 100.0, 10.0, 10.0),
+///     Err(SankeyError::InvalidNode(5))
+/// );
+/// ```
+pub fn sankey_layout(
+    node_count: usize,
+    links: &[SankeyLink],
+    width: f32,
+    height: f32,
+    node_width: f32,
+    node_padding: f32,
+) -> Result<SankeyLayout, SankeyError> {
+    let mut incoming = vec![Vec::new(); node_count];
+    let mut outgoing = vec![Vec::new(); node_count];
+
+    for (i, link) in links.iter().enumerate() {
+        for index in [link.source, link.target] {
+            if index >= node_count {
+                return Err(SankeyError::InvalidNode(index));
+            }
+        }
+        outgoing[link.source].push(i);
+        incoming[link.target].push(i);
+    }
+
+    let order = topological_order(node_count, links, &incoming, &outgoing)?;
+
+    let mut column = vec![0; node_count];
+    for &node in order.iter() {
+        for &i in outgoing[node].iter() {
+            let target = links[i].target;
+            column[target] = column[target].max(column[node] + 1);
+        }
+    }
+    let columns = column.iter().max().map_or(0, |max| max + 1);
+
+    let flow = |list: &Vec<usize>| list.iter().map(|i| links[*i].value.max(0.0)).sum::<f32>();
+    let values = (0..node_count)
+        .map(|node| flow(&incoming[node]).max(flow(&outgoing[node])))
+        .collect::<Vec<f32>>();
+
+    let mut stacks = vec![Vec::new(); columns];
+    for &node in order.iter() {
+        stacks[column[node]].push(node);
+    }
+
+    // The same value to height scale for every column, fitting the fullest one
+    let scale = stacks
+        .iter()
+        .filter_map(|stack| {
+            let total: f32 = stack.iter().map(|node| values[*node]).sum();
+            let free = height - node_padding * stack.len().saturating_sub(1) as f32;
+            (total > 0.0).then(|| free.max(0.0) / total)
+        })
+        .fold(f32::INFINITY, f32::min);
+    let scale = if scale.is_finite() { scale } else { 0.0 };
+
+    let mut y = vec![0.0; node_count];
+    let place = |stacks: &Vec<Vec<usize>>, y: &mut Vec<f32>| {
+        for stack in stacks.iter() {
+            let used: f32 = stack.iter().map(|node| values[*node] * scale).sum::<f32>()
+                + node_padding * stack.len().saturating_sub(1) as f32;
+            let mut top = (height - used) / 2.0;
+
+            for &node in stack.iter() {
+                y[node] = top;
+                top += values[node] * scale + node_padding;
+            }
+        }
+    };
+    place(&stacks, &mut y);
+
+    let center = |node: usize, y: &Vec<f32>| y[node] + values[node] * scale / 2.0;
+
+    // Barycenter ordering, sweeping forward over the incoming links and then backward over the
+    // outgoing ones
+    for forward in [true, false] {
+        let sweep = if forward {
+            (1..columns).collect::<Vec<usize>>()
+        } else {
+            (0..columns.saturating_sub(1)).rev().collect()
+        };
+
+        for c in sweep {
+            let mut keyed = stacks[c]
+                .iter()
+                .map(|&node| {
+                    let neighbours = if forward {
+                        &incoming[node]
+                    } else {
+                        &outgoing[node]
+                    };
+                    let (sum, weight) = neighbours.iter().fold((0.0, 0.0), |(sum, weight), i| {
+                        let link = links[*i];
+                        let other = if forward { link.source } else { link.target };
+                        let value = link.value.max(0.0);
+                        (sum + center(other, &y) * value, weight + value)
+                    });
+                    let key = if weight > 0.0 {
+                        sum / weight
+                    } else {
+                        center(node, &y)
+                    };
+                    (key, node)
+                })
+                .collect::<Vec<(f32, usize)>>();
+
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            stacks[c] = keyed.into_iter().map(|(_, node)| node).collect();
+            place(&stacks, &mut y);
+        }
+    }
+
+    let step = if columns > 1 {
+        (width - node_width) / (columns - 1) as f32
+    } else {
+        0.0
+    };
+
+    let nodes = (0..node_count)
+        .map(|node| SankeyNodeLayout {
+            x: column[node] as f32 * step,
+            y: y[node],
+            width: node_width,
+            height: values[node] * scale,
+            column: column[node],
+            value: values[node],
+        })
+        .collect::<Vec<SankeyNodeLayout>>();
+
+    // Ribbons leave and enter the nodes stacked in the vertical order of their other end
+    let mut source_offset = vec![0.0; links.len()];
+    let mut target_offset = vec![0.0; links.len()];
+    for node in 0..node_count {
+        let mut sorted = outgoing[node].clone();
+        sorted.sort_by(|a, b| y[links[*a].target].total_cmp(&y[links[*b].target]));
+        let mut offset = 0.0;
+        for i in sorted {
+            source_offset[i] = offset;
+            offset += links[i].value.max(0.0) * scale;
+        }
+
+        let mut sorted = incoming[node].clone();
+        sorted.sort_by(|a, b| y[links[*a].source].total_cmp(&y[links[*b].source]));
+        let mut offset = 0.0;
+        for i in sorted {
+            target_offset[i] = offset;
+            offset += links[i].value.max(0.0) * scale;
+        }
+    }
+
+    let links = links
+        .iter()
+        .enumerate()
+        .map(|(i, link)| {
+            let thickness = link.value.max(0.0) * scale;
+            let source = &nodes[link.source];
+            let target = &nodes[link.target];
+            let x0 = source.x + source.width;
+            let x1 = target.x;
+            let mid = (x0 + x1) / 2.0;
+            let top0 = source.y + source_offset[i];
+            let top1 = target.y + target_offset[i];
+
+            let mut path = PathBuilder::with_capacity(6);
+            path.move_to(Point::new(x0, top0))
+                .cubic_to(
+                    Point::new(mid, top0),
+                    Point::new(mid, top1),
+                    Point::new(x1, top1),
+                )
+                .line_to(Point::new(x1, top1 + thickness))
+                .cubic_to(
+                    Point::new(mid, top1 + thickness),
+                    Point::new(mid, top0 + thickness),
+                    Point::new(x0, top0 + thickness),
+                );
+            path.close();
+
+            SankeyLinkLayout {
+                source: link.source,
+                target: link.target,
+                value: link.value,
+                thickness,
+                path: path.build(),
+            }
+        })
+        .collect();
+
+    Ok(SankeyLayout {
+        nodes,
+        links,
+        columns,
+    })
+}
+
+/// Orders the nodes so every link goes forward with Kahn's algorithm, failing when some nodes
+/// are never freed from their incoming links because of a cycle.
+fn topological_order(
+    node_count: usize,
+    links: &[SankeyLink],
+    incoming: &[Vec<usize>],
+    outgoing: &[Vec<usize>],
+) -> Result<Vec<usize>, SankeyError> {
+    let mut pending = incoming.iter().map(Vec::len).collect::<Vec<usize>>();
+    let mut order = (0..node_count)
+        .filter(|node| pending[*node] == 0)
+        .collect::<Vec<usize>>();
+    let mut next = 0;
+
+    while next < order.len() {
+        let node = order[next];
+        next += 1;
+
+        for &i in outgoing[node].iter() {
+            let target = links[i].target;
+            pending[target] -= 1;
+            if pending[target] == 0 {
+                order.push(target);
+            }
+        }
+    }
+
+    if order.len() == node_count {
+        Ok(order)
+    } else {
+        Err(SankeyError::Cycle)
+    }
+}
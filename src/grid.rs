@@ -1,10 +1,132 @@
 use log::debug;
 
 use crate::types::*;
-use crate::utils::magnitude;
+use crate::utils::format::{round_to, NumberFormat, RoundingMode, ValueKind};
+use crate::utils::path::PathBuilder;
+use crate::utils::scale::{
+    linear, nice_step, round_out, round_step, BrokenLinearScale, DurationScale,
+};
+use crate::utils::text::{estimate_text_width, format_scaled, DEFAULT_FONT_SIZE};
 
-const LABEL_OFFSET: f32 = 6.0;
+pub(crate) const LABEL_OFFSET: f32 = 6.0;
+/// The room taken by an axis title along the edge of the viewbox.
+pub(crate) const AXIS_TITLE_ROOM: f32 = DEFAULT_FONT_SIZE + 8.0;
+const LABEL_GAP: f32 = 4.0;
 const TICK_SIZE: f32 = 10.0;
+/// The length of the axis taken by every break of the value axis.
+const BREAK_GAP: f32 = 12.0;
+
+/// The strategy used when the labels of the category axis don't fit side by side.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TickOverflow {
+    /// Render all the labels even if they overlap.
+    Overlap,
+    /// Show only every Nth label, with N picked from the estimated label sizes.
+    AutoSkip,
+    /// Alternate the labels between two rows. Labels on a vertical axis fall back to
+    /// [`TickOverflow::AutoSkip`].
+    Stagger,
+}
+
+/// How the values are mapped along the value axis.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AxisScale {
+    /// Evenly spaced values.
+    #[default]
+    Linear,
+    /// Evenly spaced powers of ten, for values spanning several orders of magnitude. The axis
+    /// spans whole decades, with a tick at every power of ten, or every few of them when there
+    /// are more than the maximum number of ticks.
+    Log10,
+}
+
+/// The round numbers the ticks of a generated linear value axis step by.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TickSteps {
+    /// 1, 1.5, 2, 2.5 or any whole number up to 10 times a power of ten, for as many ticks as
+    /// fit.
+    #[default]
+    Fine,
+    /// Only 1, 2 or 5 times a power of ten, for the roundest tick values.
+    Round,
+}
+
+/// What to do with the zero and negative values that can't be placed on a
+/// [log](AxisScale::Log10) axis.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum NonPositive {
+    /// Place them at the start of the axis.
+    #[default]
+    Clamp,
+    /// Render an error instead of the chart.
+    Error,
+}
+
+/// A line across the plot at a value of the value axis, i.e., a target to reach.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ReferenceLine {
+    /// The value the line is drawn at.
+    pub value: f32,
+    /// A label drawn at the end of the line.
+    pub label: Option<String>,
+    /// An extra `class` for the line group, added to the chart `class_reference_line`.
+    pub class: Option<String>,
+    /// Widen the value axis to the value when it is outside of the data range. Otherwise the
+    /// line is skipped when the value is outside of the axis.
+    pub expand_axis: bool,
+}
+
+/// How the range of the value axis is picked from the data.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DomainMode {
+    /// Widen the data range outwards to round tick values.
+    Nice,
+    /// Span exactly the data range, with the first and last ticks at its bounds.
+    Exact,
+    /// Span exactly from `min` to `max`, whatever the data.
+    Manual {
+        /// The value at the start of the axis.
+        min: f32,
+        /// The value at the end of the axis.
+        max: f32,
+    },
+}
+
+impl DomainMode {
+    /// The range the axis must span before any rounding, given the lowest and highest values of
+    /// the data. An empty data range is widened around its value so that the axis always has a
+    /// length. Returns `None` for a manual range that is empty or inverted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dioxus_charts::charts::line::DomainMode;
+    ///
+    /// assert_eq!(DomainMode::Exact.bounds(2.0, 8.0), Some((2.0, 8.0)));
+    /// assert_eq!(DomainMode::Exact.bounds(5.0, 5.0), Some((4.5, 5.5)));
+    /// assert_eq!(DomainMode::Nice.bounds(0.0, 0.0), Some((-1.0, 1.0)));
+    ///
+    /// let manual = DomainMode::Manual { min: 0.0, max: 50.0 };
+    /// assert_eq!(manual.bounds(-10.0, 80.0), Some((0.0, 50.0)));
+    ///
+    /// let inverted = DomainMode::Manual { min: 50.0, max: 0.0 };
+    /// assert_eq!(inverted.bounds(2.0, 8.0), None);
+    /// ```
+    pub fn bounds(&self, lowest: f32, highest: f32) -> Option<(f32, f32)> {
+        match *self {
+            DomainMode::Manual { min, max } => (min < max).then_some((min, max)),
+            DomainMode::Nice | DomainMode::Exact if lowest < highest => Some((lowest, highest)),
+            DomainMode::Nice | DomainMode::Exact => {
+                let pad = if lowest == 0.0 {
+                    1.0
+                } else {
+                    lowest.abs() / 10.0
+                };
+                Some((lowest - pad, highest + pad))
+            }
+        }
+    }
+}
 
 #[derive(Copy, Clone)]
 pub enum Direction {
@@ -12,17 +134,38 @@ pub enum Direction {
     Vertical,
 }
 
-#[derive(Copy, Clone)]
+/// The grid lines and labels standing out from the others, see [`Axis::emphasis`].
+#[derive(Default)]
+pub(crate) struct Emphasis {
+    /// The lines across the plot at the emphasized values.
+    pub lines: Vec<Rect>,
+    /// The indices of the generated labels at an emphasized value.
+    pub ticks: Vec<usize>,
+    /// The labels of the emphasized values, at the ticks and between them when labelled.
+    pub labels: Vec<(TextData, String)>,
+}
+
+#[derive(Clone)]
 pub(crate) struct Axis {
     view: Rect,
-    step_len: f32,
+    /// The world values are kept in `f64`, and only the view positions are rounded to `f32`, so
+    /// that close values far from zero don't collapse to the same position or tick.
+    step_len: f64,
     steps: i32,
-    world_start: f32,
-    world: f32,
+    world_start: f64,
+    world: f64,
+    /// The world values of a log axis are the logarithms of the values.
+    scale: AxisScale,
     grid_ticks: bool,
     label_interpolation: Option<fn(f32) -> String>,
+    number_format: Option<NumberFormat>,
+    divisor: f32,
+    unit: Option<String>,
     label_size: i32,
     direction: Direction,
+    /// The breaks of the value axis, mapping the values relative to `world_start` to a ratio
+    /// of the axis length.
+    breaks: Option<BrokenLinearScale>,
 }
 
 impl Default for Axis {
@@ -33,10 +176,15 @@ impl Default for Axis {
             steps: 0,
             world_start: 0.0,
             world: 0.0,
+            scale: AxisScale::Linear,
             grid_ticks: false,
             label_interpolation: None,
+            number_format: None,
+            divisor: 1.0,
+            unit: None,
             label_size: 60,
             direction: Direction::Horizontal,
+            breaks: None,
         }
     }
 }
@@ -47,53 +195,202 @@ impl Axis {
     }
 
     pub fn world_to_view(&self, v: f32, start_offset: f32) -> f32 {
+        self.offset_to_view(f64::from(v) - f64::from(start_offset))
+    }
+
+    /// The view position of a world offset from the start of the axis.
+    fn offset_to_view(&self, offset: f64) -> f32 {
         if self.world > 0.0 {
-            match self.direction {
-                Direction::Vertical => v / self.world * self.view.width() + self.view.min.x,
-                Direction::Horizontal => {
-                    let c = (v - start_offset) / self.world * self.view.height() + self.view.min.y;
-                    self.view.min.y - c + self.view.max.y
-                }
-            }
+            let (start, end) = match self.direction {
+                Direction::Vertical => (self.view.min.x, self.view.max.x),
+                Direction::Horizontal => (self.view.max.y, self.view.min.y),
+            };
+            let range = (f64::from(start), f64::from(end));
+
+            let view = match &self.breaks {
+                Some(scale) => linear(f64::from(scale.map(offset as f32)), (0.0, 1.0), range),
+                None => linear(offset, (0.0, self.world), range),
+            };
+
+            view as f32
         } else {
             0.0
         }
     }
 
-    pub fn step_to_world(&self, v: f32) -> f32 {
-        self.world / (self.steps as f32 - 1.0) * v
+    /// Whether the tick at step `i` falls inside a break of the axis, and must be skipped.
+    fn tick_in_break(&self, i: i32) -> bool {
+        self.breaks
+            .as_ref()
+            .is_some_and(|scale| scale.in_break(self.step_to_world(i.into()) as f32))
     }
 
-    pub fn lines(&self) -> Vec<Rect> {
-        let mut lines = Vec::<Rect>::new();
+    /// The view positions along the axis of the middle of every break.
+    pub fn break_positions(&self) -> Vec<f32> {
+        self.breaks
+            .iter()
+            .flat_map(|scale| scale.breaks())
+            .map(|(a, b)| self.offset_to_view(f64::from((a + b) / 2.0)))
+            .collect()
+    }
 
-        for i in 0..self.steps {
-            let w = self.step_to_world(i as f32);
-            let v = self.world_to_view(w as f32, 0.0);
+    /// The span of world values along the whole axis.
+    pub fn world(&self) -> f32 {
+        self.world as f32
+    }
 
-            match self.direction {
-                Direction::Vertical => {
-                    let end = if self.grid_ticks && i != 0 {
-                        self.view.max.y - TICK_SIZE
-                    } else {
-                        self.view.min.y
-                    };
+    /// The number of steps between the first and the last tick, i.e., the span of the point
+    /// indices along a label axis, where `world` also counts the room after the last label.
+    pub fn step_span(&self) -> f32 {
+        (self.steps - 1).max(0) as f32
+    }
 
-                    lines.push(Rect::new(v, self.view.max.y, v, end));
-                }
-                Direction::Horizontal => {
-                    let end = if self.grid_ticks && i != 0 {
-                        self.view.min.x + TICK_SIZE
-                    } else {
-                        self.view.max.x
-                    };
+    /// The view position of a value on a value axis, counted from the start of the axis. Values
+    /// outside of the axis range are clamped to its ends, to keep them inside the plot.
+    pub fn value_to_view(&self, v: impl Into<f64>) -> f32 {
+        let v = self
+            .value_to_world(v.into())
+            .min(self.world_start + self.world);
+        self.offset_to_view(v.max(self.world_start) - self.world_start)
+    }
 
-                    lines.push(Rect::new(self.view.min.x, v, end, v));
-                }
+    /// The value moved inside the range of the axis.
+    pub fn clamp_value(&self, v: f32) -> f32 {
+        let v = self
+            .value_to_world(v.into())
+            .min(self.world_start + self.world)
+            .max(self.world_start);
+
+        self.world_to_value(v) as f32
+    }
+
+    /// The line across the plot at the value, or `None` when the value is outside of the axis.
+    pub fn cross_line(&self, v: f32) -> Option<Rect> {
+        if self.clamp_value(v) != v {
+            return None;
+        }
+
+        let position = self.value_to_view(v);
+
+        Some(match self.direction {
+            Direction::Vertical => Rect::new(position, self.view.min.y, position, self.view.max.y),
+            Direction::Horizontal => {
+                Rect::new(self.view.min.x, position, self.view.max.x, position)
             }
+        })
+    }
+
+    /// The band across the plot between the two values, cut at the ends of the axis, or `None`
+    /// when it is entirely outside of it.
+    pub fn cross_band(&self, a: f32, b: f32) -> Option<Rect> {
+        let (low, high) = (a.min(b), a.max(b));
+
+        if self.clamp_value(low) > high || self.clamp_value(high) < low {
+            return None;
+        }
+
+        let (start, end) = (self.value_to_view(low), self.value_to_view(high));
+        let (min, max) = (start.min(end), start.max(end));
+
+        Some(match self.direction {
+            Direction::Vertical => Rect::new(min, self.view.min.y, max, self.view.max.y),
+            Direction::Horizontal => Rect::new(self.view.min.x, min, self.view.max.x, max),
+        })
+    }
+
+    /// Adds to the path an error whisker from `low` to `high` along a value axis, at `across` on
+    /// the other axis, with caps `cap` wide at its ends. The whisker is cut at the ends of the
+    /// axis, without a cap there, and skipped when it is entirely outside of it.
+    pub fn whisker(&self, path: &mut PathBuilder, across: f32, low: f32, high: f32, cap: f32) {
+        let (low, high) = (low.min(high), low.max(high));
+
+        if self.clamp_value(low) > high || self.clamp_value(high) < low {
+            return;
         }
 
-        lines
+        let point = |along: f32, across: f32| match self.direction {
+            Direction::Vertical => Point::new(along, across),
+            Direction::Horizontal => Point::new(across, along),
+        };
+        let (start, end) = (self.value_to_view(low), self.value_to_view(high));
+        path.move_to(point(start, across))
+            .line_to(point(end, across));
+
+        for (value, position) in [(low, start), (high, end)] {
+            if self.clamp_value(value) == value {
+                path.move_to(point(position, across - cap / 2.0))
+                    .line_to(point(position, across + cap / 2.0));
+            }
+        }
+    }
+
+    /// The world value of a value, with the values that can't be placed on a log axis at its
+    /// start.
+    fn value_to_world(&self, v: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => v,
+            AxisScale::Log10 if v > 0.0 => v.log10(),
+            AxisScale::Log10 => self.world_start,
+        }
+    }
+
+    fn world_to_value(&self, w: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => w,
+            AxisScale::Log10 => 10_f64.powf(w),
+        }
+    }
+
+    /// The world offset of step `v` from the start of the axis.
+    fn step_to_world(&self, v: f64) -> f64 {
+        self.world / (f64::from(self.steps) - 1.0) * v
+    }
+
+    pub fn lines(&self) -> Vec<Rect> {
+        (0..self.steps)
+            .filter(|i| !self.tick_in_break(*i))
+            .map(|i| self.line_at(self.step_to_world(i.into()), i == 0))
+            .collect()
+    }
+
+    /// The grid lines between the ticks of a log axis, at 2 to 9 times every power of ten. Other
+    /// axes have none.
+    pub fn minor_lines(&self) -> Vec<Rect> {
+        if self.scale != AxisScale::Log10 || self.step_len != 1.0 {
+            return Vec::new();
+        }
+
+        (0..self.steps - 1)
+            .flat_map(|i| (2..10).map(move |k| f64::from(i) + f64::from(k).log10()))
+            .map(|w| self.line_at(w, false))
+            .collect()
+    }
+
+    /// The grid line at a world offset, shortened to a tick with `grid_ticks` unless it is the
+    /// `first` line along the axis.
+    fn line_at(&self, offset: f64, first: bool) -> Rect {
+        let v = self.offset_to_view(offset);
+
+        match self.direction {
+            Direction::Vertical => {
+                let end = if self.grid_ticks && !first {
+                    self.view.max.y - TICK_SIZE
+                } else {
+                    self.view.min.y
+                };
+
+                Rect::new(v, self.view.max.y, v, end)
+            }
+            Direction::Horizontal => {
+                let end = if self.grid_ticks && !first {
+                    self.view.min.x + TICK_SIZE
+                } else {
+                    self.view.max.x
+                };
+
+                Rect::new(self.view.min.x, v, end, v)
+            }
+        }
     }
 
     pub fn tick_centers(&self) -> Vec<Point> {
@@ -101,10 +398,8 @@ impl Axis {
 
         for i in 1..self.steps {
             let p = i - 1;
-            let w1 = self.step_to_world(p as f32);
-            let w2 = self.step_to_world(i as f32);
-            let v1 = self.world_to_view(w1 as f32, 0.0);
-            let v2 = self.world_to_view(w2 as f32, 0.0);
+            let v1 = self.offset_to_view(self.step_to_world(p.into()));
+            let v2 = self.offset_to_view(self.step_to_world(i.into()));
             let center = (v1 + v2) / 2.0;
 
             match self.direction {
@@ -130,10 +425,8 @@ impl Axis {
 
         for i in 1..n_labels {
             let p = i - 1;
-            let w1 = self.step_to_world(p as f32);
-            let w2 = self.step_to_world(i as f32);
-            let v1 = self.world_to_view(w1 as f32, 0.0);
-            let v2 = self.world_to_view(w2 as f32, 0.0);
+            let v1 = self.offset_to_view(self.step_to_world(p.into()));
+            let v2 = self.offset_to_view(self.step_to_world(i.into()));
 
             match self.direction {
                 Direction::Vertical => {
@@ -157,65 +450,255 @@ impl Axis {
         texts
     }
 
+    /// The label rects of categories laid out in `(start, width)` spans along the axis instead of
+    /// evenly, given in category order and returned in the same order as `centered_text_rects`.
+    pub fn span_text_rects(&self, spans: &[(f32, f32)]) -> Vec<Rect> {
+        match self.direction {
+            Direction::Vertical => spans
+                .iter()
+                .map(|(start, width)| {
+                    Rect::new(
+                        *start,
+                        self.view.max.y + LABEL_OFFSET,
+                        *width,
+                        self.label_size as f32,
+                    )
+                })
+                .collect(),
+            Direction::Horizontal => spans
+                .iter()
+                .rev()
+                .map(|(start, height)| {
+                    let width = self.label_size as f32;
+                    Rect::new(
+                        self.view.min.x - LABEL_OFFSET - width,
+                        *start,
+                        width,
+                        *height,
+                    )
+                })
+                .collect(),
+        }
+    }
+
     pub fn text_data(&self, n_labels: usize) -> Vec<TextData> {
         let mut texts = Vec::<TextData>::new();
-        let n_labels = self.steps.min(n_labels as i32);
+        let ticks = (0..self.steps)
+            .filter(|i| !self.tick_in_break(*i))
+            .take(n_labels);
 
-        for i in 0..n_labels {
-            let w = self.step_to_world(i as f32);
-            let v = self.world_to_view(w as f32, 0.0);
+        for i in ticks {
+            texts.push(self.text_at(self.offset_to_view(self.step_to_world(i.into()))));
+        }
 
-            match self.direction {
-                Direction::Vertical => {
-                    texts.push(TextData {
-                        x: v,
-                        y: self.view.max.y + LABEL_OFFSET,
-                        anchor: "start",
-                        baseline: "hanging",
-                    });
+        texts
+    }
+
+    fn text_at(&self, v: f32) -> TextData {
+        match self.direction {
+            Direction::Vertical => TextData {
+                x: v,
+                y: self.view.max.y + LABEL_OFFSET,
+                anchor: "start",
+                baseline: "hanging",
+            },
+            Direction::Horizontal => TextData {
+                x: self.view.min.x - LABEL_OFFSET,
+                y: v,
+                anchor: "end",
+                baseline: "text-bottom",
+            },
+        }
+    }
+
+    /// The emphasized grid lines and labels at `values`, or at zero when they are not set and
+    /// the axis crosses it. Values outside of the axis are skipped, and the ones between the
+    /// ticks only get a label with `label_between`.
+    pub fn emphasis(&self, values: Option<&[f32]>, label_between: bool) -> Emphasis {
+        let end = self.world_start + self.world;
+        let values = match values {
+            Some(values) => values.to_vec(),
+            None if self.scale == AxisScale::Linear && self.world_start < 0.0 && end > 0.0 => {
+                vec![0.0]
+            }
+            None => Vec::new(),
+        };
+        let mut emphasis = Emphasis::default();
+
+        let in_break = |w: f64| {
+            self.breaks
+                .as_ref()
+                .is_some_and(|scale| scale.in_break((w - self.world_start) as f32))
+        };
+
+        for (value, w) in values
+            .into_iter()
+            // Zero and the negative values have no place on a log axis
+            .filter(|v| self.scale == AxisScale::Linear || *v > 0.0)
+            .map(|v| (v, self.value_to_world(v.into())))
+            .filter(|(_, w)| (self.world_start..=end).contains(w) && !in_break(*w))
+        {
+            let v = self.offset_to_view(w - self.world_start);
+            let step = ((w - self.world_start) / self.step_len).round();
+            let tick_world = self.world_start + step * self.step_len;
+            // A value within float noise of a tick is the tick
+            let tick = (self.step_len > 0.0
+                && (step as i32) < self.steps
+                && (tick_world - w).abs() < self.step_len * 1e-3)
+                .then_some(step as usize);
+
+            emphasis.lines.push(match self.direction {
+                Direction::Vertical => Rect::new(v, self.view.max.y, v, self.view.min.y),
+                Direction::Horizontal => Rect::new(self.view.min.x, v, self.view.max.x, v),
+            });
+
+            match tick {
+                Some(step) => {
+                    // The index among the labels left by the breaks
+                    let i = (0..step as i32).filter(|i| !self.tick_in_break(*i)).count();
+                    emphasis.ticks.push(i);
+                    emphasis.labels.push((
+                        self.text_at(v),
+                        self.format_label(self.world_to_value(tick_world)),
+                    ));
                 }
-                Direction::Horizontal => {
-                    texts.push(TextData {
-                        x: self.view.min.x - LABEL_OFFSET,
-                        y: v,
-                        anchor: "end",
-                        baseline: "text-bottom",
-                    });
+                None if label_between => {
+                    emphasis
+                        .labels
+                        .push((self.text_at(v), self.format_label(f64::from(value))));
                 }
+                None => {}
             }
         }
 
-        texts
+        emphasis
+    }
+
+    pub fn slot_len(&self) -> f32 {
+        if self.steps < 2 {
+            return 0.0;
+        }
+
+        match self.direction {
+            Direction::Vertical => self.view.width() / (self.steps as f32 - 1.0),
+            Direction::Horizontal => self.view.height() / (self.steps as f32 - 1.0),
+        }
+    }
+
+    /// Returns for every label either `None` if it should be hidden, or the offset away from
+    /// the axis it should be drawn with.
+    ///
+    /// A `step` shows every Nth label whatever the `overflow`. The labels of a horizontal axis
+    /// are rotated by `rotation` degrees, which changes the room they take along it. The first
+    /// and the last labels are always shown when skipping.
+    pub fn label_overflow<S: AsRef<str>>(
+        &self,
+        labels: &[S],
+        overflow: TickOverflow,
+        step: Option<usize>,
+        rotation: f32,
+    ) -> Vec<Option<f32>> {
+        let slot = self.slot_len();
+        let widest = match self.direction {
+            Direction::Vertical => labels
+                .iter()
+                .map(|l| rotated_label_size(l.as_ref(), rotation).0)
+                .fold(0.0, f32::max),
+            Direction::Horizontal => DEFAULT_FONT_SIZE,
+        };
+
+        let factor = match step {
+            Some(step) => step.max(1),
+            None if slot > 0.0 => ((widest + LABEL_GAP) / slot).ceil().max(1.0) as usize,
+            None => 1,
+        };
+
+        let overflow = match (overflow, self.direction) {
+            _ if step.is_some() => TickOverflow::AutoSkip,
+            (TickOverflow::Stagger, Direction::Horizontal) => TickOverflow::AutoSkip,
+            (overflow, _) => overflow,
+        };
+        let last = labels.len().saturating_sub(1);
+
+        labels
+            .iter()
+            .enumerate()
+            .map(|(i, _)| match overflow {
+                TickOverflow::Overlap => Some(0.0),
+                // The label before the last one makes room for it
+                TickOverflow::AutoSkip => {
+                    (i == last || i % factor == 0 && (i == 0 || last - i >= factor)).then_some(0.0)
+                }
+                TickOverflow::Stagger if factor > 1 && i % 2 == 1 => {
+                    Some(DEFAULT_FONT_SIZE + LABEL_GAP)
+                }
+                TickOverflow::Stagger => Some(0.0),
+            })
+            .collect()
     }
 
     pub fn generated_labels(&self) -> Labels {
         let mut labels = Labels::new();
 
-        for i in 0..=self.steps {
-            if let Some(func) = self.label_interpolation {
-                labels.push(func(self.world_start + i as f32 * self.step_len));
-            } else {
-                labels.push(format!("{}", self.world_start + i as f32 * self.step_len));
-            }
+        for i in (0..=self.steps).filter(|i| !self.tick_in_break(*i)) {
+            labels.push(self.format_label(
+                self.world_to_value(self.world_start + f64::from(i) * self.step_len),
+            ));
         }
 
         labels
     }
+
+    /// The label of a tick value, rounded a few digits past the step to drop only the noise of
+    /// the float sums.
+    fn format_label(&self, value: f64) -> String {
+        let divisor = f64::from(self.divisor);
+        let value = value / divisor;
+        // The ticks of a log axis are each their own step
+        let step = match self.scale {
+            AxisScale::Log10 => value,
+            AxisScale::Linear => self.step_len / divisor,
+        };
+        let decimals = if step.is_normal() {
+            (3 - step.log10().floor() as i32).max(0) as usize
+        } else {
+            0
+        };
+
+        format_scaled(
+            round_to(value, decimals, RoundingMode::HalfUp),
+            self.label_interpolation,
+            self.number_format.as_ref(),
+            1.0,
+            self.unit.as_deref(),
+        )
+    }
 }
 
+#[derive(Clone)]
 pub(crate) struct AxisBuilder<'a> {
     view: Rect,
     lowest: Option<f32>,
     highest: Option<f32>,
     direction: Direction,
     label_interpolation: Option<fn(f32) -> String>,
+    number_format: Option<&'a NumberFormat>,
+    divisor: f32,
+    unit: Option<&'a str>,
     labels_centered: bool,
     label_size: i32,
     grid_ticks: bool,
     max_ticks: i32,
+    tick_steps: TickSteps,
     stacked_series: bool,
+    include_zero: bool,
+    included: Vec<f32>,
+    scale: AxisScale,
+    domain: DomainMode,
     series: Option<&'a Series>,
     labels: Option<&'a Labels>,
+    breaks: Option<&'a [(f32, f32)]>,
+    value_kind: ValueKind,
 }
 
 impl<'a> Default for AxisBuilder<'a> {
@@ -226,13 +709,23 @@ impl<'a> Default for AxisBuilder<'a> {
             lowest: None,
             direction: Direction::Horizontal,
             label_interpolation: None,
+            number_format: None,
+            divisor: 1.0,
+            unit: None,
             labels_centered: false,
             label_size: 60,
             grid_ticks: false,
             max_ticks: 8,
+            tick_steps: TickSteps::Fine,
             stacked_series: false,
+            include_zero: false,
+            included: Vec::new(),
+            scale: AxisScale::Linear,
+            domain: DomainMode::Nice,
             series: None,
             labels: None,
+            breaks: None,
+            value_kind: ValueKind::Number,
         }
     }
 }
@@ -258,11 +751,37 @@ impl<'a> AxisBuilder<'a> {
         self
     }
 
+    pub fn with_domain(mut self, domain: DomainMode) -> Self {
+        self.domain = domain;
+        self
+    }
+
     pub fn with_stacked_series(mut self, stacked: bool) -> Self {
         self.stacked_series = stacked;
         self
     }
 
+    /// Extends the range computed from the series to zero, i.e., for bars growing from a zero
+    /// baseline.
+    pub fn with_zero(mut self, include_zero: bool) -> Self {
+        self.include_zero = include_zero;
+        self
+    }
+
+    /// Extends the range computed from the series to the values, unless the bound they would
+    /// move is set.
+    pub fn with_included(mut self, values: Vec<f32>) -> Self {
+        self.included = values;
+        self
+    }
+
+    /// Maps the values along the axis with the scale. A log axis ignores `with_zero`, the value
+    /// kind and the breaks.
+    pub fn with_scale(mut self, scale: AxisScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
     pub fn with_labels(mut self, labels: Option<&'a Labels>) -> Self {
         self.labels = labels;
         self
@@ -278,6 +797,11 @@ impl<'a> AxisBuilder<'a> {
         self
     }
 
+    pub fn with_tick_steps(mut self, steps: TickSteps) -> Self {
+        self.tick_steps = steps;
+        self
+    }
+
     pub fn with_grid_ticks(mut self, show_ticks: bool) -> Self {
         self.grid_ticks = show_ticks;
         self
@@ -299,21 +823,64 @@ impl<'a> AxisBuilder<'a> {
         self
     }
 
+    pub fn with_number_format(mut self, number_format: Option<&'a NumberFormat>) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Generates the ticks and labels for the values divided by `divisor`, i.e., to show bytes
+    /// in MiB, while the axis still maps the original values.
+    pub fn with_divisor(mut self, divisor: Option<f32>) -> Self {
+        self.divisor = divisor.filter(|d| *d > 0.0).unwrap_or(1.0);
+        self
+    }
+
+    pub fn with_unit(mut self, unit: Option<&'a str>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Compresses every range of values to a small fixed gap on the axis, skipping its ticks.
+    pub fn with_breaks(mut self, breaks: Option<&'a [(f32, f32)]>) -> Self {
+        self.breaks = breaks.filter(|breaks| !breaks.is_empty());
+        self
+    }
+
+    /// Steps the ticks at round values for the kind of values, i.e., round durations.
+    pub fn with_value_kind(mut self, value_kind: ValueKind) -> Self {
+        self.value_kind = value_kind;
+        self
+    }
+
     pub fn build(self) -> Axis {
-        if let Some(series) = self.series {
+        if let (Some(series), AxisScale::Log10) = (self.series, self.scale) {
+            self.build_log(series)
+        } else if let Some(series) = self.series {
+            // The stacks of every category, with the positive values stacked up from zero and
+            // the negative ones down, and the shorter series padded with zeros. The non-finite values
+            // are gaps and stay out of the range
+            let categories = series.iter().map(Vec::len).max().unwrap_or(0);
+            let stack = |positive: bool| {
+                (0..categories).map(move |j| {
+                    series
+                        .iter()
+                        .filter_map(|a| a.get(j))
+                        .filter(|v| v.is_finite() && (**v > 0.0) == positive)
+                        .map(|v| f64::from(*v))
+                        .sum::<f64>() as f32
+                })
+            };
+
             let highest = if let Some(high) = self.highest {
                 high
             } else if self.stacked_series {
-                MultiZip(series.iter().map(|a| a.iter().copied()).collect())
-                    .map(|t| t.iter().sum())
-                    .reduce(f32::max)
-                    .unwrap()
+                stack(true).reduce(f32::max).unwrap()
             } else {
                 series
                     .iter()
-                    .map(|a| a.iter().copied().reduce(f32::max).unwrap())
+                    .filter_map(|a| a.iter().copied().filter(|v| v.is_finite()).reduce(f32::max))
                     .reduce(f32::max)
-                    .unwrap()
+                    .unwrap_or(0.0)
             };
 
             //if self.stacked_series {
@@ -326,75 +893,125 @@ impl<'a> AxisBuilder<'a> {
 
             let lowest = if let Some(low) = self.lowest {
                 low
+            } else if self.stacked_series {
+                stack(false).reduce(f32::min).unwrap()
             } else {
                 series
                     .iter()
-                    .map(|a| a.iter().copied().reduce(f32::min).unwrap())
+                    .filter_map(|a| a.iter().copied().filter(|v| v.is_finite()).reduce(f32::min))
                     .reduce(f32::min)
-                    .unwrap()
+                    .unwrap_or(0.0)
+            };
+
+            let lowest = self
+                .included
+                .iter()
+                .copied()
+                .fold(lowest, |low, v| self.lowest.map_or(low.min(v), |_| low));
+            let highest = self.included.iter().copied().fold(highest, |high, v| {
+                self.highest.map_or(high.max(v), |_| high)
+            });
+
+            // With zero included, an axis of only zeros goes up from it
+            let (lowest, highest) = if self.include_zero {
+                let lowest = self.lowest.unwrap_or(lowest.min(0.0));
+                let highest = self.highest.unwrap_or(highest.max(0.0));
+
+                if lowest == 0.0 && highest == 0.0 {
+                    (0.0, 1.0)
+                } else {
+                    (lowest, highest)
+                }
+            } else {
+                (lowest, highest)
             };
 
+            let (lowest, highest) = self.domain.bounds(lowest, highest).unwrap_or((0.0, 1.0));
+            let divisor = f64::from(self.divisor);
+            let (lowest, highest) = (f64::from(lowest) / divisor, f64::from(highest) / divisor);
+            let exact = self.domain != DomainMode::Nice;
+
             debug!("highest: {}", highest);
             debug!("lowest: {}", lowest);
             let value_range = highest - lowest;
-            let minimum_tick = value_range / (self.max_ticks as f32 - 2.0);
-            let magnitude = magnitude(minimum_tick);
-            let residual = minimum_tick / magnitude;
-            debug!("magnitude: {}", magnitude);
-
-            let step = match residual {
-                n if n > 9.0 => 10.0,
-                n if n > 8.0 => 9.0,
-                n if n > 7.0 => 8.0,
-                n if n > 6.0 => 7.0,
-                n if n > 5.0 => 6.0,
-                n if n > 4.0 => 5.0,
-                n if n > 3.0 => 4.0,
-                n if n > 2.5 => 3.0,
-                n if n > 2.0 => 2.5,
-                n if n > 1.5 => 2.0,
-                n if n > 1.0 => 1.5,
-                _ => 1.0,
-            } * magnitude;
+
+            let step = if self.value_kind == ValueKind::DurationSeconds {
+                DurationScale {
+                    max_ticks: self.max_ticks,
+                }
+                .step(value_range as f32)
+                .into()
+            } else {
+                match self.tick_steps {
+                    TickSteps::Fine => nice_step(value_range, self.max_ticks),
+                    TickSteps::Round => round_step(value_range / (f64::from(self.max_ticks) - 2.0)),
+                }
+            };
 
             debug!("step_len: {}", step);
 
-            let max = if self.highest.is_some() {
+            let (nice_min, nice_max) = round_out(lowest, highest, step);
+            let max = if exact || self.highest.is_some() {
                 highest
             } else {
-                let max = (highest / step).ceil() * step;
-                if max < highest {
-                    debug!("step added to max");
-                    max + step
-                } else {
-                    max
-                }
+                nice_max
             };
-
-            let min = if self.lowest.is_some() {
+            let min = if exact || self.lowest.is_some() {
                 lowest
             } else {
-                let min = (lowest / step).floor() * step;
-                if min > lowest {
-                    debug!("step added to min");
-                    min - step
-                } else {
-                    min
-                }
+                nice_min
             };
 
             let range = max - min;
             debug!("range: {} min: {}, max: {}", range, min, max);
-            let steps = unsafe { (range / step).round().to_int_unchecked::<i32>() + 1 };
+            let steps = unsafe { (range / step).round().max(1.0).to_int_unchecked::<i32>() + 1 };
             debug!("steps: {}", steps);
 
+            // The ticks are evenly spaced over the range, so a range that isn't a whole number of
+            // steps gets slightly uneven tick values instead of ticks outside of it.
+            let step = if exact {
+                range / f64::from(steps - 1)
+            } else {
+                step
+            };
+
+            let world_start = min * divisor;
+            let world = range * divisor;
+            let breaks = self.breaks.map(|breaks| {
+                let length = match self.direction {
+                    Direction::Vertical => self.view.width(),
+                    Direction::Horizontal => self.view.height(),
+                };
+                let relative = breaks
+                    .iter()
+                    .map(|(a, b)| {
+                        (
+                            (f64::from(*a) - world_start) as f32,
+                            (f64::from(*b) - world_start) as f32,
+                        )
+                    })
+                    .collect::<Vec<(f32, f32)>>();
+
+                BrokenLinearScale::new(
+                    (0.0, world as f32),
+                    (0.0, 1.0),
+                    &relative,
+                    BREAK_GAP / length,
+                )
+            });
+
             Axis {
                 view: self.view,
-                step_len: step,
+                step_len: step * divisor,
                 steps,
-                world_start: min,
-                world: range,
+                world_start,
+                world,
+                scale: AxisScale::Linear,
+                breaks,
                 label_interpolation: self.label_interpolation,
+                number_format: self.number_format.cloned(),
+                divisor: self.divisor,
+                unit: self.unit.map(str::to_owned),
                 grid_ticks: self.grid_ticks,
                 label_size: self.label_size,
                 direction: self.direction,
@@ -409,9 +1026,9 @@ impl<'a> AxisBuilder<'a> {
 
             Axis {
                 view: self.view,
-                step_len: steps as f32 / (steps as f32 - 1.0),
+                step_len: f64::from(steps) / (f64::from(steps) - 1.0),
                 steps,
-                world: steps as f32,
+                world: f64::from(steps),
                 grid_ticks: self.grid_ticks,
                 label_size: self.label_size,
                 direction: self.direction,
@@ -421,6 +1038,78 @@ impl<'a> AxisBuilder<'a> {
             Axis::default()
         }
     }
+
+    /// The log axis over the positive values of the series, spanning whole decades.
+    fn build_log(self, series: &Series) -> Axis {
+        let (lowest, highest) = match self.domain {
+            DomainMode::Manual { min, max } => (Some(min), Some(max)),
+            DomainMode::Nice | DomainMode::Exact => (self.lowest, self.highest),
+        };
+
+        let categories = series.iter().map(Vec::len).max().unwrap_or(0);
+        let positive = || {
+            series
+                .iter()
+                .flatten()
+                .chain(self.included.iter())
+                .filter(|v| v.is_finite() && **v > 0.0)
+                .map(|v| f64::from(*v))
+        };
+        let data_highest = if self.stacked_series {
+            (0..categories)
+                .map(|j| {
+                    series
+                        .iter()
+                        .filter_map(|a| a.get(j))
+                        .filter(|v| v.is_finite() && **v > 0.0)
+                        .map(|v| f64::from(*v))
+                        .sum::<f64>()
+                })
+                .chain(self.included.iter().map(|v| f64::from(*v)))
+                .reduce(f64::max)
+                .filter(|v| *v > 0.0)
+        } else {
+            positive().reduce(f64::max)
+        };
+
+        let lowest = lowest
+            .map(f64::from)
+            .filter(|v| *v > 0.0)
+            .or_else(|| positive().reduce(f64::min))
+            .unwrap_or(1.0);
+        let highest = highest
+            .map(f64::from)
+            .filter(|v| *v > 0.0)
+            .or(data_highest)
+            .unwrap_or(lowest * 10.0);
+        debug!("log lowest: {} highest: {}", lowest, highest);
+
+        // Every tick is a power of ten, or every few of them to stay under `max_ticks`
+        let first = lowest.log10().floor();
+        let last = highest.log10().ceil().max(first + 1.0);
+        let step = ((last - first) / f64::from(self.max_ticks.max(2) - 1))
+            .ceil()
+            .max(1.0);
+        let first = (first / step).floor() * step;
+        let steps = ((last - first) / step).ceil().max(1.0);
+
+        Axis {
+            view: self.view,
+            step_len: step,
+            steps: steps as i32 + 1,
+            world_start: first,
+            world: steps * step,
+            scale: AxisScale::Log10,
+            label_interpolation: self.label_interpolation,
+            number_format: self.number_format.cloned(),
+            divisor: self.divisor,
+            unit: self.unit.map(str::to_owned),
+            grid_ticks: self.grid_ticks,
+            label_size: self.label_size,
+            direction: self.direction,
+            breaks: None,
+        }
+    }
 }
 
 pub(crate) struct Grid {
@@ -436,29 +1125,239 @@ impl Grid {
         }
     }
 
-    pub fn world_to_view(&self, cx: f32, cy: f32, inverted: bool) -> Point {
+    pub fn world_to_view(&self, cx: impl Into<f64>, cy: impl Into<f64>, inverted: bool) -> Point {
         if inverted {
             Point {
-                x: self.x.world_to_view(cx, self.x.world_start),
-                y: self.y.world_to_view(self.y.step_to_world(cy), 0.0),
+                x: self.x.value_to_view(cx),
+                y: self.y.offset_to_view(self.y.step_to_world(cy.into())),
             }
         } else {
             Point {
-                x: self.x.world_to_view(self.x.step_to_world(cx), 0.0),
-                y: self.y.world_to_view(cy, self.y.world_start),
+                x: self.x.offset_to_view(self.x.step_to_world(cx.into())),
+                y: self.y.value_to_view(cy),
             }
         }
     }
 
-    pub fn lines(&self) -> Vec<Rect> {
-        [self.x.lines().as_slice(), self.y.lines().as_slice()].concat()
+    /// The grid lines at the ticks of the horizontal axis when `along_x` is set, followed by the
+    /// ones at the ticks of the vertical axis when `along_y` is set.
+    pub fn lines(&self, along_x: bool, along_y: bool) -> Vec<Rect> {
+        let x = if along_x { self.x.lines() } else { Vec::new() };
+        let y = if along_y { self.y.lines() } else { Vec::new() };
+
+        [x, y].concat()
+    }
+}
+
+/// The values of the reference lines that widen the value axis.
+pub(crate) fn reference_values(lines: Option<&[ReferenceLine]>) -> Vec<f32> {
+    lines
+        .iter()
+        .copied()
+        .flatten()
+        .filter(|line| line.expand_axis)
+        .map(|line| line.value)
+        .collect()
+}
+
+/// The reference lines inside of the value axis, with the position of their label at the end of
+/// the line: over it and right-aligned for horizontal lines, right of its top for vertical ones.
+pub(crate) fn reference_lines<'a>(
+    axis: &Axis,
+    lines: Option<&'a [ReferenceLine]>,
+) -> Vec<(Rect, TextData, &'a ReferenceLine)> {
+    lines
+        .iter()
+        .copied()
+        .flatten()
+        .filter_map(|reference| {
+            let line = axis.cross_line(reference.value)?;
+            let text = match axis.direction {
+                Direction::Horizontal => TextData {
+                    x: line.max.x,
+                    y: line.min.y - LABEL_GAP,
+                    anchor: "end",
+                    baseline: "text-bottom",
+                },
+                Direction::Vertical => TextData {
+                    x: line.min.x + LABEL_GAP,
+                    y: line.min.y,
+                    anchor: "start",
+                    baseline: "hanging",
+                },
+            };
+
+            Some((line, text, reference))
+        })
+        .collect()
+}
+
+/// The estimated room taken by a label rotated by `rotation` degrees, along a horizontal axis
+/// when it has rotated neighbours and below it.
+fn rotated_label_size(label: &str, rotation: f32) -> (f32, f32) {
+    let width = label
+        .lines()
+        .map(|line| estimate_text_width(line, DEFAULT_FONT_SIZE))
+        .fold(0.0, f32::max);
+    let height = label.lines().count().max(1) as f32 * DEFAULT_FONT_SIZE;
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+
+    if sin < f32::EPSILON {
+        return (width, height);
     }
 
-    pub fn text_data(&self, x_n_labels: Option<usize>, y_n_labels: Option<usize>) -> Vec<TextData> {
-        [
-            self.x.text_data(x_n_labels.unwrap_or(0)).as_slice(),
-            self.y.text_data(y_n_labels.unwrap_or(0)).as_slice(),
-        ]
-        .concat()
+    // Parallel rotated labels only need their height apart, across their direction
+    (
+        (width * cos + height * sin).min(height / sin),
+        width * sin + height * cos,
+    )
+}
+
+/// The room taken below a horizontal axis by its labels rotated by `rotation` degrees.
+pub(crate) fn rotated_labels_height<S: AsRef<str>>(labels: &[S], rotation: f32) -> f32 {
+    labels
+        .iter()
+        .map(|label| rotated_label_size(label.as_ref(), rotation).1)
+        .fold(0.0, f32::max)
+}
+
+/// The centers of the titles of the horizontal and the vertical axes, along the plot `view` in
+/// the room left for them at the bottom and at the left of the viewbox.
+pub(crate) fn axis_title_centers(view: &Rect, viewbox_height: f32) -> (Point, Point) {
+    (
+        Point::new(
+            (view.min.x + view.max.x) / 2.0,
+            viewbox_height - AXIS_TITLE_ROOM / 2.0,
+        ),
+        Point::new(AXIS_TITLE_ROOM / 2.0, (view.min.y + view.max.y) / 2.0),
+    )
+}
+
+/// The two parallel slashes marking a break of the value axis, centered on `center` and `size`
+/// long across the axis, returned as the path of the gap between the slashes and the path of the
+/// slashes themselves. `along_x` is set when the value axis is horizontal.
+pub(crate) fn break_glyph(center: Point, size: f32, along_x: bool) -> (String, String) {
+    let half_gap = BREAK_GAP / 4.0;
+    let slant = BREAK_GAP / 4.0;
+    let point = |along: f32, across: f32| {
+        if along_x {
+            Point::new(center.x + along, center.y + across)
+        } else {
+            Point::new(center.x + across, center.y + along)
+        }
+    };
+    let slash = |along: f32| {
+        (
+            point(along + slant, -size / 2.0),
+            point(along - slant, size / 2.0),
+        )
+    };
+    let (a, b) = slash(-half_gap);
+    let (c, d) = slash(half_gap);
+
+    let mut gap = PathBuilder::with_capacity(4);
+    gap.move_to(a).line_to(b).line_to(d).line_to(c);
+    gap.close();
+
+    let mut slashes = PathBuilder::with_capacity(4);
+    slashes.move_to(a).line_to(b).move_to(c).line_to(d);
+
+    (gap.build(), slashes.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The tick labels drawn along a value axis of the series, as the charts draw them.
+    fn drawn_labels(series: &Series, steps: TickSteps) -> Labels {
+        let axis = Axis::builder()
+            .with_view(Rect::new(40.0, 10.0, 560.0, 390.0))
+            .with_series(series)
+            .with_max_ticks(8)
+            .with_tick_steps(steps)
+            .with_direction(Direction::Horizontal)
+            .build();
+        let labels = axis.generated_labels();
+
+        labels[..axis.text_data(labels.len()).len()].to_vec()
+    }
+
+    #[test]
+    fn small_ranges_get_distinct_round_ticks() {
+        assert_eq!(
+            drawn_labels(&vec![vec![0.001, 0.0025, 0.004]], TickSteps::Fine),
+            ["0.001", "0.0015", "0.002", "0.0025", "0.003", "0.0035", "0.004"]
+        );
+    }
+
+    #[test]
+    fn large_ranges_get_round_ticks() {
+        assert_eq!(
+            drawn_labels(&vec![vec![1_200_000.0, 3_900_000.0]], TickSteps::Fine),
+            ["1000000", "1500000", "2000000", "2500000", "3000000", "3500000", "4000000"]
+        );
+    }
+
+    #[test]
+    fn round_steps_are_one_two_or_five_times_a_power_of_ten() {
+        let series = vec![vec![0.0, 130.0]];
+
+        assert_eq!(
+            drawn_labels(&series, TickSteps::Fine),
+            ["0", "25", "50", "75", "100", "125", "150"]
+        );
+        assert_eq!(
+            drawn_labels(&series, TickSteps::Round),
+            ["0", "50", "100", "150"]
+        );
+        assert_eq!(
+            drawn_labels(&vec![vec![0.0012, 0.0039]], TickSteps::Round),
+            ["0.001", "0.0015", "0.002", "0.0025", "0.003", "0.0035", "0.004"]
+        );
+        assert_eq!(
+            drawn_labels(&vec![vec![1_200_000.0, 4_400_000.0]], TickSteps::Round),
+            ["1000000", "2000000", "3000000", "4000000", "5000000"]
+        );
+    }
+
+    #[test]
+    fn close_large_values_keep_distinct_ordered_positions() {
+        // The f32 props can't tell cents apart at a billion, so this is the axis an exact domain
+        // from 1_000_000_000.00 to 1_000_000_000.10 would build
+        let view = Rect::new(40.0, 10.0, 560.0, 390.0);
+        let y = Axis {
+            view,
+            step_len: 0.1,
+            steps: 2,
+            world_start: 1_000_000_000.0,
+            world: 0.1,
+            ..Axis::default()
+        };
+        let labels = vec!["A".to_string(), "B".to_string()];
+        let grid = Grid {
+            x: Axis::builder()
+                .with_view(view)
+                .with_labels(Some(&labels))
+                .with_direction(Direction::Vertical)
+                .build(),
+            y: y.clone(),
+        };
+
+        let (a, b) = (
+            y.value_to_view(1_000_000_000.01),
+            y.value_to_view(1_000_000_000.02),
+        );
+        // The vertical axis grows upwards, 38 pixels for every cent
+        assert!(a > b, "{a} {b}");
+        assert!((a - b - 38.0).abs() < 0.01, "{a} {b}");
+
+        let (a, b) = (
+            grid.world_to_view(1.0, 1_000_000_000.01, false),
+            grid.world_to_view(1.0, 1_000_000_000.02, false),
+        );
+        assert!(a.y > b.y, "{a} {b}");
+        assert_eq!(a.x, b.x);
     }
 }
@@ -0,0 +1,88 @@
+//! Hooks for wiring the chart events into the state of the parent component.
+
+use dioxus::prelude::*;
+
+/// Keeps the payload of the last chart event, i.e., to show the details of the hovered or
+/// clicked element in a panel next to the chart.
+///
+/// Returns the state, holding `None` until the first event and after a leave event, a handler
+/// setting it, to pass to the chart event props with a matching payload type, i.e.,
+/// `onmouseover_slice`, and a handler clearing it, to pass to the matching leave event props,
+/// i.e., `onmouseout_slice`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_charts::hooks::use_chart_hover;
+/// use dioxus_charts::prelude::SliceEvent;
+/// use dioxus_charts::PieChart;
+///
+/// fn app(cx: Scope) -> Element {
+///     let (hover, on_hover, on_leave) = use_chart_hover::<SliceEvent>(&cx);
+///     let labels = ["Rent", "Food", "Fun"];
+///
+///     cx.render(rsx! {
+///         PieChart {
+///             series: vec![40.0, 35.0, 25.0],
+///             labels: labels.iter().map(|label| label.to_string()).collect(),
+///             onmouseover_slice: on_hover,
+///             onmouseout_slice: on_leave,
+///         }
+///         hover.map(|slice| labels[slice.index]).map(|label| rsx! {
+///             div { class: "detail", "{label}" }
+///         })
+///     })
+/// }
+/// ```
+pub fn use_chart_hover<T: 'static>(
+    cx: &ScopeState,
+) -> (&UseState<Option<T>>, impl Fn(T) + '_, impl Fn(T) + '_) {
+    let hover = use_state(cx, || None);
+
+    (
+        hover,
+        move |event| hover.set(Some(event)),
+        move |_| hover.set(None),
+    )
+}
+
+/// The user's motion preference, shared with every chart below the component providing it with
+/// [`provide_motion_preference`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MotionPreference {
+    /// Whether the user asked for reduced motion, i.e., from the
+    /// `(prefers-reduced-motion: reduce)` media query.
+    pub reduced: bool,
+}
+
+/// Shares the motion preference with every chart below the component, so the charts with
+/// `respect_reduced_motion` set render their final states without animating.
+///
+/// The preference is not queried by the crate, so on the web it is up to the app to pass the
+/// result of `window.matchMedia("(prefers-reduced-motion: reduce)")`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dioxus::prelude::*;
+/// use dioxus_charts::hooks::{provide_motion_preference, MotionPreference};
+///
+/// fn app(cx: Scope<AppProps>) -> Element {
+///     provide_motion_preference(&cx, MotionPreference { reduced: cx.props.reduced_motion });
+///
+///     cx.render(rsx! {
+///         Dashboard {}
+///     })
+/// }
+/// ```
+pub fn provide_motion_preference(cx: &ScopeState, preference: MotionPreference) {
+    cx.provide_context(preference);
+}
+
+/// Whether the charts should skip their animations, following the [`MotionPreference`] provided
+/// by a parent component. Without any, the animations are kept.
+pub fn use_reduced_motion(cx: &ScopeState) -> bool {
+    cx.consume_context::<MotionPreference>()
+        .is_some_and(|preference| preference.reduced)
+}
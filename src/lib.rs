@@ -9,8 +9,12 @@ The components available are:
 
 - [PieChart](crate::charts::PieChart): for Pie, Donut and Gauge charts
 - [BarChart](crate::charts::BarChart): for Bar and Stacked Bar charts, vertical
-or horizontal
+  or horizontal
 - [LineChart](crate::charts::LineChart)
+- [ParetoChart](crate::charts::ParetoChart): for Pareto charts, sorted bars with a cumulative
+  percentage line
+- [SankeyChart](crate::charts::SankeyChart): for Sankey diagrams of flows between nodes
+- [DonutGrid](crate::charts::DonutGrid): for a grid of donuts comparing several metrics
 
 # Usage
 This crate is [on crates.io](https://crates.io/crates/dioxus-charts) and can be
@@ -21,14 +25,19 @@ used by adding `dioxus_charts` to your dependencies in your project's `Cargo.tom
 dioxus_charts = "0.1.0"
 ```
 
+The components and their configuration types can be imported all at once with
+`use dioxus_charts::prelude::*;`.
+
 [Dioxus]: https://dioxuslabs.com/
 */
 
 #![deny(missing_docs)]
 
+pub mod geometry;
 mod grid;
+pub mod hooks;
 mod types;
-mod utils;
+pub mod utils;
 
 pub mod charts {
     //! Chart components
@@ -37,17 +46,80 @@ pub mod charts {
     //! - [PieChart](crate::charts::PieChart)
     //! - [BarChart](crate::charts::BarChart)
     //! - [LineChart](crate::charts::LineChart)
+    //! - [ParetoChart](crate::charts::ParetoChart)
+    //! - [SankeyChart](crate::charts::SankeyChart)
+    //! - [DonutGrid](crate::charts::DonutGrid)
+    //! - [WindRoseChart](crate::charts::WindRoseChart)
+    //! - [CompositionBar](crate::charts::CompositionBar)
 
     /// Module for the [BarChart](pie::PieChart) component and its configuration types
     pub mod bar;
+    /// Module for the [CompositionBar](composition_bar::CompositionBar) component
+    pub mod composition_bar;
+    /// Module for the [DonutGrid](donut_grid::DonutGrid) component and its configuration types
+    pub mod donut_grid;
+    mod empty;
+    mod legend_row;
     /// Module for the [LineChart](pie::PieChart) component and its configuration types
     pub mod line;
+    mod multiline_text;
+    /// Module for the [ParetoChart](pareto::ParetoChart) component and its configuration types
+    pub mod pareto;
     /// Module for the [PieChart](pie::PieChart) component and its configuration types
     pub mod pie;
+    #[cfg(test)]
+    mod render;
+    /// Module for the [SankeyChart](sankey::SankeyChart) component and its configuration types
+    pub mod sankey;
+    /// Module for the [WindRoseChart](wind_rose::WindRoseChart) component and its configuration
+    /// types
+    pub mod wind_rose;
 
-    pub use bar::BarChart;
-    pub use line::LineChart;
-    pub use pie::PieChart;
+    pub use bar::{BarChart, SummaryBand};
+    pub use composition_bar::CompositionBar;
+    pub use donut_grid::DonutGrid;
+    pub use line::{Callout, LineChart, QuadrantConfig};
+    pub use pareto::ParetoChart;
+    pub use pie::{PieChart, PieLegendList};
+    pub use sankey::SankeyChart;
+    pub use wind_rose::WindRoseChart;
 }
 
-pub use crate::charts::{BarChart, LineChart, PieChart};
+pub use crate::charts::{
+    BarChart, CompositionBar, DonutGrid, LineChart, ParetoChart, PieChart, PieLegendList,
+    SankeyChart, WindRoseChart,
+};
+
+pub mod prelude {
+    //! Everything needed to configure the charts in a single import
+    //!
+    //! ```rust,ignore
+    //! use dioxus::prelude::*;
+    //! use dioxus_charts::prelude::*;
+    //! ```
+
+    pub use crate::charts::bar::{BarLabel, Orientation, SegmentEvent, SummaryBand, ValuePosition};
+    pub use crate::charts::donut_grid::DonutMetric;
+    pub use crate::charts::line::{
+        AreaFill, Callout, ColorRamp, OutlierMethod, PointEvent, QuadrantConfig, TrendKind,
+    };
+    pub use crate::charts::pie::{
+        Direction, LabelContent, LabelPosition, LabelsMode, PieShape, SliceEvent, SliceInfo,
+        SliceLabel, SlicePart, SortOrder,
+    };
+    pub use crate::charts::sankey::{SankeyLink, SankeyNode};
+    pub use crate::charts::wind_rose::{RoseScale, SectorEvent};
+    pub use crate::charts::{
+        BarChart, CompositionBar, DonutGrid, LineChart, ParetoChart, PieChart, PieLegendList,
+        SankeyChart, WindRoseChart,
+    };
+    pub use crate::grid::{
+        AxisScale, DomainMode, NonPositive, ReferenceLine, TickOverflow, TickSteps,
+    };
+    pub use crate::hooks::{
+        provide_motion_preference, use_chart_hover, use_reduced_motion, MotionPreference,
+    };
+    pub use crate::types::ContextMenu;
+    pub use crate::utils::color::DivergingScale;
+    pub use crate::utils::format::{DurationStyle, NumberFormat, RoundingMode, ValueKind};
+}
@@ -1,7 +1,44 @@
 use std::fmt;
 
+use dioxus::events::MouseData;
+
 pub(crate) type Series = Vec<Vec<f32>>;
 pub(crate) type Labels = Vec<String>;
+pub(crate) type PointErrors = Vec<Vec<Option<(f32, f32)>>>;
+
+/// The payload of the `on_context_menu` events of the charts, with the right-clicked element
+/// like the payload of the other events of the chart, the position of the pointer in client
+/// coordinates, i.e., to open a menu at the pointer, and the position of the element in viewbox
+/// coordinates, i.e., to draw a marker on the chart.
+///
+/// The mouse events carry no position relative to the chart, so the viewbox position is the
+/// middle of the right-clicked element rather than the pointer: the middle of a bar, the center
+/// of a dot, or halfway along the arc of a slice and across its ring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextMenu<T> {
+    /// The right-clicked element.
+    pub target: T,
+    /// The horizontal position of the pointer in the viewport.
+    pub client_x: i32,
+    /// The vertical position of the pointer in the viewport.
+    pub client_y: i32,
+    /// The horizontal middle of the element in the viewbox.
+    pub viewbox_x: f32,
+    /// The vertical middle of the element in the viewbox.
+    pub viewbox_y: f32,
+}
+
+impl<T> ContextMenu<T> {
+    pub(crate) fn new(target: T, mouse: &MouseData, middle: Point) -> Self {
+        Self {
+            target,
+            client_x: mouse.client_x,
+            client_y: mouse.client_y,
+            viewbox_x: middle.x,
+            viewbox_y: middle.y,
+        }
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 pub(crate) struct Point {
@@ -15,6 +52,14 @@ impl Point {
     }
 }
 
+impl std::ops::Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
 // Used for cleaner SVG path formatting
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -43,6 +88,36 @@ impl Rect {
     pub fn height(&self) -> f32 {
         self.max.y - self.min.y
     }
+
+    /// Shrinks the rect along one side, keeping it centered, so that one unit of a world spanning
+    /// `x_world` across its width is as long as `aspect` units of a world spanning `y_world`
+    /// along its height.
+    pub fn fit_aspect(&self, x_world: f32, y_world: f32, aspect: f32) -> Self {
+        if x_world <= 0.0 || y_world <= 0.0 || aspect <= 0.0 {
+            return *self;
+        }
+
+        let (width, height) = (self.width(), self.height());
+        let fitted_width = height / y_world * aspect * x_world;
+
+        if fitted_width < width {
+            let inset = (width - fitted_width) / 2.0;
+            Self::new(
+                self.min.x + inset,
+                self.min.y,
+                self.max.x - inset,
+                self.max.y,
+            )
+        } else {
+            let inset = (height - width / x_world / aspect * y_world) / 2.0;
+            Self::new(
+                self.min.x,
+                self.min.y + inset,
+                self.max.x,
+                self.max.y - inset,
+            )
+        }
+    }
 }
 
 impl fmt::Display for Rect {
@@ -69,41 +144,3 @@ impl Default for TextData {
         }
     }
 }
-
-pub(crate) struct MultiZip<T>(pub Vec<T>);
-
-//pub(crate) struct MultiZip<'a, T> {
-//    vec: &'a Vec<T>
-//}
-//
-//pub(crate) struct MultiZipIter<'a, T> {
-//    values: &'a Vec<T>,
-//    index: usize
-//}
-//
-//impl<'a, T> IntoIterator for MultiZip<'a, T>
-//where
-//    T: Iterator
-//{
-//    type Item = Vec<T::Item>;
-//    type IntoIter = MultiZipIter<'a, T>;
-//
-//    fn into_iter(self) -> Self::IntoIter {
-//        MultiZipIter {
-//            values: self.vec,
-//            index: 0
-//        }
-//    }
-//}
-
-//impl<'a, T> Iterator for MultiZipIter<'a, T>
-impl<T> Iterator for MultiZip<T>
-where
-    T: Iterator,
-{
-    type Item = Vec<T::Item>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.iter_mut().map(Iterator::next).collect()
-    }
-}
@@ -0,0 +1,299 @@
+//! Color helpers shared by the charts.
+
+/// The dash patterns cycled through for the series lines in monochrome mode.
+const DASH_PATTERNS: [&str; 6] = ["none", "8 4", "2 4", "8 4 2 4", "12 6", "4 2 4 6"];
+
+/// The relative luminance from which a fill is light and takes dark labels, where black and
+/// white text have about the same contrast ratio against it.
+const LIGHT_LUMINANCE: f32 = 0.179;
+
+/// The fill patterns cycled through for the series bars in monochrome mode, as path data for an
+/// 8x8 pattern tile.
+const FILL_PATTERNS: [&str; 6] = [
+    "",
+    "M0,8L8,0",
+    "M0,4L8,4",
+    "M4,0L4,8",
+    "M0,0L8,8M0,8L8,0",
+    "M4,4l0.01,0",
+];
+
+/// A shade of grey for the series at `index`, stepping through the lightness range so that
+/// neighbouring series get distinct shades.
+pub(crate) fn grey(index: usize) -> String {
+    let level = 40 + (index * 47) % 150;
+    format!("rgb({level}, {level}, {level})")
+}
+
+pub(crate) fn dash_pattern(index: usize) -> &'static str {
+    DASH_PATTERNS[index % DASH_PATTERNS.len()]
+}
+
+pub(crate) fn fill_pattern(index: usize) -> &'static str {
+    FILL_PATTERNS[index % FILL_PATTERNS.len()]
+}
+
+/// The red channel of the generated color for the element at `index`, getting darker with every
+/// step.
+pub(crate) fn red_shade(index: usize) -> f32 {
+    (1..=index).fold(255.0, |color, i| color - 75.0 / i as f32)
+}
+
+/// The RGB channels of a CSS color in the `#rgb`, `#rrggbb`, `rgb(r, g, b)` or
+/// `rgba(r, g, b, a)` notations, ignoring the alpha, or `None` for any other color, like the
+/// named ones.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::color::parse_rgb;
+///
+/// assert_eq!(parse_rgb("#4e79a7"), Some((78, 121, 167)));
+/// assert_eq!(parse_rgb("#fff"), Some((255, 255, 255)));
+/// assert_eq!(parse_rgb("rgb(255, 40, 40)"), Some((255, 40, 40)));
+/// assert_eq!(parse_rgb("rgba(196.5, 40, 40, 0.5)"), Some((197, 40, 40)));
+/// assert_eq!(parse_rgb("steelblue"), None);
+/// ```
+pub fn parse_rgb(color: &str) -> Option<(u8, u8, u8)> {
+    let color = color.trim();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        return match hex.len() {
+            3 => Some((
+                channel(&hex.get(0..1)?.repeat(2))?,
+                channel(&hex.get(1..2)?.repeat(2))?,
+                channel(&hex.get(2..3)?.repeat(2))?,
+            )),
+            6 => Some((
+                channel(hex.get(0..2)?)?,
+                channel(hex.get(2..4)?)?,
+                channel(hex.get(4..6)?)?,
+            )),
+            _ => None,
+        };
+    }
+
+    let channels = color
+        .strip_prefix("rgba(")
+        .or_else(|| color.strip_prefix("rgb("))?
+        .strip_suffix(')')?
+        .split(',')
+        .map(|channel| channel.trim().parse::<f32>().ok())
+        .collect::<Option<Vec<f32>>>()?;
+    let channel = |i: usize| channels.get(i).map(|c| c.round().clamp(0.0, 255.0) as u8);
+
+    match channels.len() {
+        3 | 4 => Some((channel(0)?, channel(1)?, channel(2)?)),
+        _ => None,
+    }
+}
+
+/// The relative luminance of the color, from `0.0` for black to `1.0` for white, as defined by
+/// the WCAG contrast ratio.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::color::relative_luminance;
+///
+/// assert_eq!(relative_luminance((0, 0, 0)), 0.0);
+/// assert!((relative_luminance((255, 255, 255)) - 1.0).abs() < 1e-6);
+/// assert!((relative_luminance((255, 0, 0)) - 0.2126).abs() < 1e-4);
+/// ```
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    let linear = |channel: u8| {
+        let c = f32::from(channel) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// Whether the CSS color is light enough for dark labels on top of it to have more contrast
+/// than white ones, or `None` when the color can't be parsed by [`parse_rgb`].
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::color::is_light;
+///
+/// assert_eq!(is_light("#ffffff"), Some(true));
+/// assert_eq!(is_light("rgb(255, 40, 40)"), Some(true));
+/// assert_eq!(is_light("rgb(180, 40, 40)"), Some(false));
+/// assert_eq!(is_light("#1f3b73"), Some(false));
+///
+/// // Around the threshold
+/// assert_eq!(is_light("rgb(117, 117, 117)"), Some(false));
+/// assert_eq!(is_light("rgb(119, 119, 119)"), Some(true));
+///
+/// assert_eq!(is_light("currentColor"), None);
+/// ```
+pub fn is_light(color: &str) -> Option<bool> {
+    parse_rgb(color).map(|rgb| relative_luminance(rgb) >= LIGHT_LUMINANCE)
+}
+
+/// A three color scale for values diverging from a midpoint, i.e., blue for negative
+/// correlations, white at zero and red for positive ones.
+///
+/// The values below and above the midpoint are mapped onto their own half of the scale, so the
+/// midpoint keeps the middle color even when the range isn't symmetric around it.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::color::DivergingScale;
+///
+/// let scale = DivergingScale {
+///     min_color: (0, 0, 255),
+///     mid_color: (255, 255, 255),
+///     max_color: (255, 0, 0),
+///     midpoint: 0.0,
+/// };
+///
+/// assert_eq!(scale.color_for_value(0.0, -1.0, 10.0), "rgb(255, 255, 255)");
+/// assert_eq!(scale.color_for_value(-1.0, -1.0, 10.0), "rgb(0, 0, 255)");
+/// assert_eq!(scale.color_for_value(5.0, -1.0, 10.0), "rgb(255, 128, 128)");
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DivergingScale {
+    /// The color for the lowest value, as RGB channels.
+    pub min_color: (u8, u8, u8),
+    /// The color for the midpoint.
+    pub mid_color: (u8, u8, u8),
+    /// The color for the highest value.
+    pub max_color: (u8, u8, u8),
+    /// The value that gets the `mid_color`.
+    pub midpoint: f32,
+}
+
+impl Default for DivergingScale {
+    fn default() -> Self {
+        Self {
+            min_color: (40, 40, 255),
+            mid_color: (255, 255, 255),
+            max_color: (255, 40, 40),
+            midpoint: 0.0,
+        }
+    }
+}
+
+impl DivergingScale {
+    /// The CSS color for the value, for a range of values from `min` to `max`. Values outside
+    /// the range get the color of the closest end.
+    pub fn color_for_value(&self, value: f32, min: f32, max: f32) -> String {
+        let (from, to, t) = if value < self.midpoint {
+            let span = self.midpoint - min;
+            let t = if span > 0.0 {
+                (self.midpoint - value) / span
+            } else {
+                1.0
+            };
+            (self.mid_color, self.min_color, t)
+        } else {
+            let span = max - self.midpoint;
+            let t = if span > 0.0 {
+                (value - self.midpoint) / span
+            } else {
+                0.0
+            };
+            (self.mid_color, self.max_color, t)
+        };
+
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round();
+
+        format!(
+            "rgb({}, {}, {})",
+            mix(from.0, to.0),
+            mix(from.1, to.1),
+            mix(from.2, to.2)
+        )
+    }
+}
+
+/// A sequential color scale for continuous values, interpolating between evenly spaced color
+/// stops, i.e., from dark blue for cold temperatures to yellow for hot ones.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::color::ColorRamp;
+///
+/// let ramp = ColorRamp {
+///     stops: vec![(0, 0, 0), (255, 0, 0), (255, 255, 255)],
+/// };
+///
+/// assert_eq!(ramp.sample(0.0), "rgb(0, 0, 0)");
+/// assert_eq!(ramp.sample(0.25), "rgb(128, 0, 0)");
+/// assert_eq!(ramp.sample(0.5), "rgb(255, 0, 0)");
+/// assert_eq!(ramp.sample(2.0), "rgb(255, 255, 255)");
+///
+/// assert_eq!(ramp.color_for_value(15.0, 10.0, 30.0), "rgb(128, 0, 0)");
+/// assert_eq!(ramp.color_for_value(5.0, 10.0, 10.0), "rgb(0, 0, 0)");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct ColorRamp {
+    /// The colors as RGB channels, from the lowest value to the highest one.
+    pub stops: Vec<(u8, u8, u8)>,
+}
+
+impl Default for ColorRamp {
+    fn default() -> Self {
+        Self {
+            stops: vec![
+                (68, 1, 84),
+                (59, 82, 139),
+                (33, 145, 140),
+                (94, 201, 98),
+                (253, 231, 37),
+            ],
+        }
+    }
+}
+
+impl ColorRamp {
+    /// The CSS color at `t`, from `0.0` for the first stop to `1.0` for the last one. Values
+    /// outside that range get the color of the closest end, and a ramp without stops is always
+    /// black.
+    pub fn sample(&self, t: f32) -> String {
+        let (from, to, t) = match self.stops.len() {
+            0 => ((0, 0, 0), (0, 0, 0), 0.0),
+            1 => (self.stops[0], self.stops[0], 0.0),
+            len => {
+                let position = t.clamp(0.0, 1.0) * (len - 1) as f32;
+                let index = (position.floor() as usize).min(len - 2);
+
+                (
+                    self.stops[index],
+                    self.stops[index + 1],
+                    position - index as f32,
+                )
+            }
+        };
+        let mix = |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round();
+
+        format!(
+            "rgb({}, {}, {})",
+            mix(from.0, to.0),
+            mix(from.1, to.1),
+            mix(from.2, to.2)
+        )
+    }
+
+    /// The CSS color for the value, for a range of values from `min` to `max`. An empty range
+    /// maps everything to the first stop.
+    pub fn color_for_value(&self, value: f32, min: f32, max: f32) -> String {
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.0
+        };
+
+        self.sample(t)
+    }
+}
@@ -0,0 +1,368 @@
+//! Number formatting for the generated labels.
+
+/// Locale aware number formatting, used for the generated labels of the charts when no
+/// `label_interpolation` function is given.
+///
+/// Values are rounded to at most `decimals` decimals, without trailing zeros. In compact mode
+/// the values are divided by thousands while they round to a thousand or more, using the
+/// matching suffix, i.e., `1.2K` for `1234.0`.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::format::{NumberFormat, RoundingMode};
+///
+/// assert_eq!(NumberFormat::en().format(1234.5), "1,234.5");
+/// assert_eq!(NumberFormat::de().format(1234.5), "1.234,5");
+/// assert_eq!(NumberFormat::fr().format(-1234.5), "-1\u{202f}234,5");
+/// assert_eq!(NumberFormat::en().format(-0.001), "0");
+///
+/// let de = NumberFormat::de().decimals(1).compact();
+/// assert_eq!(de.format(1234.5), "1,2 Tsd.");
+///
+/// // Rounding up to the next thousand moves to the next suffix
+/// let en = NumberFormat::en().decimals(1).compact();
+/// assert_eq!(en.format(999_949.0), "999.9K");
+/// assert_eq!(en.format(999_950.0), "1M");
+/// assert_eq!(en.format(999.0), "999");
+/// assert_eq!(en.format(2.5e12), "2.5T");
+/// assert_eq!(en.format(4.0e15), "4,000T");
+///
+/// // Ties go to the even digit with banker's rounding
+/// let even = NumberFormat::en().decimals(0).rounding(RoundingMode::HalfEven);
+/// assert_eq!(even.format(2.5), "2");
+/// assert_eq!(even.format(3.5), "4");
+///
+/// // Other locales start from their separators and suffixes
+/// let ch = NumberFormat::new('.', Some('\''), &["k", "Mio.", "Mrd.", "Bio."]);
+/// assert_eq!(ch.format(-1234.5), "-1'234.5");
+/// ```
+///
+/// New settings may be added, so the formats are built from a constructor and the builder
+/// methods rather than a struct literal.
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct NumberFormat {
+    /// The separator between the integer and the decimal parts.
+    pub decimal_separator: char,
+    /// The separator between every group of three digits of the integer part, if any.
+    pub grouping_separator: Option<char>,
+    /// The suffixes for thousands, millions and so on, used in compact mode.
+    pub compact_suffixes: Vec<String>,
+    /// The maximum number of decimals.
+    pub decimals: usize,
+    /// Whether to use the compact suffixes.
+    pub compact: bool,
+    /// How the values are rounded to the `decimals`.
+    pub rounding: RoundingMode,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::en()
+    }
+}
+
+impl NumberFormat {
+    /// The formatting with the given separators and compact suffixes, with 2 decimals rounded
+    /// half up like the locale formats.
+    pub fn new(
+        decimal_separator: char,
+        grouping_separator: Option<char>,
+        compact_suffixes: &[&str],
+    ) -> Self {
+        Self {
+            decimal_separator,
+            grouping_separator,
+            compact_suffixes: suffixes(compact_suffixes),
+            decimals: 2,
+            compact: false,
+            rounding: RoundingMode::HalfUp,
+        }
+    }
+
+    /// English formatting, i.e., `1,234.5` or `1.2K` in compact mode.
+    pub fn en() -> Self {
+        Self::new('.', Some(','), &["K", "M", "B", "T"])
+    }
+
+    /// German formatting, i.e., `1.234,5` or `1,2 Tsd.` in compact mode.
+    pub fn de() -> Self {
+        Self::new(',', Some('.'), &[" Tsd.", " Mio.", " Mrd.", " Bio."])
+    }
+
+    /// French formatting, i.e., `1 234,5` or `1,2 k` in compact mode.
+    pub fn fr() -> Self {
+        Self::new(
+            ',',
+            Some('\u{202f}'),
+            &["\u{a0}k", "\u{a0}M", "\u{a0}Md", "\u{a0}Bn"],
+        )
+    }
+
+    /// Turns on the compact mode.
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Sets the maximum number of decimals.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets how the values are rounded to the decimals.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Formats the value.
+    pub fn format(&self, value: impl Into<f64>) -> String {
+        let value = value.into();
+
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let round = |v: f64| round_to(v, self.decimals, self.rounding);
+
+        let mut rounded = round(value);
+        let mut tier = 0;
+
+        if self.compact {
+            while tier < self.compact_suffixes.len() && rounded.abs() >= 1000.0 {
+                tier += 1;
+                rounded = round(value / 1000_f64.powi(tier as i32));
+            }
+        }
+
+        let magnitude = rounded.abs();
+
+        let digits = format!("{magnitude:.prec$}", prec = self.decimals);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let fraction = fraction.trim_end_matches('0');
+
+        let mut text = String::new();
+
+        if rounded < 0.0 && magnitude > 0.0 {
+            text.push('-');
+        }
+
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                if let Some(separator) = self.grouping_separator {
+                    text.push(separator);
+                }
+            }
+            text.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            text.push(self.decimal_separator);
+            text.push_str(fraction);
+        }
+
+        if tier > 0 {
+            text.push_str(&self.compact_suffixes[tier - 1]);
+        }
+
+        text
+    }
+}
+
+/// How values are rounded to a number of decimals.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RoundingMode {
+    /// To the nearest value, with ties away from zero, i.e., `2.5` to `3` and `-2.5` to `-3`.
+    #[default]
+    HalfUp,
+    /// To the nearest value, with ties to the even digit, i.e., `2.5` to `2` and `3.5` to `4`,
+    /// also known as banker's rounding.
+    HalfEven,
+    /// Down to the value below, i.e., `2.7` to `2` and `-2.2` to `-3`.
+    Floor,
+    /// Up to the value above, i.e., `2.2` to `3` and `-2.7` to `-2`.
+    Ceil,
+}
+
+/// Rounds the value to `decimals` decimals with the rounding mode.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::format::{round_to, RoundingMode};
+///
+/// let halves = [0.5, 1.5, 2.5, -0.5, -2.5];
+/// let round = |mode| halves.map(|v| round_to(v, 0, mode));
+///
+/// assert_eq!(round(RoundingMode::HalfUp), [1.0, 2.0, 3.0, -1.0, -3.0]);
+/// assert_eq!(round(RoundingMode::HalfEven), [0.0, 2.0, 2.0, -0.0, -2.0]);
+/// assert_eq!(round(RoundingMode::Floor), [0.0, 1.0, 2.0, -1.0, -3.0]);
+/// assert_eq!(round(RoundingMode::Ceil), [1.0, 2.0, 3.0, -0.0, -2.0]);
+///
+/// // Halves of the last decimal
+/// assert_eq!(round_to(0.125, 2, RoundingMode::HalfUp), 0.13);
+/// assert_eq!(round_to(0.125, 2, RoundingMode::HalfEven), 0.12);
+/// assert_eq!(round_to(-0.375, 2, RoundingMode::HalfEven), -0.38);
+/// assert_eq!(round_to(33.333332, 1, RoundingMode::HalfUp), 33.3);
+/// ```
+pub fn round_to(value: f64, decimals: usize, mode: RoundingMode) -> f64 {
+    let factor = 10_f64.powi(decimals as i32);
+    let scaled = value * factor;
+
+    let rounded = match mode {
+        RoundingMode::HalfUp => scaled.round(),
+        RoundingMode::HalfEven => scaled.round_ties_even(),
+        RoundingMode::Floor => scaled.floor(),
+        RoundingMode::Ceil => scaled.ceil(),
+    };
+
+    rounded / factor
+}
+
+/// The number format of the generated labels of a chart: the `number_format` with the
+/// `rounding` of the chart, and its `decimals` when they are set, or the plain format without
+/// grouping separators when there is no `number_format`.
+pub(crate) fn label_number_format(
+    number_format: Option<&NumberFormat>,
+    decimals: Option<usize>,
+    rounding: RoundingMode,
+) -> Option<NumberFormat> {
+    match (number_format, decimals) {
+        (Some(number_format), Some(decimals)) => Some(NumberFormat {
+            decimals,
+            rounding,
+            ..number_format.clone()
+        }),
+        (Some(number_format), None) => Some(NumberFormat {
+            rounding,
+            ..number_format.clone()
+        }),
+        (None, Some(decimals)) => Some(NumberFormat {
+            decimals,
+            rounding,
+            grouping_separator: None,
+            ..NumberFormat::en()
+        }),
+        (None, None) => None,
+    }
+}
+
+/// The kind of values shown on a value axis, picking how its ticks are spaced and how the
+/// values are formatted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ValueKind {
+    /// Plain numbers, with ticks at round decimal steps.
+    #[default]
+    Number,
+    /// Durations in seconds, with ticks at round durations like `30s`, `5m` or `1h`, formatted
+    /// like `1h 23m`.
+    DurationSeconds,
+}
+
+impl ValueKind {
+    /// The formatting of the generated labels for this kind of values, if it isn't the number
+    /// formatting.
+    pub(crate) fn label_interpolation(self) -> Option<fn(f32) -> String> {
+        match self {
+            ValueKind::Number => None,
+            ValueKind::DurationSeconds => Some(|v| format_duration(v, DurationStyle::Compact)),
+        }
+    }
+}
+
+/// How [format_duration] writes a duration.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DurationStyle {
+    /// The two largest units, i.e., `1h 23m` or `45s`.
+    Compact,
+    /// Hours, minutes and seconds separated by colons, i.e., `1:23:45` or `2:05`.
+    Clock,
+}
+
+/// Formats a duration in seconds.
+///
+/// In compact style the seconds keep one decimal under a minute, and longer durations are
+/// rounded to whole seconds and cut down to their two largest units. The clock style is always
+/// rounded to whole seconds.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::format::{format_duration, DurationStyle};
+///
+/// assert_eq!(format_duration(5025.0, DurationStyle::Compact), "1h 23m");
+/// assert_eq!(format_duration(90.0, DurationStyle::Compact), "1m 30s");
+/// assert_eq!(format_duration(3600.0, DurationStyle::Compact), "1h");
+/// assert_eq!(format_duration(2.5, DurationStyle::Compact), "2.5s");
+/// assert_eq!(format_duration(0.0, DurationStyle::Compact), "0s");
+/// assert_eq!(format_duration(-120.0, DurationStyle::Compact), "-2m");
+/// assert_eq!(format_duration(183_600.0, DurationStyle::Compact), "2d 3h");
+///
+/// assert_eq!(format_duration(5025.0, DurationStyle::Clock), "1:23:45");
+/// assert_eq!(format_duration(125.0, DurationStyle::Clock), "2:05");
+/// assert_eq!(format_duration(45.4, DurationStyle::Clock), "0:45");
+/// assert_eq!(format_duration(-90.0, DurationStyle::Clock), "-1:30");
+/// ```
+pub fn format_duration(seconds: f32, style: DurationStyle) -> String {
+    if !seconds.is_finite() {
+        return seconds.to_string();
+    }
+
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let total = seconds.abs().round() as u64;
+    let (days, hours, minutes, secs) = (
+        total / 86_400,
+        total / 3600 % 24,
+        total / 60 % 60,
+        total % 60,
+    );
+
+    match style {
+        DurationStyle::Clock if total >= 3600 => {
+            format!("{sign}{}:{minutes:02}:{secs:02}", total / 3600)
+        }
+        DurationStyle::Clock => format!("{sign}{minutes}:{secs:02}"),
+        DurationStyle::Compact if seconds.abs() < 59.95 => {
+            let tenths = (seconds.abs() * 10.0).round() / 10.0;
+            let sign = if tenths > 0.0 { sign } else { "" };
+            format!("{sign}{tenths}s")
+        }
+        DurationStyle::Compact => {
+            let parts = [(days, "d"), (hours, "h"), (minutes, "m"), (secs, "s")];
+            let first = parts.iter().position(|(n, _)| *n > 0).unwrap_or(3);
+            let text = parts[first..(first + 2).min(parts.len())]
+                .iter()
+                .filter(|(n, _)| *n > 0)
+                .map(|(n, unit)| format!("{n}{unit}"))
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            format!("{sign}{text}")
+        }
+    }
+}
+
+fn suffixes(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| (*s).to_owned()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_number_format_applies_the_chart_rounding() {
+        let de = NumberFormat::de().decimals(0);
+
+        let format = label_number_format(Some(&de), None, RoundingMode::Floor).unwrap();
+        assert_eq!(format.format(2.9), "2");
+
+        let format = label_number_format(Some(&de), Some(1), RoundingMode::Ceil).unwrap();
+        assert_eq!(format.format(2.91), "3");
+
+        assert_eq!(label_number_format(None, None, RoundingMode::Floor), None);
+    }
+}
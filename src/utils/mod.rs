@@ -0,0 +1,79 @@
+//! Utilities for working with the chart data.
+
+pub mod color;
+pub mod format;
+pub(crate) mod path;
+pub mod regression;
+pub mod scale;
+pub mod text;
+pub mod transform;
+
+use crate::types::Point;
+
+pub(crate) fn polar_to_cartesian(c: Point, radius: f32, angle_degrees: f32) -> Point {
+    let angle_radians = (angle_degrees - 90.0).to_radians();
+    Point {
+        x: c.x + radius * angle_radians.cos(),
+        y: c.y + radius * angle_radians.sin(),
+    }
+}
+
+/// Scales the [sanitized](transform::sanitize_series) series so that its highest value is
+/// `100.0`. A series without any positive value is all zeros.
+pub(crate) fn normalize_series(series: &[f32]) -> Vec<f32> {
+    let series = transform::sanitize_series(series);
+    let r = series.iter().copied().fold(0.0, f32::max) as f64 / 100.0;
+
+    if r > 0.0 {
+        series.iter().map(|v| (f64::from(*v) / r) as f32).collect()
+    } else {
+        series
+    }
+}
+
+/// Converts an SVG length used as a stroke width into viewbox units. Percentages are relative to
+/// the normalized diagonal of the viewbox, like the SVG spec defines them.
+pub(crate) fn stroke_width_to_view(width: &str, viewbox_width: f32, viewbox_height: f32) -> f32 {
+    let width = width.trim();
+
+    if let Some(percent) = width.strip_suffix('%') {
+        let diagonal = ((viewbox_width.powi(2) + viewbox_height.powi(2)) / 2.0).sqrt();
+        percent.trim().parse::<f32>().unwrap_or(0.0) / 100.0 * diagonal
+    } else {
+        width
+            .trim_end_matches("px")
+            .trim()
+            .parse::<f32>()
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_series_skips_invalid_values() {
+        assert_eq!(
+            normalize_series(&[50.0, f32::NAN, -25.0, 0.0, f32::INFINITY, 25.0]),
+            vec![100.0, 0.0, 0.0, 0.0, 0.0, 50.0]
+        );
+    }
+
+    #[test]
+    fn normalize_series_without_positive_values_is_zeros() {
+        assert_eq!(normalize_series(&[]), Vec::<f32>::new());
+        assert_eq!(
+            normalize_series(&[0.0, -1.0, f32::NAN, f32::NEG_INFINITY]),
+            vec![0.0; 4]
+        );
+    }
+
+    #[test]
+    fn normalize_series_is_finite() {
+        let normalized = normalize_series(&[f32::MAX, f32::MIN_POSITIVE, f32::NAN, -f32::MAX]);
+
+        assert!(normalized.iter().all(|v| v.is_finite()));
+        assert_eq!(normalized[0], 100.0);
+    }
+}
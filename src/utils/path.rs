@@ -0,0 +1,196 @@
+use std::fmt::Write;
+
+use crate::types::Point;
+
+/// The rough number of bytes taken by a single path command with its coordinates.
+const BYTES_PER_COMMAND: usize = 24;
+
+/// Builds the `d` attribute of SVG paths into a single buffer, avoiding the allocation of one
+/// string per command.
+///
+/// The commands are separated by a space, like the paths of the lines were before, or written
+/// back to back after [`compact`](PathBuilder::compact), like the pie slices were.
+pub(crate) struct PathBuilder {
+    d: String,
+    separator: &'static str,
+}
+
+impl PathBuilder {
+    pub fn with_capacity(commands: usize) -> Self {
+        Self {
+            d: String::with_capacity(commands * BYTES_PER_COMMAND),
+            separator: " ",
+        }
+    }
+
+    /// Writes the commands without a separator.
+    pub fn compact(mut self) -> Self {
+        self.separator = "";
+        self
+    }
+
+    /// Starts a new command, after the separator unless it is the first one.
+    fn command(&mut self, command: char) {
+        if !self.d.is_empty() {
+            self.d.push_str(self.separator);
+        }
+        self.d.push(command);
+    }
+
+    pub fn move_to(&mut self, p: Point) -> &mut Self {
+        self.command('M');
+        // Writing into a String never fails
+        let _ = write!(self.d, "{p}");
+        self
+    }
+
+    pub fn line_to(&mut self, p: Point) -> &mut Self {
+        self.command('L');
+        let _ = write!(self.d, "{p}");
+        self
+    }
+
+    pub fn arc_to(&mut self, radius: f32, large_arc: bool, sweep: bool, p: Point) -> &mut Self {
+        self.command('A');
+        let _ = write!(
+            self.d,
+            "{radius},{radius},0,{},{},{p}",
+            i32::from(large_arc),
+            i32::from(sweep)
+        );
+        self
+    }
+
+    pub fn cubic_to(&mut self, c1: Point, c2: Point, p: Point) -> &mut Self {
+        self.command('C');
+        let _ = write!(self.d, "{c1},{c2},{p}");
+        self
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        self.command('Z');
+        self
+    }
+
+    pub fn build(self) -> String {
+        self.d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    /// The line paths as they were built before, one string per command.
+    fn joined_line(points: &[Point]) -> String {
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                if i == 0 {
+                    format!("M{},{}", point.x, point.y)
+                } else {
+                    format!("L{},{}", point.x, point.y)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn built_line(points: &[Point]) -> String {
+        let mut path = PathBuilder::with_capacity(points.len());
+        for (i, point) in points.iter().enumerate() {
+            if i == 0 {
+                path.move_to(*point);
+            } else {
+                path.line_to(*point);
+            }
+        }
+        path.build()
+    }
+
+    fn wave(n: usize) -> Vec<Point> {
+        (0..n)
+            .map(|i| Point::new(i as f32 * 0.06, (i as f32 / 50.0).sin() * 120.5 + 200.0))
+            .collect()
+    }
+
+    #[test]
+    fn line_matches_the_joined_commands() {
+        let points = [
+            Point::new(0.0, 10.0),
+            Point::new(12.5, 3.25),
+            Point::new(25.0, -4.0),
+        ];
+
+        assert_eq!(built_line(&points), "M0,10 L12.5,3.25 L25,-4");
+        assert_eq!(built_line(&points), joined_line(&points));
+        assert_eq!(built_line(&wave(1000)), joined_line(&wave(1000)));
+    }
+
+    #[test]
+    fn compact_slice_matches_the_formatted_path() {
+        let (end, start, inside_start, inside_end) = (
+            Point::new(300.0, 100.0),
+            Point::new(400.0, 200.0),
+            Point::new(340.0, 200.0),
+            Point::new(300.0, 160.0),
+        );
+        let mut path = PathBuilder::with_capacity(5).compact();
+        path.move_to(end)
+            .arc_to(100.0, false, false, start)
+            .line_to(inside_start)
+            .arc_to(40.0, false, true, inside_end)
+            .close();
+
+        assert_eq!(
+            path.build(),
+            format!("M{end}A100,100,0,0,0,{start}L{inside_start}A40,40,0,0,1,{inside_end}Z")
+        );
+    }
+
+    #[test]
+    fn commands_are_separated_by_a_space() {
+        let mut path = PathBuilder::with_capacity(4);
+        path.move_to(Point::new(0.0, 0.0))
+            .cubic_to(
+                Point::new(1.0, 0.0),
+                Point::new(2.0, 1.0),
+                Point::new(3.0, 1.0),
+            )
+            .arc_to(2.0, true, false, Point::new(0.0, 0.0))
+            .close();
+
+        assert_eq!(path.build(), "M0,0 C1,0,2,1,3,1 A2,2,0,1,0,0,0 Z");
+    }
+
+    /// Times a 10k point line built both ways. Run it with
+    /// `cargo test --release -- --ignored --nocapture path_builder_timing`.
+    #[test]
+    #[ignore]
+    fn path_builder_timing() {
+        let points = wave(10_000);
+        let runs = 200;
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            std::hint::black_box(joined_line(std::hint::black_box(&points)));
+        }
+        let joined = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..runs {
+            std::hint::black_box(built_line(std::hint::black_box(&points)));
+        }
+        let built = start.elapsed();
+
+        println!(
+            "10k point line, {runs} runs: joined {:?}, PathBuilder {:?}",
+            joined / runs,
+            built / runs
+        );
+        assert_eq!(built_line(&points), joined_line(&points));
+    }
+}
@@ -0,0 +1,209 @@
+//! Least squares fits for drawing trend lines over the chart data.
+
+use std::fmt;
+
+use log::warn;
+
+/// The highest polynomial degree accepted by [`fit`].
+const MAX_DEGREE: usize = 3;
+
+/// The kind of curve fitted to the data for a trend line.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrendKind {
+    /// A straight line.
+    Linear,
+    /// A polynomial of the given degree, up to `3`.
+    Polynomial {
+        /// The degree of the polynomial.
+        degree: usize,
+    },
+}
+
+impl TrendKind {
+    fn degree(self) -> usize {
+        match self {
+            TrendKind::Linear => 1,
+            TrendKind::Polynomial { degree } => degree,
+        }
+    }
+}
+
+/// The result of a least squares fit.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Fit {
+    /// The polynomial coefficients, from the constant term up to the highest degree.
+    pub coefficients: Vec<f64>,
+    /// The coefficient of determination of the fit, `1.0` being a perfect fit.
+    pub r_squared: f64,
+}
+
+impl Fit {
+    /// The value of the fitted curve at `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x + c)
+    }
+}
+
+/// Formats the fitted curve as an equation, i.e., `y = 2.00x + 1.00`.
+impl fmt::Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "y =")?;
+
+        for (power, c) in self.coefficients.iter().enumerate().rev() {
+            let first = power + 1 == self.coefficients.len();
+            let sign = match (first, *c < 0.0) {
+                (true, true) => " -",
+                (true, false) => "",
+                (false, true) => " -",
+                (false, false) => " +",
+            };
+            let term = match power {
+                0 => "",
+                1 => "x",
+                2 => "x²",
+                _ => "x³",
+            };
+
+            write!(f, "{sign} {:.2}{term}", c.abs())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Fits the curve to the points with the normal equations, accumulating in [f64].
+///
+/// Returns `None` and logs a warning when the points can't define the curve: fewer points than
+/// the degree plus one, all the points sharing the same `x`, or a degree above `3`.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::regression::{fit, TrendKind};
+///
+/// let line = fit(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)], TrendKind::Linear).unwrap();
+/// assert!((line.coefficients[0] - 1.0).abs() < 1e-9);
+/// assert!((line.coefficients[1] - 2.0).abs() < 1e-9);
+/// assert!((line.r_squared - 1.0).abs() < 1e-9);
+/// assert_eq!(line.to_string(), "y = 2.00x + 1.00");
+///
+/// let points = [(-1.0, 2.0), (0.0, 1.0), (1.0, 2.0), (2.0, 5.0)];
+/// let parabola = fit(&points, TrendKind::Polynomial { degree: 2 }).unwrap();
+/// assert!((parabola.eval(3.0) - 10.0).abs() < 1e-9);
+///
+/// let noisy = fit(&[(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)], TrendKind::Linear).unwrap();
+/// assert!((noisy.r_squared - 0.64).abs() < 1e-9);
+///
+/// assert_eq!(fit(&[(1.0, 1.0), (1.0, 2.0)], TrendKind::Linear), None);
+/// assert_eq!(fit(&[(0.0, 1.0), (1.0, 2.0)], TrendKind::Polynomial { degree: 2 }), None);
+/// ```
+pub fn fit(points: &[(f64, f64)], kind: TrendKind) -> Option<Fit> {
+    let degree = kind.degree();
+
+    if degree == 0 || degree > MAX_DEGREE {
+        warn!("trend line skipped: unsupported polynomial degree {degree}");
+        return None;
+    }
+
+    if points.len() < degree + 1 {
+        warn!(
+            "trend line skipped: {} points can't define a degree {degree} fit",
+            points.len()
+        );
+        return None;
+    }
+
+    let size = degree + 1;
+    let mut power_sums = vec![0.0; 2 * degree + 1];
+    let mut rhs = vec![0.0; size];
+
+    for (x, y) in points {
+        let mut xp = 1.0;
+
+        for (k, sum) in power_sums.iter_mut().enumerate() {
+            *sum += xp;
+
+            if k < size {
+                rhs[k] += xp * y;
+            }
+
+            xp *= x;
+        }
+    }
+
+    let matrix = (0..size)
+        .map(|row| power_sums[row..row + size].to_vec())
+        .collect::<Vec<Vec<f64>>>();
+
+    let coefficients = match solve(matrix, rhs) {
+        Some(coefficients) => coefficients,
+        None => {
+            warn!("trend line skipped: the points don't define a unique fit");
+            return None;
+        }
+    };
+
+    let mean = points.iter().map(|(_, y)| y).sum::<f64>() / points.len() as f64;
+    let mut fit = Fit {
+        coefficients,
+        r_squared: 0.0,
+    };
+
+    let residual: f64 = points.iter().map(|(x, y)| (y - fit.eval(*x)).powi(2)).sum();
+    let total: f64 = points.iter().map(|(_, y)| (y - mean).powi(2)).sum();
+
+    fit.r_squared = if total > 0.0 {
+        1.0 - residual / total
+    } else if residual <= f64::EPSILON {
+        1.0
+    } else {
+        0.0
+    };
+
+    Some(fit)
+}
+
+/// Solves the linear system with Gaussian elimination and partial pivoting, returning `None`
+/// for a singular matrix.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+    let scale = matrix
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |max, v| max.max(v.abs()));
+
+    for col in 0..size {
+        let pivot =
+            (col..size).max_by(|a, b| matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs()))?;
+
+        if matrix[pivot][col].abs() <= scale * 1e-12 {
+            return None;
+        }
+
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+
+        let pivot_row = matrix[col].clone();
+
+        for row in col + 1..size {
+            let factor = matrix[row][col] / pivot_row[col];
+
+            for (value, pivot_value) in matrix[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+
+    for row in (0..size).rev() {
+        let sum: f64 = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+
+    Some(solution)
+}
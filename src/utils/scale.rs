@@ -0,0 +1,308 @@
+//! Mappings from values to positions along an axis.
+
+/// Maps the value linearly from `domain` to `range`.
+///
+/// The mapping is done in `f64` so that small differences between large values, like cents on
+/// amounts in the billions, keep distinct and ordered positions.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::linear;
+///
+/// assert_eq!(linear(5.0, (0.0, 10.0), (0.0, 200.0)), 100.0);
+/// assert_eq!(linear(5.0, (0.0, 10.0), (400.0, 0.0)), 200.0);
+///
+/// let domain = (1_000_000_000.0, 1_000_000_000.1);
+/// let a = linear(1_000_000_000.01, domain, (0.0, 500.0));
+/// let b = linear(1_000_000_000.02, domain, (0.0, 500.0));
+/// assert!(a < b);
+/// assert!((a - 50.0).abs() < 0.01);
+/// assert!((b - 100.0).abs() < 0.01);
+/// ```
+pub fn linear(value: f64, domain: (f64, f64), range: (f64, f64)) -> f64 {
+    let length = domain.1 - domain.0;
+
+    if length == 0.0 {
+        range.0
+    } else {
+        range.0 + (value - domain.0) / length * (range.1 - range.0)
+    }
+}
+
+/// The multiples of a power of ten used by [`nice_step`].
+const NICE_STEPS: [f64; 12] = [1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+
+/// The multiples of a power of ten used by [`round_step`].
+const ROUND_STEPS: [f64; 4] = [1.0, 2.0, 5.0, 10.0];
+
+/// The smallest of the multiples of a power of ten that is at least `minimum`.
+fn step_at_least(minimum: f64, multiples: &[f64]) -> f64 {
+    let magnitude = 10_f64.powf(minimum.abs().log10().floor());
+    // The noise of the f32 data in the last digits must not bump the step to the next one
+    let residual = (minimum / magnitude * 1e6).round() / 1e6;
+
+    multiples
+        .iter()
+        .copied()
+        .find(|n| *n >= residual)
+        .unwrap_or(1.0)
+        * magnitude
+}
+
+/// The round tick step that splits `range` into at most `max_ticks - 2` steps, leaving room to
+/// round the ends out to a step. The steps are the multiples of a power of ten by 1, 1.5, 2,
+/// 2.5 and then every whole number up to 10.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::nice_step;
+///
+/// assert_eq!(nice_step(100.0, 10), 15.0);
+/// assert_eq!(nice_step(80.0, 10), 10.0);
+/// assert_eq!(nice_step(7.0, 9), 1.0);
+/// assert_eq!(nice_step(0.5, 7), 0.1);
+/// assert_eq!(nice_step(f64::from(0.004_f32) - f64::from(0.001_f32), 8), 0.0005);
+/// ```
+pub fn nice_step(range: f64, max_ticks: i32) -> f64 {
+    step_at_least(range / (f64::from(max_ticks) - 2.0), &NICE_STEPS)
+}
+
+/// The smallest step of 1, 2 or 5 times a power of ten that is at least `minimum`, i.e., to
+/// split a range into a number of round steps.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::round_step;
+///
+/// assert_eq!(round_step(100.0 / 8.0), 20.0);
+/// assert_eq!(round_step(4.0), 5.0);
+/// assert_eq!(round_step(5.0), 5.0);
+/// assert_eq!(round_step(0.07), 0.1);
+/// assert_eq!(round_step(1_300_000.0), 2_000_000.0);
+/// assert_eq!(round_step((f64::from(0.004_f32) - f64::from(0.001_f32)) / 6.0), 0.0005);
+/// ```
+pub fn round_step(minimum: f64) -> f64 {
+    step_at_least(minimum, &ROUND_STEPS)
+}
+
+/// The range from `lowest` to `highest` rounded out to whole multiples of `step`.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::round_out;
+///
+/// assert_eq!(round_out(3.0, 47.0, 10.0), (0.0, 50.0));
+/// assert_eq!(round_out(-12.0, 40.0, 10.0), (-20.0, 40.0));
+/// assert_eq!(round_out(f64::from(0.1_f32), f64::from(0.4_f32), 0.1).1, 0.4);
+/// ```
+pub fn round_out(lowest: f64, highest: f64, step: f64) -> (f64, f64) {
+    // Ends a rounding error past a step, like f32 values read as f64, don't add a whole step
+    let tolerance = step * 1e-6;
+    let mut min = ((lowest + tolerance) / step).floor() * step;
+    let mut max = ((highest - tolerance) / step).ceil() * step;
+
+    // The divisions can land just past a whole step
+    if min > lowest + tolerance {
+        min -= step;
+    }
+    if max < highest - tolerance {
+        max += step;
+    }
+
+    (min, max)
+}
+
+/// A linear scale with breaks: every break range of the domain is compressed to a fixed `gap`
+/// in the output range, and the rest of the domain shares what is left of the range linearly.
+///
+/// Breaks outside of the domain are clipped, and overlapping or touching breaks are merged.
+/// Values outside of the domain are extrapolated from the closest linear piece.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::BrokenLinearScale;
+///
+/// // 70 of the 100 values are cut, so the 30 left share the 90 units that aren't the gap
+/// let scale = BrokenLinearScale::new((0.0, 100.0), (0.0, 100.0), &[(20.0, 90.0)], 10.0);
+/// assert_eq!(scale.map(0.0), 0.0);
+/// assert_eq!(scale.map(10.0), 30.0);
+/// assert_eq!(scale.map(20.0), 60.0);
+/// assert_eq!(scale.map(55.0), 65.0);
+/// assert_eq!(scale.map(90.0), 70.0);
+/// assert_eq!(scale.map(100.0), 100.0);
+/// assert_eq!(scale.map(110.0), 130.0);
+///
+/// assert!(scale.in_break(50.0));
+/// assert!(!scale.in_break(20.0));
+/// assert!(!scale.in_break(95.0));
+///
+/// // Without breaks it is a plain linear scale, also for inverted ranges
+/// let linear = BrokenLinearScale::new((10.0, 20.0), (400.0, 0.0), &[], 10.0);
+/// assert_eq!(linear.map(10.0), 400.0);
+/// assert_eq!(linear.map(15.0), 200.0);
+///
+/// // Breaks are clipped to the domain, sorted and merged
+/// let merged = BrokenLinearScale::new(
+///     (0.0, 100.0),
+///     (0.0, 120.0),
+///     &[(50.0, 70.0), (-10.0, 10.0), (60.0, 90.0), (200.0, 300.0)],
+///     10.0,
+/// );
+/// assert_eq!(merged.breaks(), &[(0.0, 10.0), (50.0, 90.0)]);
+/// assert_eq!(merged.map(10.0), 10.0);
+/// assert_eq!(merged.map(30.0), 50.0);
+/// assert_eq!(merged.map(50.0), 90.0);
+/// assert_eq!(merged.map(90.0), 100.0);
+/// assert_eq!(merged.map(100.0), 120.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct BrokenLinearScale {
+    domain: (f32, f32),
+    range: (f32, f32),
+    breaks: Vec<(f32, f32)>,
+    gap: f32,
+    /// The range units for every domain unit outside of the breaks.
+    ratio: f32,
+}
+
+impl BrokenLinearScale {
+    /// Creates the scale mapping `domain` to `range`, with every one of the `breaks` taking
+    /// `gap` range units. The gap is reduced when the breaks would take the whole range.
+    pub fn new(domain: (f32, f32), range: (f32, f32), breaks: &[(f32, f32)], gap: f32) -> Self {
+        let mut clipped = breaks
+            .iter()
+            .map(|(a, b)| (a.min(*b).max(domain.0), a.max(*b).min(domain.1)))
+            .filter(|(a, b)| a < b)
+            .collect::<Vec<(f32, f32)>>();
+        clipped.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut merged = Vec::<(f32, f32)>::with_capacity(clipped.len());
+        for (a, b) in clipped {
+            match merged.last_mut() {
+                Some(last) if a <= last.1 => last.1 = last.1.max(b),
+                _ => merged.push((a, b)),
+            }
+        }
+
+        let length = range.1 - range.0;
+        let gap = gap
+            .abs()
+            .min(length.abs() / (2 * merged.len()).max(1) as f32)
+            * length.signum();
+        let cut: f32 = merged.iter().map(|(a, b)| b - a).sum();
+        let kept = domain.1 - domain.0 - cut;
+        let ratio = if kept > 0.0 {
+            (length - gap * merged.len() as f32) / kept
+        } else {
+            0.0
+        };
+
+        Self {
+            domain,
+            range,
+            breaks: merged,
+            gap,
+            ratio,
+        }
+    }
+
+    /// The breaks as they are applied, clipped to the domain, sorted and merged.
+    pub fn breaks(&self) -> &[(f32, f32)] {
+        &self.breaks
+    }
+
+    /// Whether the value falls strictly inside one of the breaks.
+    pub fn in_break(&self, value: f32) -> bool {
+        self.breaks.iter().any(|(a, b)| *a < value && value < *b)
+    }
+
+    /// Maps the value to the range. Values inside a break are spread over its gap.
+    pub fn map(&self, value: f32) -> f32 {
+        let mut position = self.range.0;
+        let mut cursor = self.domain.0;
+
+        for (a, b) in self.breaks.iter().copied() {
+            if value <= a {
+                break;
+            }
+
+            position += (a - cursor) * self.ratio;
+
+            if value < b {
+                return position + (value - a) / (b - a) * self.gap;
+            }
+
+            position += self.gap;
+            cursor = b;
+        }
+
+        position + (value - cursor) * self.ratio
+    }
+}
+
+/// The round durations in seconds used as tick steps, from a second up to a day.
+const DURATION_STEPS: [f32; 19] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10_800.0, 21_600.0, 43_200.0, 86_400.0, 172_800.0,
+];
+
+/// A tick generator for durations in seconds, stepping at round durations like `15s`, `5m` or
+/// `1h` instead of round decimal numbers.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::scale::DurationScale;
+///
+/// let scale = DurationScale { max_ticks: 8 };
+///
+/// assert_eq!(scale.ticks(0.0, 90.0), vec![0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0]);
+/// assert_eq!(
+///     scale.ticks(0.0, 18_000.0),
+///     vec![0.0, 3600.0, 7200.0, 10_800.0, 14_400.0, 18_000.0]
+/// );
+/// assert_eq!(
+///     scale.ticks(70.0, 400.0),
+///     vec![60.0, 120.0, 180.0, 240.0, 300.0, 360.0, 420.0]
+/// );
+///
+/// // Past the steps of up to two days, whole days are stepped like plain numbers
+/// assert_eq!(scale.step(30.0 * 86_400.0), 5.0 * 86_400.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DurationScale {
+    /// The maximum number of ticks, including both ends.
+    pub max_ticks: i32,
+}
+
+impl DurationScale {
+    /// The smallest round duration that splits `range` seconds into at most `max_ticks - 2`
+    /// steps, leaving room to round the ends out to a step.
+    pub fn step(&self, range: f32) -> f32 {
+        let minimum = range / (self.max_ticks.max(3) - 2) as f32;
+
+        DURATION_STEPS
+            .iter()
+            .copied()
+            .find(|step| *step >= minimum)
+            .unwrap_or_else(|| {
+                let days = f64::from(minimum / 86_400.0);
+
+                (round_step(days) * 86_400.0) as f32
+            })
+    }
+
+    /// The ticks from `lowest` to `highest`, both rounded out to a whole step.
+    pub fn ticks(&self, lowest: f32, highest: f32) -> Vec<f32> {
+        let step = self.step(highest - lowest);
+        let first = (lowest / step).floor() as i64;
+        let last = (highest / step).ceil() as i64;
+
+        (first..=last).map(|n| n as f32 * step).collect()
+    }
+}
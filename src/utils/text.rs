@@ -0,0 +1,148 @@
+//! Text layout helpers for the labels.
+
+use std::fmt::Display;
+use std::ops::Div;
+
+use crate::utils::format::NumberFormat;
+
+/// The average glyph width relative to the font size for common proportional fonts.
+const AVERAGE_CHAR_WIDTH: f32 = 0.6;
+
+/// The font size assumed for labels when estimating their sizes in viewbox units.
+pub(crate) const DEFAULT_FONT_SIZE: f32 = 14.0;
+
+/// A rough estimate of the rendered width of the text. SVG text can't be measured before it is
+/// rendered, so this is only used for layout decisions that must be deterministic.
+pub(crate) fn estimate_text_width(text: &str, font_size: f32) -> f32 {
+    text.chars().count() as f32 * font_size * AVERAGE_CHAR_WIDTH
+}
+
+/// Formats the value divided by `divisor` with the interpolation function, or the number format,
+/// or as is without either, followed by the unit when there is one.
+pub(crate) fn format_scaled<V>(
+    value: V,
+    interpolation: Option<fn(f32) -> String>,
+    number_format: Option<&NumberFormat>,
+    divisor: V,
+    unit: Option<&str>,
+) -> String
+where
+    V: Copy + Display + Div<Output = V> + Into<f64>,
+{
+    let value = value / divisor;
+    let label = if let Some(func) = interpolation {
+        func(value.into() as f32)
+    } else if let Some(number_format) = number_format {
+        number_format.format(value)
+    } else {
+        format!("{value}")
+    };
+
+    if let Some(unit) = unit {
+        format!("{label} {unit}")
+    } else {
+        label
+    }
+}
+
+/// The smallest font size used when shrinking text to fit, below which it's hidden instead.
+pub(crate) const MIN_FONT_SIZE: f32 = 8.0;
+
+/// The largest font size up to `font_size` at which the text fits in a `max_width` by
+/// `max_height` box, or `None` if it would have to be smaller than [`MIN_FONT_SIZE`].
+pub(crate) fn fit_font_size(
+    text: &str,
+    max_width: f32,
+    max_height: f32,
+    font_size: f32,
+) -> Option<f32> {
+    let width = estimate_text_width(text, font_size);
+    let scale = if width > 0.0 { max_width / width } else { 1.0 };
+    let size = (font_size * scale.min(1.0)).min(max_height);
+
+    (size >= MIN_FONT_SIZE).then_some(size)
+}
+
+/// Greedily wraps the text on whitespace into lines whose estimated width fits `max_width`. A
+/// single word wider than `max_width` is kept on its own line instead of being broken.
+pub(crate) fn wrap_text(text: &str, max_width: f32, font_size: f32) -> Vec<String> {
+    let mut lines = Vec::<String>::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        if !current.is_empty()
+            && estimate_text_width(&current, font_size)
+                + estimate_text_width(word, font_size)
+                + font_size * AVERAGE_CHAR_WIDTH
+                > max_width
+        {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Splits the text into lines on `\n`, wrapping every line into `max_width` when it is set, and
+/// gives every line its vertical offset from the label position. The lines are stacked
+/// `line_height` apart as a block that keeps the label alignment: centered on the position for
+/// the `"middle"` and `"central"` baselines, going down from it for the hanging ones and up from
+/// it for the others.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::text::text_lines;
+///
+/// assert_eq!(
+///     text_lines("Asia\n59.5%", None, 14.0, "middle"),
+///     vec![(-7.0, "Asia".to_owned()), (7.0, "59.5%".to_owned())]
+/// );
+/// assert_eq!(
+///     text_lines("Asia\n59.5%", None, 14.0, "hanging"),
+///     vec![(0.0, "Asia".to_owned()), (14.0, "59.5%".to_owned())]
+/// );
+/// assert_eq!(
+///     text_lines("Asia\n59.5%", None, 14.0, "text-bottom"),
+///     vec![(-14.0, "Asia".to_owned()), (0.0, "59.5%".to_owned())]
+/// );
+/// assert_eq!(text_lines("Oceania", None, 14.0, "middle"), vec![(0.0, "Oceania".to_owned())]);
+/// ```
+pub fn text_lines(
+    text: &str,
+    max_width: Option<f32>,
+    line_height: f32,
+    baseline: &str,
+) -> Vec<(f32, String)> {
+    let lines = text
+        .split('\n')
+        .flat_map(|line| match max_width {
+            Some(max_width) if !line.trim().is_empty() => {
+                wrap_text(line, max_width, DEFAULT_FONT_SIZE)
+            }
+            _ => vec![line.to_owned()],
+        })
+        .collect::<Vec<String>>();
+
+    let last = lines.len().saturating_sub(1) as f32;
+    let first_offset = match baseline {
+        "middle" | "central" => -last / 2.0 * line_height,
+        "hanging" | "text-before-edge" | "before-edge" | "text-top" => 0.0,
+        _ => -last * line_height,
+    };
+
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| (first_offset + i as f32 * line_height, line))
+        .collect()
+}
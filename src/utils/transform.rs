@@ -0,0 +1,526 @@
+//! Helpers for preparing data before handing it to the charts.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::types::Labels;
+
+/// The indices of the values in descending order of the values. Ties keep their order.
+pub(crate) fn descending_order(series: &[f32]) -> Vec<usize> {
+    let mut order = (0..series.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| {
+        series[*b]
+            .partial_cmp(&series[*a])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    order
+}
+
+/// Sorts the values in descending order, keeping every label paired with its value.
+/// Missing labels are filled with empty strings.
+pub(crate) fn sort_descending_with_labels(series: &[f32], labels: &[String]) -> (Vec<f32>, Labels) {
+    descending_order(series)
+        .into_iter()
+        .map(|i| (series[i], labels.get(i).cloned().unwrap_or_default()))
+        .unzip()
+}
+
+/// The running sum of the values as a percentage of the series total.
+pub(crate) fn cumulative_percentages(series: &[f32]) -> Vec<f32> {
+    let total: f32 = series.iter().sum();
+
+    if total <= 0.0 {
+        return vec![0.0; series.len()];
+    }
+
+    series
+        .iter()
+        .scan(0.0, |sum, v| {
+            *sum += v;
+            Some((*sum / total * 100.0).min(100.0))
+        })
+        .collect()
+}
+
+/// The values of every category, i.e., every index across the series, as a percentage of the
+/// category total, so that the stacked categories all add up to 100. Categories with no positive
+/// total stay at zero.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::percent_of_category;
+///
+/// let series = vec![vec![1.0, 3.0, 0.0], vec![3.0, 1.0, 0.0], vec![4.0]];
+/// assert_eq!(
+///     percent_of_category(&series),
+///     vec![vec![12.5, 75.0, 0.0], vec![37.5, 25.0, 0.0], vec![50.0]]
+/// );
+/// ```
+pub fn percent_of_category(series: &[Vec<f32>]) -> Vec<Vec<f32>> {
+    let categories = series.iter().map(Vec::len).max().unwrap_or(0);
+    let totals = (0..categories)
+        .map(|j| series.iter().filter_map(|a| a.get(j)).sum::<f32>())
+        .collect::<Vec<f32>>();
+
+    series
+        .iter()
+        .map(|a| {
+            a.iter()
+                .zip(&totals)
+                .map(|(v, total)| if *total > 0.0 { v / total * 100.0 } else { 0.0 })
+                .collect()
+        })
+        .collect()
+}
+
+/// A shaded band across the plot behind the bars, i.e., the historical average plus and minus
+/// one standard deviation. See [`summary_from_series`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SummaryBand {
+    /// The value at the middle of the band.
+    pub center: f32,
+    /// The distance from the center to both edges of the band, in values.
+    pub half_width: f32,
+    /// An extra `class` for the band group, added to the chart `class_summary_band`.
+    pub class: Option<String>,
+    /// Draw a dashed line at the center of the band.
+    pub show_center_line: bool,
+}
+
+/// Builds a [`SummaryBand`] spanning the mean of the values plus and minus one (population)
+/// standard deviation, with the center line shown. An empty series gives a zero band at zero.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::summary_from_series;
+///
+/// let band = summary_from_series(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+/// assert_eq!((band.center, band.half_width), (5.0, 2.0));
+///
+/// let band = summary_from_series(&[3.0]);
+/// assert_eq!((band.center, band.half_width), (3.0, 0.0));
+///
+/// let band = summary_from_series(&[1.5, 1.5, 1.5]);
+/// assert_eq!((band.center, band.half_width), (1.5, 0.0));
+/// ```
+pub fn summary_from_series(series: &[f32]) -> SummaryBand {
+    let (center, half_width) = if series.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let len = series.len() as f32;
+        let mean = series.iter().sum::<f32>() / len;
+        let variance = series.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / len;
+
+        (mean, variance.sqrt())
+    };
+
+    SummaryBand {
+        center,
+        half_width,
+        class: None,
+        show_center_line: true,
+    }
+}
+
+/// The method used by [`detect_outliers`] to flag values.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OutlierMethod {
+    /// Flags the values further than `threshold` standard deviations from the mean.
+    ZScore {
+        /// The number of standard deviations, usually `3.0`.
+        threshold: f32,
+    },
+    /// Flags the values further than `k` interquartile ranges below the first quartile or above
+    /// the third quartile.
+    Iqr {
+        /// The multiple of the interquartile range, usually `1.5`.
+        k: f32,
+    },
+}
+
+/// Flags the outliers of the series with the given method, returning one flag per value.
+/// A constant series has no outliers. The non-finite values, like the `NaN` gaps of a line, are
+/// left out of the statistics and never flagged.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::{detect_outliers, OutlierMethod};
+///
+/// let series = [10.0, 12.0, 11.0, 13.0, 12.0, 40.0, 11.0];
+///
+/// let flags = detect_outliers(&series, OutlierMethod::Iqr { k: 1.5 });
+/// assert_eq!(flags, vec![false, false, false, false, false, true, false]);
+///
+/// let flags = detect_outliers(&series, OutlierMethod::ZScore { threshold: 2.0 });
+/// assert_eq!(flags, vec![false, false, false, false, false, true, false]);
+///
+/// let flags = detect_outliers(&[5.0, 5.0, 5.0], OutlierMethod::ZScore { threshold: 0.5 });
+/// assert_eq!(flags, vec![false, false, false]);
+///
+/// let gaps = [10.0, 12.0, f32::NAN, 11.0, 13.0, 12.0, 40.0, 11.0];
+///
+/// let flags = detect_outliers(&gaps, OutlierMethod::Iqr { k: 1.5 });
+/// assert_eq!(flags, vec![false, false, false, false, false, false, true, false]);
+///
+/// let flags = detect_outliers(&gaps, OutlierMethod::ZScore { threshold: 2.0 });
+/// assert_eq!(flags, vec![false, false, false, false, false, false, true, false]);
+/// ```
+pub fn detect_outliers(series: &[f32], method: OutlierMethod) -> Vec<bool> {
+    let mut finite = series
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite())
+        .collect::<Vec<f32>>();
+
+    match method {
+        OutlierMethod::ZScore { threshold } => {
+            let band = summary_from_series(&finite);
+
+            if band.half_width <= 0.0 {
+                return vec![false; series.len()];
+            }
+
+            series
+                .iter()
+                .map(|v| v.is_finite() && ((v - band.center) / band.half_width).abs() > threshold)
+                .collect()
+        }
+        OutlierMethod::Iqr { k } => {
+            finite.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+            let q1 = quantile(&finite, 0.25);
+            let q3 = quantile(&finite, 0.75);
+            let iqr = q3 - q1;
+
+            series
+                .iter()
+                .map(|v| v.is_finite() && (*v < q1 - k * iqr || *v > q3 + k * iqr))
+                .collect()
+        }
+    }
+}
+
+/// Whether the value can be drawn as a share of a whole, like a pie slice: finite and not
+/// negative.
+pub fn is_valid_share(value: f32) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+/// The series with every value that isn't a [valid share](is_valid_share), like `NaN`, an
+/// infinite or a negative value, replaced by `0.0`, so it is skipped like any zero value.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::sanitize_series;
+///
+/// assert_eq!(
+///     sanitize_series(&[4.0, f32::NAN, -2.0, 0.0, f32::INFINITY, 1.5]),
+///     vec![4.0, 0.0, 0.0, 0.0, 0.0, 1.5]
+/// );
+/// assert!(sanitize_series(&[f32::NAN, f32::NEG_INFINITY]).iter().all(|v| *v == 0.0));
+/// ```
+pub fn sanitize_series(series: &[f32]) -> Vec<f32> {
+    series
+        .iter()
+        .map(|v| if is_valid_share(*v) { *v } else { 0.0 })
+        .collect()
+}
+
+/// The values to group into a single "Other" value: the non-zero values whose share of `total`
+/// is below `threshold`, i.e., `0.05` for 5%. There is nothing to group when fewer than two
+/// values are that small.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::small_shares;
+///
+/// let series = [60.0, 30.0, 4.0, 3.0, 0.0, 3.0];
+/// assert_eq!(
+///     small_shares(&series, 100.0, 0.05),
+///     Some(vec![false, false, true, true, false, true])
+/// );
+///
+/// // A single small value stays on its own
+/// assert_eq!(small_shares(&[60.0, 37.0, 3.0], 100.0, 0.05), None);
+/// ```
+pub fn small_shares(series: &[f32], total: f32, threshold: f32) -> Option<Vec<bool>> {
+    if total <= 0.0 {
+        return None;
+    }
+
+    let grouped = series
+        .iter()
+        .map(|v| *v != 0.0 && v / total < threshold)
+        .collect::<Vec<bool>>();
+
+    (grouped.iter().filter(|g| **g).count() > 1).then_some(grouped)
+}
+
+/// The mismatch found by [`check_total`] between the series sum and the expected total.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TotalError {
+    /// The series sum is below the total by the given amount.
+    Shortfall(f32),
+    /// The series sum is above the total by the given amount.
+    Overshoot(f32),
+}
+
+impl fmt::Display for TotalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TotalError::Shortfall(v) => write!(f, "the series sum is {v} short of the total"),
+            TotalError::Overshoot(v) => write!(f, "the series sum is {v} over the total"),
+        }
+    }
+}
+
+/// Checks that the series sums up to the total, give or take the tolerance, i.e., survey
+/// percentages that must add up to `100.0`.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::{check_total, TotalError};
+///
+/// assert_eq!(check_total(&[40.0, 35.0, 25.0], 100.0, 0.01), Ok(()));
+/// assert_eq!(check_total(&[40.0, 35.0, 24.5], 100.0, 0.5), Ok(()));
+///
+/// assert_eq!(check_total(&[40.0, 35.0, 15.0], 100.0, 0.5), Err(TotalError::Shortfall(10.0)));
+/// assert_eq!(check_total(&[40.0, 35.0, 30.0], 100.0, 0.5), Err(TotalError::Overshoot(5.0)));
+/// ```
+pub fn check_total(series: &[f32], total: f32, tolerance: f32) -> Result<(), TotalError> {
+    let sum: f32 = series.iter().sum();
+    let tolerance = tolerance.abs();
+
+    if sum < total - tolerance {
+        Err(TotalError::Shortfall(total - sum))
+    } else if sum > total + tolerance {
+        Err(TotalError::Overshoot(sum - total))
+    } else {
+        Ok(())
+    }
+}
+
+/// The linearly interpolated quantile of the already sorted values.
+fn quantile(sorted: &[f32], q: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+
+    let position = q * (sorted.len() - 1) as f32;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f32)
+}
+
+/// The size of the time buckets used by [`bucket_by_interval`]. All the buckets are in UTC.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BucketInterval {
+    /// One bucket per hour.
+    Hour,
+    /// One bucket per day.
+    Day,
+    /// One bucket per week, starting on Mondays.
+    Week,
+    /// One bucket per calendar month.
+    Month,
+}
+
+/// How the values falling into the same bucket are combined by [`bucket_by_interval`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Aggregation {
+    /// The sum of the values.
+    Sum,
+    /// The number of values.
+    Count,
+    /// The average of the values.
+    Mean,
+    /// The highest value.
+    Max,
+}
+
+const SECONDS_PER_HOUR: i64 = 3600;
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Aggregates timestamped values into time buckets, returning a series and the formatted
+/// bucket labels ready to be used by a [BarChart](crate::charts::BarChart).
+///
+/// The timestamps are Unix timestamps in seconds. Every bucket between the first and the last
+/// event is returned, so empty buckets in the middle of the range show up as zeros.
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus_charts::utils::transform::{bucket_by_interval, Aggregation, BucketInterval};
+///
+/// // 2022-01-31 10:00, 2022-01-31 12:00 and 2022-03-01 00:00 UTC
+/// let events = [(1643623200, 2.0), (1643630400, 3.0), (1646092800, 1.0)];
+/// let (series, labels) = bucket_by_interval(&events, BucketInterval::Month, Aggregation::Sum);
+///
+/// assert_eq!(series, vec![5.0, 0.0, 1.0]);
+/// assert_eq!(labels, vec!["2022-01", "2022-02", "2022-03"]);
+///
+/// let (series, labels) = bucket_by_interval(&[], BucketInterval::Day, Aggregation::Count);
+/// assert!(series.is_empty() && labels.is_empty());
+/// ```
+pub fn bucket_by_interval(
+    events: &[(i64, f32)],
+    interval: BucketInterval,
+    agg: Aggregation,
+) -> (Vec<f32>, Vec<String>) {
+    let (first, last) = match events.iter().map(|(t, _)| bucket_key(*t, interval)).fold(
+        None,
+        |range: Option<(i64, i64)>, key| match range {
+            Some((first, last)) => Some((first.min(key), last.max(key))),
+            None => Some((key, key)),
+        },
+    ) {
+        Some(range) => range,
+        None => return (Vec::new(), Vec::new()),
+    };
+
+    let len = (last - first + 1) as usize;
+    let mut sums = vec![0.0; len];
+    let mut counts = vec![0usize; len];
+    let mut maxes = vec![f32::MIN; len];
+
+    for (timestamp, value) in events {
+        let i = (bucket_key(*timestamp, interval) - first) as usize;
+        sums[i] += value;
+        counts[i] += 1;
+        maxes[i] = maxes[i].max(*value);
+    }
+
+    let series = (0..len)
+        .map(|i| match (counts[i], agg) {
+            (0, _) => 0.0,
+            (_, Aggregation::Sum) => sums[i],
+            (n, Aggregation::Count) => n as f32,
+            (n, Aggregation::Mean) => sums[i] / n as f32,
+            (_, Aggregation::Max) => maxes[i],
+        })
+        .collect();
+
+    let labels = (first..=last)
+        .map(|key| bucket_label(key, interval))
+        .collect();
+
+    (series, labels)
+}
+
+fn bucket_key(timestamp: i64, interval: BucketInterval) -> i64 {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+
+    match interval {
+        BucketInterval::Hour => timestamp.div_euclid(SECONDS_PER_HOUR),
+        BucketInterval::Day => days,
+        // 1970-01-01 was a Thursday, so shifting by 3 days makes the weeks start on Mondays
+        BucketInterval::Week => (days + 3).div_euclid(7),
+        BucketInterval::Month => {
+            let (year, month, _) = civil_from_days(days);
+            year * 12 + month - 1
+        }
+    }
+}
+
+fn bucket_label(key: i64, interval: BucketInterval) -> String {
+    match interval {
+        BucketInterval::Hour => {
+            let (year, month, day) = civil_from_days(key.div_euclid(24));
+            format!("{year:04}-{month:02}-{day:02} {:02}:00", key.rem_euclid(24))
+        }
+        BucketInterval::Day => {
+            let (year, month, day) = civil_from_days(key);
+            format!("{year:04}-{month:02}-{day:02}")
+        }
+        BucketInterval::Week => {
+            let (year, month, day) = civil_from_days(key * 7 - 3);
+            format!("{year:04}-{month:02}-{day:02}")
+        }
+        BucketInterval::Month => {
+            format!("{:04}-{:02}", key.div_euclid(12), key.rem_euclid(12) + 1)
+        }
+    }
+}
+
+/// Converts days since the Unix epoch into a proleptic Gregorian `(year, month, day)` date.
+/// Based on Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(names: &[&str]) -> Labels {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn sorting_keeps_every_label_with_its_value() {
+        let (values, sorted) =
+            sort_descending_with_labels(&[2.0, 5.0, 1.0, 3.0], &labels(&["A", "B", "C", "D"]));
+
+        assert_eq!(values, [5.0, 3.0, 2.0, 1.0]);
+        assert_eq!(sorted, labels(&["B", "D", "A", "C"]));
+    }
+
+    #[test]
+    fn sorting_fills_the_missing_labels() {
+        let (values, sorted) = sort_descending_with_labels(&[1.0, 4.0, 2.0], &labels(&["A"]));
+
+        assert_eq!(values, [4.0, 2.0, 1.0]);
+        assert_eq!(sorted, labels(&["", "", "A"]));
+    }
+
+    #[test]
+    fn sorting_keeps_the_order_of_ties() {
+        let (values, sorted) =
+            sort_descending_with_labels(&[2.0, 3.0, 2.0, 3.0], &labels(&["A", "B", "C", "D"]));
+
+        assert_eq!(values, [3.0, 3.0, 2.0, 2.0]);
+        assert_eq!(sorted, labels(&["B", "D", "A", "C"]));
+    }
+
+    #[test]
+    fn descending_order_points_at_the_original_values() {
+        assert_eq!(descending_order(&[2.0, 5.0, 2.0, 3.0]), [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn cumulative_percentages_reach_a_hundred() {
+        assert_eq!(
+            cumulative_percentages(&[50.0, 30.0, 15.0, 5.0]),
+            [50.0, 80.0, 95.0, 100.0]
+        );
+        assert_eq!(
+            cumulative_percentages(&[1.0, 1.0, 1.0]).last(),
+            Some(&100.0)
+        );
+    }
+
+    #[test]
+    fn cumulative_percentages_without_a_positive_total_are_zero() {
+        assert_eq!(cumulative_percentages(&[0.0, 0.0]), [0.0, 0.0]);
+        assert_eq!(cumulative_percentages(&[2.0, -3.0]), [0.0, 0.0]);
+        assert_eq!(cumulative_percentages(&[]), Vec::<f32>::new());
+    }
+}